@@ -2,6 +2,9 @@ import { BrowserRouter, Routes, Route } from "react-router-dom"
 import { TooltipProvider } from "@/components/ui/tooltip"
 import { useEffect } from "react"
 import { ws } from "@/ws"
+import { startDesktopNotifications } from "@/desktopNotifications"
+import { startSessionTitle } from "@/sessionTitle"
+import { UpdateBanner } from "@/components/UpdateBanner"
 import ProjectPicker from "@/pages/ProjectPicker"
 import IssuesView from "@/pages/IssuesView"
 import IssueDetail from "@/pages/IssueDetail"
@@ -10,7 +13,13 @@ import ProjectSettings from "@/pages/ProjectSettings"
 function App() {
   useEffect(() => {
     ws.connect()
-    return () => ws.disconnect()
+    const stopNotifications = startDesktopNotifications()
+    const stopSessionTitle = startSessionTitle()
+    return () => {
+      stopNotifications()
+      stopSessionTitle()
+      ws.disconnect()
+    }
   }, [])
 
   return (
@@ -26,6 +35,7 @@ function App() {
           />
         </Routes>
       </BrowserRouter>
+      <UpdateBanner />
     </TooltipProvider>
   )
 }
@@ -1,25 +1,116 @@
 import type {
+  ActivityEvent,
+  ApiErrorCode,
+  ApiErrorDetail,
+  AutocompleteItem,
+  AutocompleteKind,
   Issue,
   Comment,
   Label,
+  SimilarIssue,
+  SimpleUser,
+  StateReason,
+  MilestoneListItem,
+  MilestonePlan,
+  ProjectAmplifierProfile,
+  CommentCommandsConfig,
+  ImportResult,
+  IssuePolishSuggestion,
+  LabelSuggestion,
+  ExportResult,
+  ExportIssuesRequest,
+  ExportIssuesFileRequest,
+  IssueExportResult,
+  IssueMarkdownExportResult,
+  ImportIssuesFileRequest,
+  PullRequestResult,
+  MirrorConfig,
+  IssueMirror,
+  MirrorSyncResult,
   ListResponse,
+  AmplifierProfile,
+  AmplifierProfiles,
+  AmplifierProvider,
   AmplifierSessionInfo,
+  AmplifierSettingsFile,
+  AmplifierSessionLogs,
+  OpenProject,
+  Workspace,
+  WorkspaceIssue,
   GitHubStatus,
+  GitHubAccount,
+  AccountEmails,
   StoreStatus,
+  GitTransport,
+  CommitIdentity,
+  CommitSigning,
   SyncResult,
+  SyncAllResult,
+  TrayStatus,
+  StaticSiteResult,
+  VerifyStoreResult,
+  Collaborator,
+  CollaboratorPermission,
   PatUrl,
+  DeviceLogin,
+  TokenCapabilities,
+  DoctorReport,
+  UpdateStatus,
+  GitHubLogout,
+  LogEntry,
+  RateLimitStatus,
   StoreConfig,
+  GitProviderInfo,
+  ProviderAccount,
   AppConfig,
+  AppSettings,
+  ProjectSettings,
+  ProjectGitStatus,
+  SettingsExport,
+  QuickSwitcherItems,
+  Notification,
+  NotificationList,
   PathValidationResponse,
   GitHubRepo,
+  GitHubOrg,
+  DiscoveredStore,
   CreateProjectRequest,
+  NetworkOperation,
 } from "@/types"
 
+export class ApiError extends Error {
+  readonly status: number
+  readonly code: ApiErrorCode
+  readonly detail: ApiErrorDetail
+
+  constructor(status: number, detail: ApiErrorDetail) {
+    super(detail.message)
+    this.status = status
+    this.code = detail.error
+    this.detail = detail
+  }
+}
+
+function errorDetail(res: Response, body: unknown): ApiErrorDetail {
+  const detail = (body as { detail?: unknown } | null)?.detail
+  if (detail && typeof detail === "object" && "error" in detail) {
+    return detail as ApiErrorDetail
+  }
+  // FastAPI request validation errors are a list of {loc, msg}.
+  if (Array.isArray(detail)) {
+    return {
+      error: "VALIDATION_ERROR",
+      message: detail.map((d: { msg?: string }) => d.msg).join("; "),
+    }
+  }
+  return { error: `HTTP_${res.status}`, message: `${res.status} ${res.statusText}` }
+}
+
 async function request<T>(url: string, init?: RequestInit): Promise<T> {
   const res = await fetch(url, init)
   if (!res.ok) {
-    const text = await res.text().catch(() => res.statusText)
-    throw new Error(text || `${res.status} ${res.statusText}`)
+    const body = await res.json().catch(() => null)
+    throw new ApiError(res.status, errorDetail(res, body))
   }
   if (res.status === 204) return undefined as T
   return res.json()
@@ -51,12 +142,16 @@ export interface ProjectInfo {
   issues_path: string
   store_id: string
   store: StoreConfig
+  project_path: string | null
+  read_only: boolean
 }
 
 export interface IssueFilters {
   state?: string
+  reason?: StateReason
   labels?: string
   assignee?: string
+  mentioned?: string
   sort?: string
   direction?: string
   page?: number
@@ -73,8 +168,9 @@ export interface UpdateIssuePayload {
   title?: string
   body?: string
   state?: string
-  state_reason?: string
+  state_reason?: StateReason
   labels?: string[]
+  expected_version?: number
 }
 
 export interface CreateLabelPayload {
@@ -97,6 +193,73 @@ export const api = {
   updateConfig(updates: Partial<AppConfig>): Promise<AppConfig> {
     return request("/api/config", { method: "PATCH", ...json(updates) })
   },
+  getAppSettings(): Promise<AppSettings> {
+    return request("/api/config/settings")
+  },
+  updateAppSettings(updates: Partial<AppSettings>): Promise<AppSettings> {
+    return request("/api/config/settings", {
+      method: "PATCH",
+      ...json(updates),
+    })
+  },
+  exportSettings(path: string): Promise<SettingsExport> {
+    return request("/api/config/export", { method: "POST", ...json({ path }) })
+  },
+  importSettings(path: string): Promise<AppConfig> {
+    return request("/api/config/import", { method: "POST", ...json({ path }) })
+  },
+  getQuickSwitcherItems(project?: string): Promise<QuickSwitcherItems> {
+    return request(`/api/quick-switcher${qs({ project })}`)
+  },
+
+  // -- Network --
+  cancelNetworkOperations(): Promise<{ cancelled: number }> {
+    return request("/api/network/cancel", { method: "POST" })
+  },
+  listNetworkOperations(): Promise<NetworkOperation[]> {
+    return request("/api/network/operations")
+  },
+  cancelOperation(id: string): Promise<void> {
+    return request(`/api/network/operations/${enc(id)}/cancel`, {
+      method: "POST",
+    })
+  },
+
+  // -- Doctor --
+  runDoctor(project?: string): Promise<DoctorReport> {
+    return request(`/api/doctor${qs({ project })}`)
+  },
+  getRecentLogs(level?: string, limit?: number): Promise<LogEntry[]> {
+    return request(`/api/logs${qs({ level, limit })}`)
+  },
+
+  // -- Updates --
+  checkForUpdates(): Promise<UpdateStatus> {
+    return request("/api/updates")
+  },
+  installUpdate(): Promise<UpdateStatus> {
+    return request("/api/updates/install", { method: "POST" })
+  },
+
+  // -- Openers --
+  openInEditor(path: string, line?: number): Promise<{ opened_with: string }> {
+    return request("/api/open/editor", {
+      method: "POST",
+      ...json({ path, line }),
+    })
+  },
+  openInTerminal(path: string): Promise<{ opened_with: string }> {
+    return request("/api/open/terminal", {
+      method: "POST",
+      ...json({ path }),
+    })
+  },
+  openInFileManager(path: string): Promise<{ opened_with: string }> {
+    return request("/api/open/folder", {
+      method: "POST",
+      ...json({ path }),
+    })
+  },
 
   // -- Filesystem --
   validatePath(path: string): Promise<PathValidationResponse> {
@@ -110,15 +273,120 @@ export const api = {
   getProject(name: string): Promise<ProjectInfo> {
     return request(`/api/projects/${enc(name)}`)
   },
+  getProjectGitStatus(name: string): Promise<ProjectGitStatus> {
+    return request(`/api/projects/${enc(name)}/repo/status`)
+  },
+  commitProjectChanges(
+    name: string,
+    message: string,
+    paths?: string[],
+  ): Promise<{ sha: string }> {
+    return request(`/api/projects/${enc(name)}/repo/commit`, {
+      method: "POST",
+      ...json({ message, paths }),
+    })
+  },
+  pushProject(name: string): Promise<void> {
+    return request(`/api/projects/${enc(name)}/repo/push`, { method: "POST" })
+  },
   createProject(name: string): Promise<ProjectInfo> {
     return request("/api/projects", { method: "POST", ...json({ name }) })
   },
   createProjectAdvanced(data: CreateProjectRequest): Promise<ProjectInfo> {
     return request("/api/projects", { method: "POST", ...json(data) })
   },
+  getProjectSettings(name: string): Promise<ProjectSettings> {
+    return request(`/api/projects/${enc(name)}/settings`)
+  },
+  updateProjectSettings(
+    name: string,
+    updates: Partial<ProjectSettings>,
+  ): Promise<ProjectSettings> {
+    return request(`/api/projects/${enc(name)}/settings`, {
+      method: "PATCH",
+      ...json(updates),
+    })
+  },
+  getCommentCommands(name: string): Promise<CommentCommandsConfig> {
+    return request(`/api/projects/${enc(name)}/comment-commands`)
+  },
+  setCommentCommands(
+    name: string,
+    config: CommentCommandsConfig,
+  ): Promise<CommentCommandsConfig> {
+    return request(`/api/projects/${enc(name)}/comment-commands`, {
+      method: "PUT",
+      ...json(config),
+    })
+  },
+  backupProject(name: string, path: string): Promise<{ path: string }> {
+    return request(`/api/projects/${enc(name)}/backup`, {
+      method: "POST",
+      ...json({ path }),
+    })
+  },
+  restoreProject(
+    archive: string,
+    repoName?: string,
+    isPrivate: boolean = true,
+  ): Promise<ProjectInfo> {
+    return request("/api/projects/restore", {
+      method: "POST",
+      ...json({ archive, repo_name: repoName, private: isPrivate }),
+    })
+  },
+  renameProject(
+    name: string,
+    newName: string,
+    newRepoName?: string,
+  ): Promise<ProjectInfo> {
+    return request(`/api/projects/${enc(name)}/rename`, {
+      method: "POST",
+      ...json({ new_name: newName, new_repo_name: newRepoName }),
+    })
+  },
   deleteProject(name: string): Promise<void> {
     return request(`/api/projects/${enc(name)}`, { method: "DELETE" })
   },
+  listOpenProjects(): Promise<OpenProject[]> {
+    return request("/api/open-projects")
+  },
+  closeProject(name: string): Promise<void> {
+    return request(`/api/open-projects/${enc(name)}`, { method: "DELETE" })
+  },
+
+  // -- Workspaces --
+  listWorkspaces(): Promise<Workspace[]> {
+    return request("/api/workspaces")
+  },
+  saveWorkspace(name: string, projects: string[]): Promise<Workspace> {
+    return request(`/api/workspaces/${enc(name)}`, {
+      method: "PUT",
+      ...json({ projects }),
+    })
+  },
+  deleteWorkspace(name: string): Promise<void> {
+    return request(`/api/workspaces/${enc(name)}`, { method: "DELETE" })
+  },
+  listWorkspaceIssues(
+    name: string,
+    filters?: IssueFilters,
+  ): Promise<ListResponse<WorkspaceIssue>> {
+    const q = filters
+      ? qs({
+          state: filters.state,
+          reason: filters.reason,
+          labels: filters.labels,
+          assignee: filters.assignee,
+          mentioned: filters.mentioned,
+          sort: filters.sort,
+          direction: filters.direction,
+          page: filters.page,
+          per_page: filters.per_page,
+        })
+      : ""
+    return request(`/api/workspaces/${enc(name)}/issues${q}`)
+  },
 
   // -- Issues --
   listIssues(
@@ -128,8 +396,10 @@ export const api = {
     const q = filters
       ? qs({
           state: filters.state,
+          reason: filters.reason,
           labels: filters.labels,
           assignee: filters.assignee,
+          mentioned: filters.mentioned,
           sort: filters.sort,
           direction: filters.direction,
           page: filters.page,
@@ -160,6 +430,82 @@ export const api = {
       ...json(fields),
     })
   },
+  exportIssuesToFile(
+    project: string,
+    payload: ExportIssuesFileRequest,
+  ): Promise<IssueExportResult> {
+    return request(`/api/projects/${enc(project)}/issues/export`, {
+      method: "POST",
+      ...json(payload),
+    })
+  },
+  importIssuesFromFile(
+    project: string,
+    payload: ImportIssuesFileRequest,
+  ): Promise<ImportResult> {
+    return request(`/api/projects/${enc(project)}/issues/import`, {
+      method: "POST",
+      ...json(payload),
+    })
+  },
+  createIssueBranch(project: string, number: number): Promise<Issue> {
+    return request(`/api/projects/${enc(project)}/issues/${number}/branch`, {
+      method: "POST",
+    })
+  },
+  findSimilarIssues(
+    project: string,
+    title: string,
+    body?: string,
+  ): Promise<SimilarIssue[]> {
+    return request(`/api/projects/${enc(project)}/issues/similar`, {
+      method: "POST",
+      ...json({ title, body: body ?? null }),
+    })
+  },
+  lockIssue(project: string, number: number, lockReason?: string): Promise<Issue> {
+    return request(`/api/projects/${enc(project)}/issues/${number}/lock`, {
+      method: "PUT",
+      ...json({ lock_reason: lockReason ?? null }),
+    })
+  },
+  unlockIssue(project: string, number: number): Promise<Issue> {
+    return request(`/api/projects/${enc(project)}/issues/${number}/lock`, {
+      method: "DELETE",
+    })
+  },
+  exportIssueMarkdown(
+    project: string,
+    number: number,
+    destination: string,
+  ): Promise<IssueMarkdownExportResult> {
+    return request(`/api/projects/${enc(project)}/issues/${number}/export`, {
+      method: "POST",
+      ...json({ destination }),
+    })
+  },
+  revealIssueFiles(
+    project: string,
+    number: number,
+  ): Promise<{ opened_with: string }> {
+    return request(
+      `/api/projects/${enc(project)}/issues/${number}/reveal`,
+      { method: "POST" },
+    )
+  },
+  listAssignableUsers(project: string, q?: string): Promise<SimpleUser[]> {
+    return request(`/api/projects/${enc(project)}/assignees${qs({ q })}`)
+  },
+  getAutocomplete(
+    project: string,
+    kind: AutocompleteKind,
+    prefix = "",
+    limit?: number,
+  ): Promise<AutocompleteItem[]> {
+    return request(
+      `/api/projects/${enc(project)}/autocomplete${qs({ kind, prefix, limit })}`,
+    )
+  },
 
   // -- Comments --
   listComments(
@@ -270,13 +616,171 @@ export const api = {
     )
   },
 
+  // -- Milestones --
+  listMilestones(
+    project: string,
+    options?: { dueWithinDays?: number; overdue?: boolean },
+  ): Promise<MilestoneListItem[]> {
+    const overdue = options?.overdue === undefined ? undefined : String(options.overdue)
+    return request(
+      `/api/projects/${enc(project)}/milestones${qs({
+        due_within_days: options?.dueWithinDays,
+        overdue,
+      })}`,
+    )
+  },
+  planMilestone(
+    project: string,
+    milestone: number,
+    constraints?: string,
+  ): Promise<MilestonePlan> {
+    return request(
+      `/api/projects/${enc(project)}/milestones/${milestone}/amplifier/plan`,
+      { method: "POST", ...json({ constraints: constraints || null }) },
+    )
+  },
+  assignMilestone(
+    project: string,
+    milestone: number,
+    issues: number[],
+  ): Promise<Issue[]> {
+    return request(
+      `/api/projects/${enc(project)}/milestones/${milestone}/issues`,
+      { method: "POST", ...json({ issues }) },
+    )
+  },
+
+  // -- GitHub Issues --
+  importGitHubIssues(
+    project: string,
+    owner: string,
+    repo: string,
+  ): Promise<ImportResult> {
+    return request(`/api/projects/${enc(project)}/github/import`, {
+      method: "POST",
+      ...json({ owner, repo }),
+    })
+  },
+  exportGitHubIssues(
+    project: string,
+    payload: ExportIssuesRequest,
+  ): Promise<ExportResult> {
+    return request(`/api/projects/${enc(project)}/github/export`, {
+      method: "POST",
+      ...json(payload),
+    })
+  },
+  createPullRequest(
+    project: string,
+    issueNumber: number,
+    base?: string,
+  ): Promise<PullRequestResult> {
+    return request(`/api/projects/${enc(project)}/github/pulls`, {
+      method: "POST",
+      ...json({ issue_number: issueNumber, base }),
+    })
+  },
+  linkPullRequest(
+    project: string,
+    issueNumber: number,
+    url: string,
+  ): Promise<Issue> {
+    return request(`/api/projects/${enc(project)}/github/pulls/link`, {
+      method: "POST",
+      ...json({ issue_number: issueNumber, url }),
+    })
+  },
+  refreshPullRequests(project: string): Promise<{ updated: number[] }> {
+    return request(`/api/projects/${enc(project)}/github/pulls/refresh`, {
+      method: "POST",
+    })
+  },
+  getMirrorConfig(project: string): Promise<MirrorConfig | null> {
+    return request(`/api/projects/${enc(project)}/github/mirror`)
+  },
+  setMirrorConfig(project: string, config: MirrorConfig): Promise<MirrorConfig> {
+    return request(`/api/projects/${enc(project)}/github/mirror`, {
+      method: "PUT",
+      ...json(config),
+    })
+  },
+  disableMirror(project: string): Promise<void> {
+    return request(`/api/projects/${enc(project)}/github/mirror`, {
+      method: "DELETE",
+    })
+  },
+  syncMirror(project: string): Promise<MirrorSyncResult> {
+    return request(`/api/projects/${enc(project)}/github/mirror/sync`, {
+      method: "POST",
+    })
+  },
+  getIssueMirror(project: string, number: number): Promise<IssueMirror> {
+    return request(`/api/projects/${enc(project)}/github/mirror/issues/${number}`)
+  },
+  mirrorIssue(
+    project: string,
+    number: number,
+    remoteNumber?: number,
+  ): Promise<IssueMirror> {
+    return request(`/api/projects/${enc(project)}/github/mirror/issues/${number}`, {
+      method: "PUT",
+      ...json({ remote_number: remoteNumber ?? null }),
+    })
+  },
+  unmirrorIssue(project: string, number: number): Promise<void> {
+    return request(`/api/projects/${enc(project)}/github/mirror/issues/${number}`, {
+      method: "DELETE",
+    })
+  },
+
+  // -- Activity --
+  getActivity(
+    project: string,
+    since?: string,
+    page?: number,
+    perPage?: number,
+  ): Promise<ListResponse<ActivityEvent>> {
+    const q = qs({ since, page, per_page: perPage })
+    return request(`/api/projects/${enc(project)}/activity${q}`)
+  },
+
+  // -- Notifications --
+  listNotifications(unread?: boolean): Promise<NotificationList> {
+    return request(`/api/notifications${qs({ unread: unread ? "true" : undefined })}`)
+  },
+  markNotificationRead(id: string, read = true): Promise<Notification> {
+    return request(`/api/notifications/${enc(id)}/read`, {
+      method: "POST",
+      ...json({ read }),
+    })
+  },
+  markAllNotificationsRead(): Promise<{ marked: number }> {
+    return request("/api/notifications/read-all", { method: "POST" })
+  },
+
   // -- Amplifier --
   runAmplifier(
     project: string,
     issueNumber: number,
+    paths: string[] = [],
   ): Promise<AmplifierSessionInfo> {
     return request(
       `/api/projects/${enc(project)}/issues/${issueNumber}/amplifier`,
+      { method: "POST", ...json({ paths }) },
+    )
+  },
+  polishIssue(project: string, issueNumber: number): Promise<IssuePolishSuggestion> {
+    return request(
+      `/api/projects/${enc(project)}/issues/${issueNumber}/amplifier/polish`,
+      { method: "POST" },
+    )
+  },
+  suggestIssueLabels(
+    project: string,
+    issueNumber: number,
+  ): Promise<LabelSuggestion[]> {
+    return request(
+      `/api/projects/${enc(project)}/issues/${issueNumber}/amplifier/labels`,
       { method: "POST" },
     )
   },
@@ -288,6 +792,70 @@ export const api = {
       `/api/projects/${enc(project)}/issues/${issueNumber}/amplifier`,
     )
   },
+  getAmplifierLogs(
+    project: string,
+    issueNumber: number,
+    tail?: number,
+  ): Promise<AmplifierSessionLogs> {
+    return request(
+      `/api/projects/${enc(project)}/issues/${issueNumber}/amplifier/logs${qs({ tail })}`,
+    )
+  },
+  listAmplifierProfiles(): Promise<AmplifierProfiles> {
+    return request("/api/amplifier/profiles")
+  },
+  saveAmplifierProfile(
+    name: string,
+    profile: {
+      provider: AmplifierProvider
+      model: string
+      base_url?: string | null
+      api_key?: string | null
+    },
+  ): Promise<AmplifierProfile> {
+    return request(`/api/amplifier/profiles/${enc(name)}`, {
+      method: "PUT",
+      ...json(profile),
+    })
+  },
+  deleteAmplifierProfile(name: string): Promise<void> {
+    return request(`/api/amplifier/profiles/${enc(name)}`, { method: "DELETE" })
+  },
+  setDefaultAmplifierProfile(profile: string | null): Promise<AmplifierProfiles> {
+    return request("/api/amplifier/default-profile", {
+      method: "PUT",
+      ...json({ profile }),
+    })
+  },
+  getProjectAmplifierProfile(project: string): Promise<ProjectAmplifierProfile> {
+    return request(`/api/projects/${enc(project)}/amplifier/profile`)
+  },
+  setProjectAmplifierProfile(
+    project: string,
+    profile: string | null,
+  ): Promise<ProjectAmplifierProfile> {
+    return request(`/api/projects/${enc(project)}/amplifier/profile`, {
+      method: "PUT",
+      ...json({ profile }),
+    })
+  },
+  getAmplifierSettings(project: string): Promise<AmplifierSettingsFile> {
+    return request(`/api/projects/${enc(project)}/amplifier/settings`)
+  },
+  updateAmplifierSettings(
+    project: string,
+    patch: string,
+  ): Promise<AmplifierSettingsFile> {
+    return request(`/api/projects/${enc(project)}/amplifier/settings`, {
+      method: "PATCH",
+      ...json({ patch }),
+    })
+  },
+  resetAmplifierSettings(project: string): Promise<void> {
+    return request(`/api/projects/${enc(project)}/amplifier/settings`, {
+      method: "DELETE",
+    })
+  },
   cancelAmplifier(
     project: string,
     issueNumber: number,
@@ -297,8 +865,24 @@ export const api = {
       { method: "DELETE" },
     )
   },
-  listAmplifierSessions(): Promise<AmplifierSessionInfo[]> {
-    return request("/api/amplifier/sessions")
+  listAmplifierSessions(project?: string): Promise<AmplifierSessionInfo[]> {
+    return request(`/api/amplifier/sessions${qs({ project })}`)
+  },
+  cancelAllAmplifierSessions(): Promise<{ cancelled: number }> {
+    return request("/api/amplifier/sessions", { method: "DELETE" })
+  },
+
+  // -- Tray --
+  getTrayStatus(): Promise<TrayStatus> {
+    return request("/api/tray")
+  },
+  syncAllStores(): Promise<SyncAllResult> {
+    return request("/api/tray/sync", { method: "POST" })
+  },
+
+  // -- Avatars --
+  avatarUrl(login: string): string {
+    return `/api/avatars/${enc(login)}`
   },
 
   // -- GitHub Auth --
@@ -311,11 +895,92 @@ export const api = {
   removeGitHubToken(): Promise<void> {
     return request("/api/github/token", { method: "DELETE" })
   },
+  logoutGitHub(wipeClones = false): Promise<GitHubLogout> {
+    return request("/api/github/logout", {
+      method: "POST",
+      ...json({ wipe_clones: wipeClones }),
+    })
+  },
   getPatUrl(): Promise<PatUrl> {
     return request("/api/github/pat-url")
   },
-  listRepos(q?: string): Promise<GitHubRepo[]> {
-    return request(`/api/github/repos${q ? qs({ q }) : ""}`)
+  checkTokenCapabilities(
+    account?: string,
+    owner?: string,
+    repo?: string,
+  ): Promise<TokenCapabilities> {
+    return request(`/api/github/capabilities${qs({ account, owner, repo })}`)
+  },
+  getRateLimitStatus(account?: string): Promise<RateLimitStatus> {
+    return request(`/api/github/rate-limit${qs({ account })}`)
+  },
+  startDeviceLogin(): Promise<DeviceLogin> {
+    return request("/api/github/device-login", { method: "POST" })
+  },
+  getDeviceLogin(): Promise<DeviceLogin> {
+    return request("/api/github/device-login")
+  },
+  cancelDeviceLogin(): Promise<void> {
+    return request("/api/github/device-login", { method: "DELETE" })
+  },
+  listRepos(
+    q?: string,
+    account?: string,
+    options?: { affiliation?: string; org?: string; search?: string },
+  ): Promise<GitHubRepo[]> {
+    return request(
+      `/api/github/repos${qs({
+        q,
+        search: options?.search,
+        account,
+        affiliation: options?.affiliation,
+        org: options?.org,
+      })}`,
+    )
+  },
+  discoverStores(account?: string): Promise<DiscoveredStore[]> {
+    return request(`/api/github/stores${qs({ account })}`)
+  },
+  suggestStoreNames(project: string, account?: string): Promise<string[]> {
+    return request(`/api/github/store-names${qs({ project, account })}`)
+  },
+  listGitHubOrgs(account?: string): Promise<GitHubOrg[]> {
+    return request(`/api/github/orgs${qs({ account })}`)
+  },
+  listGitHubAccounts(): Promise<GitHubAccount[]> {
+    return request("/api/github/accounts")
+  },
+  addGitHubAccount(
+    token: string,
+    makeDefault: boolean = false,
+  ): Promise<{ user: string; validated_at: string }> {
+    return request("/api/github/accounts", {
+      method: "POST",
+      ...json({ token, make_default: makeDefault }),
+    })
+  },
+  addCredentialHelperAccount(
+    makeDefault: boolean = true,
+  ): Promise<{ user: string; validated_at: string }> {
+    return request("/api/github/accounts/credential-helper", {
+      method: "POST",
+      ...json({ make_default: makeDefault }),
+    })
+  },
+  removeGitHubAccount(user: string): Promise<void> {
+    return request(`/api/github/accounts/${enc(user)}`, { method: "DELETE" })
+  },
+  setDefaultGitHubAccount(user: string): Promise<void> {
+    return request(`/api/github/accounts/${enc(user)}/default`, { method: "PUT" })
+  },
+  listAccountEmails(user: string): Promise<AccountEmails> {
+    return request(`/api/github/accounts/${enc(user)}/emails`)
+  },
+  setCommitEmail(user: string, email: string | null): Promise<void> {
+    return request(`/api/github/accounts/${enc(user)}/email`, {
+      method: "PUT",
+      ...json({ email }),
+    })
   },
 
   // -- Store --
@@ -326,12 +991,64 @@ export const api = {
     project: string,
     owner: string,
     repo: string,
+    options?: { transport?: GitTransport; sshKeyPath?: string; branch?: string },
   ): Promise<StoreStatus> {
     return request(`/api/projects/${enc(project)}/store/connect`, {
       method: "POST",
-      ...json({ owner, repo }),
+      ...json({
+        owner,
+        repo,
+        transport: options?.transport,
+        ssh_key_path: options?.sshKeyPath,
+        branch: options?.branch,
+      }),
     })
   },
+  setStoreTransport(
+    project: string,
+    transport: GitTransport,
+    sshKeyPath?: string,
+  ): Promise<StoreStatus> {
+    return request(`/api/projects/${enc(project)}/store/transport`, {
+      method: "PUT",
+      ...json({ transport, ssh_key_path: sshKeyPath ?? null }),
+    })
+  },
+  setStoreBranch(project: string, branch: string | null): Promise<StoreStatus> {
+    return request(`/api/projects/${enc(project)}/store/branch`, {
+      method: "PUT",
+      ...json({ branch }),
+    })
+  },
+
+  setCommitSigning(project: string, signing: CommitSigning): Promise<StoreStatus> {
+    return request(`/api/projects/${enc(project)}/store/signing`, {
+      method: "PUT",
+      ...json(signing),
+    })
+  },
+  disableCommitSigning(project: string): Promise<void> {
+    return request(`/api/projects/${enc(project)}/store/signing`, {
+      method: "DELETE",
+    })
+  },
+  setCommitAuthor(project: string, author: CommitIdentity): Promise<StoreStatus> {
+    return request(`/api/projects/${enc(project)}/store/author`, {
+      method: "PUT",
+      ...json(author),
+    })
+  },
+  clearCommitAuthor(project: string): Promise<void> {
+    return request(`/api/projects/${enc(project)}/store/author`, {
+      method: "DELETE",
+    })
+  },
+
+  suggestRepoName(
+    project: string,
+  ): Promise<{ repo_name: string; alternatives: string[] }> {
+    return request(`/api/projects/${enc(project)}/store/repo-name`)
+  },
   createRemote(
     project: string,
     repoName: string,
@@ -343,9 +1060,129 @@ export const api = {
       ...json({ repo_name: repoName, private: isPrivate, description }),
     })
   },
+  setStoreAccount(project: string, account: string | null): Promise<StoreStatus> {
+    return request(`/api/projects/${enc(project)}/store/account`, {
+      method: "PUT",
+      ...json({ account }),
+    })
+  },
+  setStoreVisibility(
+    project: string,
+    visibility: "private" | "internal" | "public",
+  ): Promise<{ visibility: string; private: boolean }> {
+    return request(`/api/projects/${enc(project)}/store/visibility`, {
+      method: "PUT",
+      ...json({ visibility }),
+    })
+  },
+  recreateStore(
+    project: string,
+    repoName?: string,
+    isPrivate: boolean = true,
+  ): Promise<StoreStatus> {
+    return request(`/api/projects/${enc(project)}/store/recreate`, {
+      method: "POST",
+      ...json({ repo_name: repoName, private: isPrivate }),
+    })
+  },
+  archiveStore(project: string, confirm: string): Promise<void> {
+    return request(`/api/projects/${enc(project)}/store/archive`, {
+      method: "POST",
+      ...json({ confirm }),
+    })
+  },
+  deleteStore(project: string, confirm: string): Promise<void> {
+    return request(`/api/projects/${enc(project)}/store/delete`, {
+      method: "POST",
+      ...json({ confirm }),
+    })
+  },
+  listStoreCollaborators(project: string): Promise<Collaborator[]> {
+    return request(`/api/projects/${enc(project)}/store/collaborators`)
+  },
+  addStoreCollaborator(
+    project: string,
+    login: string,
+    permission: CollaboratorPermission = "push",
+  ): Promise<{ login: string; invited: boolean }> {
+    return request(
+      `/api/projects/${enc(project)}/store/collaborators/${enc(login)}`,
+      { method: "PUT", ...json({ permission }) },
+    )
+  },
+  removeStoreCollaborator(project: string, login: string): Promise<void> {
+    return request(
+      `/api/projects/${enc(project)}/store/collaborators/${enc(login)}`,
+      { method: "DELETE" },
+    )
+  },
   syncStore(project: string): Promise<SyncResult> {
     return request(`/api/projects/${enc(project)}/store/sync`, {
       method: "POST",
     })
   },
+  verifyStore(project: string): Promise<VerifyStoreResult> {
+    return request(`/api/projects/${enc(project)}/store/verify`, {
+      method: "POST",
+    })
+  },
+  publishStaticSite(project: string): Promise<StaticSiteResult> {
+    return request(`/api/projects/${enc(project)}/store/pages`, {
+      method: "POST",
+    })
+  },
+
+  // -- Git Providers --
+  listProviders(): Promise<GitProviderInfo[]> {
+    return request("/api/providers")
+  },
+  listProviderAccounts(): Promise<ProviderAccount[]> {
+    return request("/api/providers/accounts")
+  },
+  addProviderAccount(
+    provider: string,
+    token: string,
+    baseUrl?: string,
+  ): Promise<ProviderAccount> {
+    return request("/api/providers/accounts", {
+      method: "POST",
+      ...json({ provider, token, base_url: baseUrl }),
+    })
+  },
+  removeProviderAccount(provider: string, baseUrl: string, user: string): Promise<void> {
+    return request(
+      `/api/providers/accounts${qs({ provider, base_url: baseUrl, user })}`,
+      { method: "DELETE" },
+    )
+  },
+  connectRemoteStore(
+    project: string,
+    provider: string,
+    owner: string,
+    repo: string,
+    options?: { baseUrl?: string; user?: string },
+  ): Promise<StoreStatus> {
+    return request(`/api/projects/${enc(project)}/store/remote/connect`, {
+      method: "POST",
+      ...json({ provider, owner, repo, base_url: options?.baseUrl, user: options?.user }),
+    })
+  },
+  createRemoteStore(
+    project: string,
+    provider: string,
+    repoName: string,
+    options?: { baseUrl?: string; user?: string; private?: boolean; description?: string },
+  ): Promise<StoreStatus> {
+    return request(`/api/projects/${enc(project)}/store/remote/create`, {
+      method: "POST",
+      ...json({
+        provider,
+        repo_name: repoName,
+        base_url: options?.baseUrl,
+        user: options?.user,
+        private: options?.private ?? true,
+        description: options?.description ?? "",
+      }),
+    })
+  },
 }
@@ -0,0 +1,180 @@
+import { useEffect, useState } from "react"
+import { Bot } from "lucide-react"
+import { Button } from "@/components/ui/button"
+import { Input } from "@/components/ui/input"
+import { Label } from "@/components/ui/label"
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select"
+import { api } from "@/api"
+import type { AmplifierProfiles as Profiles, AmplifierProvider } from "@/types"
+
+const PROVIDERS: { key: AmplifierProvider; label: string; model: string }[] = [
+  { key: "anthropic", label: "Anthropic", model: "claude-opus-4-6" },
+  { key: "openai", label: "OpenAI", model: "gpt-5" },
+  { key: "azure", label: "Azure OpenAI", model: "Deployment name" },
+  { key: "local", label: "Local (Ollama)", model: "llama3.1" },
+]
+
+export function AmplifierProfiles() {
+  const [profiles, setProfiles] = useState<Profiles | null>(null)
+  const [name, setName] = useState("")
+  const [provider, setProvider] = useState<AmplifierProvider>("anthropic")
+  const [model, setModel] = useState("")
+  const [baseUrl, setBaseUrl] = useState("")
+  const [apiKey, setApiKey] = useState("")
+  const [error, setError] = useState<string | null>(null)
+
+  useEffect(() => {
+    api
+      .listAmplifierProfiles()
+      .then(setProfiles)
+      .catch(() => {})
+  }, [])
+
+  async function run(action: () => Promise<unknown>) {
+    setError(null)
+    try {
+      await action()
+      setProfiles(await api.listAmplifierProfiles())
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Failed to save profile")
+    }
+  }
+
+  async function handleSave() {
+    await run(() =>
+      api.saveAmplifierProfile(name.trim(), {
+        provider,
+        model: model.trim(),
+        base_url: baseUrl.trim() || null,
+        api_key: apiKey || null,
+      }),
+    )
+    setName("")
+    setModel("")
+    setBaseUrl("")
+    setApiKey("")
+  }
+
+  if (!profiles) return null
+
+  const placeholder = PROVIDERS.find((p) => p.key === provider)?.model
+
+  return (
+    <div className="space-y-3">
+      <h2 className="flex items-center gap-2 text-sm font-medium text-muted-foreground">
+        <Bot className="h-4 w-4" />
+        Amplifier Profiles
+      </h2>
+      {profiles.profiles.length === 0 && (
+        <p className="text-sm text-muted-foreground">
+          Sessions use Anthropic with ANTHROPIC_API_KEY until you add a profile.
+        </p>
+      )}
+      {profiles.profiles.map((p) => (
+        <div key={p.name} className="flex items-center gap-2 text-sm">
+          <span className="font-medium">{p.name}</span>
+          <span className="text-muted-foreground">
+            {PROVIDERS.find((x) => x.key === p.provider)?.label} {p.model}
+            {!p.has_api_key && p.provider !== "local" && " (key from environment)"}
+          </span>
+          <span className="ml-auto flex gap-1">
+            {profiles.default === p.name ? (
+              <span className="px-2 text-xs text-muted-foreground">Default</span>
+            ) : (
+              <Button
+                variant="ghost"
+                size="sm"
+                onClick={() => run(() => api.setDefaultAmplifierProfile(p.name))}
+              >
+                Make Default
+              </Button>
+            )}
+            <Button
+              variant="ghost"
+              size="sm"
+              onClick={() => run(() => api.deleteAmplifierProfile(p.name))}
+            >
+              Remove
+            </Button>
+          </span>
+        </div>
+      ))}
+      <div className="grid gap-3 sm:grid-cols-3">
+        <div className="space-y-1">
+          <Label htmlFor="profile-name">Name</Label>
+          <Input
+            id="profile-name"
+            placeholder="work"
+            value={name}
+            onChange={(e) => setName(e.target.value)}
+          />
+        </div>
+        <div className="space-y-1">
+          <Label>Provider</Label>
+          <Select
+            value={provider}
+            onValueChange={(v) => setProvider(v as AmplifierProvider)}
+          >
+            <SelectTrigger className="w-full">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              {PROVIDERS.map((p) => (
+                <SelectItem key={p.key} value={p.key}>
+                  {p.label}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+        </div>
+        <div className="space-y-1">
+          <Label htmlFor="profile-model">Model</Label>
+          <Input
+            id="profile-model"
+            placeholder={placeholder}
+            value={model}
+            onChange={(e) => setModel(e.target.value)}
+          />
+        </div>
+        <div className="space-y-1 sm:col-span-2">
+          <Label htmlFor="profile-url">
+            {provider === "azure" ? "Endpoint" : "Base URL (optional)"}
+          </Label>
+          <Input
+            id="profile-url"
+            placeholder={
+              provider === "azure" ? "https://<resource>.openai.azure.com" : ""
+            }
+            value={baseUrl}
+            onChange={(e) => setBaseUrl(e.target.value)}
+          />
+        </div>
+        {provider !== "local" && (
+          <div className="space-y-1">
+            <Label htmlFor="profile-key">API key (optional)</Label>
+            <Input
+              id="profile-key"
+              type="password"
+              value={apiKey}
+              onChange={(e) => setApiKey(e.target.value)}
+            />
+          </div>
+        )}
+      </div>
+      {error && <p className="text-sm text-destructive">{error}</p>}
+      <Button
+        size="sm"
+        onClick={handleSave}
+        disabled={!name.trim() || !model.trim()}
+      >
+        Save Profile
+      </Button>
+    </div>
+  )
+}
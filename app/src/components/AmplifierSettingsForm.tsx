@@ -0,0 +1,134 @@
+import { useEffect, useState } from "react"
+import { Button } from "@/components/ui/button"
+import { Input } from "@/components/ui/input"
+import { Label } from "@/components/ui/label"
+import { api } from "@/api"
+import type { AmplifierProviderSettings, AmplifierSettingsFile } from "@/types"
+
+interface AmplifierSettingsFormProps {
+  projectName: string
+}
+
+export function AmplifierSettingsForm({ projectName }: AmplifierSettingsFormProps) {
+  const [file, setFile] = useState<AmplifierSettingsFile | null>(null)
+  const [providers, setProviders] = useState<AmplifierProviderSettings[]>([])
+  const [saving, setSaving] = useState(false)
+  const [error, setError] = useState<string | null>(null)
+
+  function load(f: AmplifierSettingsFile) {
+    setFile(f)
+    setProviders(f.providers)
+  }
+
+  useEffect(() => {
+    api
+      .getAmplifierSettings(projectName)
+      .then(load)
+      .catch(() => {})
+  }, [projectName])
+
+  function setProvider(index: number, changes: Partial<AmplifierProviderSettings>) {
+    setProviders((prev) =>
+      prev.map((p, i) => (i === index ? { ...p, ...changes } : p)),
+    )
+  }
+
+  async function handleSave() {
+    setSaving(true)
+    setError(null)
+    // JSON is valid YAML, so the patch needs no YAML serializer.
+    const patch = {
+      config: {
+        providers: providers.map((p) => ({
+          module: p.module,
+          config: {
+            default_model: p.default_model || null,
+            [p.base_url_key]: p.base_url || null,
+            enable_prompt_caching: p.enable_prompt_caching,
+          },
+        })),
+      },
+    }
+    try {
+      load(await api.updateAmplifierSettings(projectName, JSON.stringify(patch)))
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Failed to save settings")
+    } finally {
+      setSaving(false)
+    }
+  }
+
+  async function handleReset() {
+    setError(null)
+    try {
+      await api.resetAmplifierSettings(projectName)
+      load(await api.getAmplifierSettings(projectName))
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Failed to reset settings")
+    }
+  }
+
+  if (!file) return null
+
+  return (
+    <div className="space-y-4">
+      <p className="text-sm text-muted-foreground">
+        {file.managed
+          ? "Written from this project's Amplifier profile before each session. Saving here makes it yours."
+          : "Edited by you, so profile changes no longer apply."}{" "}
+        <code className="text-xs">{file.path}</code>
+      </p>
+      {file.error && <p className="text-sm text-destructive">{file.error}</p>}
+      {providers.map((p, index) => (
+        <div key={p.module} className="grid gap-3 sm:grid-cols-2">
+          <div className="text-sm font-medium sm:col-span-2">{p.module}</div>
+          <div className="space-y-1">
+            <Label htmlFor={`amp-model-${index}`}>Model</Label>
+            <Input
+              id={`amp-model-${index}`}
+              value={p.default_model ?? ""}
+              onChange={(e) => setProvider(index, { default_model: e.target.value })}
+            />
+          </div>
+          <div className="space-y-1">
+            <Label htmlFor={`amp-url-${index}`}>Base URL</Label>
+            <Input
+              id={`amp-url-${index}`}
+              value={p.base_url ?? ""}
+              onChange={(e) => setProvider(index, { base_url: e.target.value })}
+            />
+          </div>
+          {p.enable_prompt_caching !== null && (
+            <label className="flex items-center gap-2 text-sm sm:col-span-2">
+              <input
+                type="checkbox"
+                checked={p.enable_prompt_caching}
+                onChange={() =>
+                  setProvider(index, {
+                    enable_prompt_caching: !p.enable_prompt_caching,
+                  })
+                }
+              />
+              Prompt caching
+            </label>
+          )}
+        </div>
+      ))}
+      {error && <p className="text-sm text-destructive">{error}</p>}
+      <div className="flex gap-2">
+        <Button
+          size="sm"
+          onClick={handleSave}
+          disabled={saving || file.error !== null}
+        >
+          {saving ? "Saving..." : "Save Amplifier Settings"}
+        </Button>
+        {!file.managed && (
+          <Button variant="ghost" size="sm" onClick={handleReset}>
+            Reset to Profile
+          </Button>
+        )}
+      </div>
+    </div>
+  )
+}
@@ -0,0 +1,189 @@
+import { useEffect, useState } from "react"
+import { Settings } from "lucide-react"
+import { Button } from "@/components/ui/button"
+import { Input } from "@/components/ui/input"
+import { Label } from "@/components/ui/label"
+import { api } from "@/api"
+import { setDesktopNotificationSettings } from "@/desktopNotifications"
+import type { AppSettings } from "@/types"
+
+const SETTINGS_FILE = "~/attractor-settings.json"
+
+export function AppSettingsForm() {
+  const [saved, setSaved] = useState<AppSettings | null>(null)
+  const [syncMinutes, setSyncMinutes] = useState("")
+  const [maxSessions, setMaxSessions] = useState("")
+  const [defaultLabels, setDefaultLabels] = useState("")
+  const [editorCommand, setEditorCommand] = useState("")
+  const [terminalCommand, setTerminalCommand] = useState("")
+  const [commitName, setCommitName] = useState("")
+  const [commitEmail, setCommitEmail] = useState("")
+  const [commitAsBot, setCommitAsBot] = useState(false)
+  const [saving, setSaving] = useState(false)
+  const [error, setError] = useState<string | null>(null)
+
+  function load(s: AppSettings) {
+    setSaved(s)
+    setSyncMinutes(String(s.auto_sync_interval / 60))
+    setMaxSessions(String(s.max_amplifier_sessions))
+    setDefaultLabels(s.default_labels.join(", "))
+    setEditorCommand(s.editor_command ?? "")
+    setTerminalCommand(s.terminal_command ?? "")
+    setCommitName(s.commit_name ?? "")
+    setCommitEmail(s.commit_email ?? "")
+    setCommitAsBot(s.commit_as_bot)
+  }
+
+  useEffect(() => {
+    api
+      .getAppSettings()
+      .then(load)
+      .catch(() => {})
+  }, [])
+
+  async function handleSave() {
+    setSaving(true)
+    setError(null)
+    try {
+      load(
+        await api.updateAppSettings({
+          auto_sync_interval: Math.round(Number(syncMinutes) * 60),
+          max_amplifier_sessions: Number(maxSessions),
+          default_labels: defaultLabels.split(","),
+          editor_command: editorCommand.trim() || null,
+          terminal_command: terminalCommand.trim() || null,
+          commit_name: commitName.trim() || null,
+          commit_email: commitEmail.trim() || null,
+          commit_as_bot: commitAsBot,
+        }),
+      )
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Failed to save settings")
+    } finally {
+      setSaving(false)
+    }
+  }
+
+  async function handleExport() {
+    const path = window.prompt("Export settings to file", SETTINGS_FILE)
+    if (!path) return
+    setError(null)
+    try {
+      await api.exportSettings(path)
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Failed to export settings")
+    }
+  }
+
+  async function handleImport() {
+    const path = window.prompt("Import settings from file", SETTINGS_FILE)
+    if (!path) return
+    setError(null)
+    try {
+      const cfg = await api.importSettings(path)
+      load(cfg.settings)
+      await setDesktopNotificationSettings(cfg.desktop_notifications)
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Failed to import settings")
+    }
+  }
+
+  if (!saved) return null
+
+  return (
+    <div className="space-y-3">
+      <h2 className="flex items-center gap-2 text-sm font-medium text-muted-foreground">
+        <Settings className="h-4 w-4" />
+        Settings
+      </h2>
+      <div className="grid gap-3 sm:grid-cols-3">
+        <div className="space-y-1">
+          <Label htmlFor="auto-sync">Auto-sync every (minutes, 0 = off)</Label>
+          <Input
+            id="auto-sync"
+            type="number"
+            min={0}
+            value={syncMinutes}
+            onChange={(e) => setSyncMinutes(e.target.value)}
+          />
+        </div>
+        <div className="space-y-1">
+          <Label htmlFor="max-sessions">Amplifier sessions (0 = no limit)</Label>
+          <Input
+            id="max-sessions"
+            type="number"
+            min={0}
+            value={maxSessions}
+            onChange={(e) => setMaxSessions(e.target.value)}
+          />
+        </div>
+        <div className="space-y-1">
+          <Label htmlFor="default-labels">Default labels</Label>
+          <Input
+            id="default-labels"
+            placeholder="bug, triage"
+            value={defaultLabels}
+            onChange={(e) => setDefaultLabels(e.target.value)}
+          />
+        </div>
+        <div className="space-y-1 sm:col-span-3">
+          <Label htmlFor="editor-command">Editor command</Label>
+          <Input
+            id="editor-command"
+            placeholder="Detected automatically, e.g. zed {path}:{line}"
+            value={editorCommand}
+            onChange={(e) => setEditorCommand(e.target.value)}
+          />
+        </div>
+        <div className="space-y-1 sm:col-span-3">
+          <Label htmlFor="terminal-command">Terminal command</Label>
+          <Input
+            id="terminal-command"
+            placeholder="Detected automatically, e.g. kitty --directory {path}"
+            value={terminalCommand}
+            onChange={(e) => setTerminalCommand(e.target.value)}
+          />
+        </div>
+        <div className="space-y-1">
+          <Label htmlFor="commit-name">Commit author name</Label>
+          <Input
+            id="commit-name"
+            placeholder="GitHub login"
+            value={commitName}
+            onChange={(e) => setCommitName(e.target.value)}
+          />
+        </div>
+        <div className="space-y-1 sm:col-span-2">
+          <Label htmlFor="commit-email">Commit author email</Label>
+          <Input
+            id="commit-email"
+            type="email"
+            placeholder="GitHub commit email"
+            value={commitEmail}
+            onChange={(e) => setCommitEmail(e.target.value)}
+          />
+        </div>
+        <label className="flex items-center gap-2 text-sm sm:col-span-3">
+          <input
+            type="checkbox"
+            checked={commitAsBot}
+            onChange={() => setCommitAsBot(!commitAsBot)}
+          />
+          Commit to stores as attractor-app[bot]
+        </label>
+      </div>
+      {error && <p className="text-sm text-destructive">{error}</p>}
+      <div className="flex gap-2">
+        <Button size="sm" onClick={handleSave} disabled={saving}>
+          Save Settings
+        </Button>
+        <Button variant="ghost" size="sm" onClick={handleExport}>
+          Export...
+        </Button>
+        <Button variant="ghost" size="sm" onClick={handleImport}>
+          Import...
+        </Button>
+      </div>
+    </div>
+  )
+}
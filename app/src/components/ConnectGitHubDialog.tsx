@@ -1,4 +1,4 @@
-import { useState } from "react"
+import { useEffect, useState } from "react"
 import { ExternalLink } from "lucide-react"
 import { Button } from "@/components/ui/button"
 import {
@@ -11,9 +11,16 @@ import {
 import { Input } from "@/components/ui/input"
 import { Label } from "@/components/ui/label"
 import { Tabs, TabsContent, TabsList, TabsTrigger } from "@/components/ui/tabs"
-import { api } from "@/api"
+import { api, ApiError } from "@/api"
 import type { StoreStatus } from "@/types"
 
+function errorMessage(err: unknown, fallback: string): string {
+  if (err instanceof ApiError && err.code === "SAML_SSO_REQUIRED") {
+    return `${err.message} Authorize it at ${err.detail.authorization_url}`
+  }
+  return err instanceof Error ? err.message : fallback
+}
+
 interface ConnectGitHubDialogProps {
   open: boolean
   onOpenChange: (open: boolean) => void
@@ -35,6 +42,7 @@ export function ConnectGitHubDialog({
 
   // Create new repo tab
   const [repoName, setRepoName] = useState(`attractor-store-${projectName}`)
+  const [alternatives, setAlternatives] = useState<string[]>([])
   const [isPrivate, setIsPrivate] = useState(true)
   const [description, setDescription] = useState(`Issues store for ${projectName}`)
   const [creating, setCreating] = useState(false)
@@ -46,12 +54,25 @@ export function ConnectGitHubDialog({
 
   const [activeTab, setActiveTab] = useState("existing")
 
+  useEffect(() => {
+    if (!open) return
+    const fallback = `attractor-store-${projectName}`
+    api
+      .suggestRepoName(projectName)
+      .then(({ repo_name, alternatives }) => {
+        setRepoName((current) => (current === fallback ? repo_name : current))
+        setAlternatives(alternatives)
+      })
+      .catch(() => {})
+  }, [open, projectName])
+
   function resetState() {
     setOwner("")
     setRepo("")
     setConnecting(false)
     setConnectError(null)
     setRepoName(`attractor-store-${projectName}`)
+    setAlternatives([])
     setIsPrivate(true)
     setDescription(`Issues store for ${projectName}`)
     setCreating(false)
@@ -69,7 +90,7 @@ export function ConnectGitHubDialog({
       onConnected(store)
       onOpenChange(false)
     } catch (err) {
-      setConnectError(err instanceof Error ? err.message : "Failed to connect")
+      setConnectError(errorMessage(err, "Failed to connect"))
     } finally {
       setConnecting(false)
     }
@@ -90,23 +111,14 @@ export function ConnectGitHubDialog({
       onConnected(store)
       onOpenChange(false)
     } catch (err) {
-      const message = err instanceof Error ? err.message : "Failed to create repository"
-      // Try to parse structured error from FastAPI's {"detail": {...}} envelope
-      try {
-        const parsed = JSON.parse(message)
-        const detail = parsed.detail ?? parsed
-        const inner = typeof detail === "string" ? JSON.parse(detail) : detail
-        if (inner.error === "REPO_CREATE_FORBIDDEN") {
-          setCreateForbidden({
-            create_url: inner.create_url,
-            instructions: inner.instructions,
-          })
-          return
-        }
-      } catch {
-        // Not a structured error
+      if (err instanceof ApiError && err.code === "REPO_CREATE_FORBIDDEN") {
+        setCreateForbidden({
+          create_url: err.detail.create_url as string,
+          instructions: err.detail.instructions as string,
+        })
+        return
       }
-      setCreateError(message)
+      setCreateError(errorMessage(err, "Failed to create repository"))
     } finally {
       setCreating(false)
     }
@@ -208,6 +220,24 @@ export function ConnectGitHubDialog({
                     onChange={(e) => setRepoName(e.target.value)}
                     disabled={creating}
                   />
+                  {alternatives.length > 0 && (
+                    <p className="text-xs text-muted-foreground">
+                      Also available:{" "}
+                      {alternatives.map((name, i) => (
+                        <span key={name}>
+                          {i > 0 && ", "}
+                          <button
+                            type="button"
+                            className="underline"
+                            onClick={() => setRepoName(name)}
+                            disabled={creating}
+                          >
+                            {name}
+                          </button>
+                        </span>
+                      ))}
+                    </p>
+                  )}
                 </div>
                 <div className="space-y-2">
                   <Label>Description</Label>
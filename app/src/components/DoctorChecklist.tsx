@@ -0,0 +1,83 @@
+import { useState } from "react"
+import { CheckCircle2, Loader2, XCircle } from "lucide-react"
+import { Button } from "@/components/ui/button"
+import { api } from "@/api"
+import type { DoctorReport } from "@/types"
+
+interface DoctorChecklistProps {
+  projectName?: string
+}
+
+export function DoctorChecklist({ projectName }: DoctorChecklistProps) {
+  const [report, setReport] = useState<DoctorReport | null>(null)
+  const [running, setRunning] = useState(false)
+  const [error, setError] = useState<string | null>(null)
+  const [copied, setCopied] = useState(false)
+
+  async function handleRun() {
+    setRunning(true)
+    setError(null)
+    try {
+      setReport(await api.runDoctor(projectName))
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Health check failed")
+    } finally {
+      setRunning(false)
+    }
+  }
+
+  async function handleCopyLogs() {
+    setError(null)
+    try {
+      const entries = await api.getRecentLogs("DEBUG", 500)
+      const text = entries
+        .reverse()
+        .map((e) => JSON.stringify(e))
+        .join("\n")
+      await navigator.clipboard.writeText(text)
+      setCopied(true)
+      setTimeout(() => setCopied(false), 2000)
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Failed to copy logs")
+    }
+  }
+
+  return (
+    <div className="space-y-3">
+      <div className="flex gap-2">
+        <Button
+          variant="outline"
+          size="sm"
+          onClick={handleRun}
+          disabled={running}
+        >
+          {running && <Loader2 className="mr-2 h-4 w-4 animate-spin" />}
+          {report ? "Run Again" : "Run Health Check"}
+        </Button>
+        <Button variant="ghost" size="sm" onClick={handleCopyLogs}>
+          {copied ? "Copied" : "Copy Recent Logs"}
+        </Button>
+      </div>
+      {error && <p className="text-sm text-destructive">{error}</p>}
+      {report && (
+        <ul className="space-y-2">
+          {report.checks.map((check) => (
+            <li key={check.name} className="flex gap-2 text-sm">
+              {check.ok ? (
+                <CheckCircle2 className="mt-0.5 h-4 w-4 shrink-0 text-green-600" />
+              ) : (
+                <XCircle className="mt-0.5 h-4 w-4 shrink-0 text-destructive" />
+              )}
+              <div>
+                <p>{check.detail}</p>
+                {!check.ok && check.fix && (
+                  <p className="text-xs text-muted-foreground">{check.fix}</p>
+                )}
+              </div>
+            </li>
+          ))}
+        </ul>
+      )}
+    </div>
+  )
+}
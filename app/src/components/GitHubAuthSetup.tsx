@@ -1,9 +1,19 @@
 import { useCallback, useEffect, useState } from "react"
-import { ExternalLink, KeyRound, Trash2, Check, AlertCircle } from "lucide-react"
+import {
+  ExternalLink,
+  KeyRound,
+  Trash2,
+  Check,
+  AlertCircle,
+  Github,
+  Terminal,
+  LogOut,
+} from "lucide-react"
 import { Button } from "@/components/ui/button"
 import { Input } from "@/components/ui/input"
 import { api } from "@/api"
-import type { GitHubStatus } from "@/types"
+import { ws } from "@/ws"
+import type { DeviceLogin, GitHubStatus } from "@/types"
 
 interface GitHubAuthSetupProps {
   onStatusChange?: (status: GitHubStatus) => void
@@ -17,6 +27,7 @@ export function GitHubAuthSetup({ onStatusChange }: GitHubAuthSetupProps) {
   const [error, setError] = useState<string | null>(null)
   const [removing, setRemoving] = useState(false)
   const [showTokenInput, setShowTokenInput] = useState(false)
+  const [deviceLogin, setDeviceLogin] = useState<DeviceLogin | null>(null)
 
   const fetchStatus = useCallback(async () => {
     setLoading(true)
@@ -35,6 +46,51 @@ export function GitHubAuthSetup({ onStatusChange }: GitHubAuthSetupProps) {
     fetchStatus()
   }, [fetchStatus])
 
+  useEffect(() => {
+    const offCompleted = ws.on("github:login_completed", () => {
+      setDeviceLogin(null)
+      fetchStatus()
+    })
+    const offFailed = ws.on("github:login_failed", (data) => {
+      const { error: message } = data as { error: string | null }
+      setDeviceLogin(null)
+      setError(message ?? "GitHub sign-in failed")
+    })
+    return () => {
+      offCompleted()
+      offFailed()
+    }
+  }, [fetchStatus])
+
+  async function handleDeviceLogin() {
+    setError(null)
+    try {
+      const login = await api.startDeviceLogin()
+      setDeviceLogin(login)
+      window.open(login.verification_uri, "_blank")
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Failed to start GitHub sign-in")
+    }
+  }
+
+  async function handleCancelDeviceLogin() {
+    setDeviceLogin(null)
+    await api.cancelDeviceLogin().catch(() => undefined)
+  }
+
+  async function handleUseGitCredentials() {
+    setSaving(true)
+    setError(null)
+    try {
+      await api.addCredentialHelperAccount()
+      await fetchStatus()
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "No Git credentials found for github.com")
+    } finally {
+      setSaving(false)
+    }
+  }
+
   async function handleSaveToken() {
     if (!token.trim()) return
     setSaving(true)
@@ -65,6 +121,27 @@ export function GitHubAuthSetup({ onStatusChange }: GitHubAuthSetupProps) {
     }
   }
 
+  async function handleLogout() {
+    if (!window.confirm("Sign out of every GitHub account on this machine?")) {
+      return
+    }
+    const wipeClones = window.confirm(
+      "Also delete the local copies of GitHub-backed projects? " +
+        "Their issues stay on GitHub and can be cloned again.",
+    )
+    setRemoving(true)
+    setError(null)
+    try {
+      await api.logoutGitHub(wipeClones)
+      setShowTokenInput(false)
+      await fetchStatus()
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Failed to sign out")
+    } finally {
+      setRemoving(false)
+    }
+  }
+
   async function handleOpenPatUrl() {
     try {
       const { url } = await api.getPatUrl()
@@ -135,6 +212,15 @@ export function GitHubAuthSetup({ onStatusChange }: GitHubAuthSetupProps) {
               <Trash2 className="mr-2 h-3.5 w-3.5" />
               {removing ? "Removing..." : "Remove Token"}
             </Button>
+            <Button
+              size="sm"
+              variant="outline"
+              onClick={handleLogout}
+              disabled={removing}
+            >
+              <LogOut className="mr-2 h-3.5 w-3.5" />
+              Sign Out
+            </Button>
           </div>
         )}
         {error && <p className="text-sm text-destructive">{error}</p>}
@@ -149,8 +235,42 @@ export function GitHubAuthSetup({ onStatusChange }: GitHubAuthSetupProps) {
         <span>No GitHub token configured</span>
       </div>
       <p className="text-sm text-muted-foreground">
-        A Personal Access Token (PAT) is needed to connect your store to GitHub.
+        Sign in with GitHub or paste a Personal Access Token (PAT) to connect your store to GitHub.
       </p>
+      {deviceLogin ? (
+        <div className="space-y-2">
+          <p className="text-sm">
+            Enter <span className="font-mono font-medium">{deviceLogin.user_code}</span> at{" "}
+            <a
+              href={deviceLogin.verification_uri}
+              target="_blank"
+              rel="noreferrer"
+              className="underline"
+            >
+              {deviceLogin.verification_uri}
+            </a>
+          </p>
+          <Button size="sm" variant="ghost" onClick={handleCancelDeviceLogin}>
+            Cancel
+          </Button>
+        </div>
+      ) : (
+        <div className="flex gap-2">
+          <Button size="sm" onClick={handleDeviceLogin}>
+            <Github className="mr-2 h-3.5 w-3.5" />
+            Sign in with GitHub
+          </Button>
+          <Button
+            size="sm"
+            variant="outline"
+            onClick={handleUseGitCredentials}
+            disabled={saving}
+          >
+            <Terminal className="mr-2 h-3.5 w-3.5" />
+            Use Git Credentials
+          </Button>
+        </div>
+      )}
       <div className="space-y-2">
         <Button size="sm" variant="outline" onClick={handleOpenPatUrl}>
           <ExternalLink className="mr-2 h-3.5 w-3.5" />
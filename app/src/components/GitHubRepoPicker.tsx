@@ -24,7 +24,7 @@ export function GitHubRepoPicker({ onSelect, disabled }: GitHubRepoPickerProps)
     setLoading(true)
     setError(null)
     try {
-      const list = await api.listRepos(q || undefined)
+      const list = await api.listRepos(undefined, undefined, { search: q || undefined })
       if (id === abortRef.current) {
         setRepos(list)
       }
@@ -1,4 +1,4 @@
-import { useState } from "react"
+import { useEffect, useState } from "react"
 import { ArrowLeft, FolderOpen, Github, Plus, Loader2 } from "lucide-react"
 import { Button } from "@/components/ui/button"
 import {
@@ -14,7 +14,8 @@ import { Card, CardContent } from "@/components/ui/card"
 import { PathInput } from "@/components/PathInput"
 import { GitHubRepoPicker } from "@/components/GitHubRepoPicker"
 import { api } from "@/api"
-import type { PathValidationResponse } from "@/types"
+import { ws } from "@/ws"
+import type { OpenProgress, PathValidationResponse } from "@/types"
 
 type Mode = null | "folder" | "github" | "empty"
 
@@ -53,11 +54,27 @@ export function NewProjectFlow({
   const [selectedOwner, setSelectedOwner] = useState("")
   const [selectedRepo, setSelectedRepo] = useState("")
 
+  const [progress, setProgress] = useState<OpenProgress | null>(null)
+  const [operationId, setOperationId] = useState<string | null>(null)
+
   // Empty mode
   const [emptyPath, setEmptyPath] = useState("")
   const [emptyPathValidation, setEmptyPathValidation] =
     useState<PathValidationResponse | null>(null)
 
+  useEffect(() => {
+    if (!creating || mode !== "github") return
+    const name = projectName.trim()
+    const off = ws.on("project:open_progress", (data) => {
+      const p = data as OpenProgress
+      if (p.project === name) setProgress(p)
+    })
+    return () => {
+      off()
+      setProgress(null)
+    }
+  }, [creating, mode, projectName])
+
   function reset() {
     setMode(null)
     setProjectName("")
@@ -114,11 +131,14 @@ export function NewProjectFlow({
           path: folderPath.trim(),
         })
       } else if (mode === "github") {
+        const id = crypto.randomUUID()
+        setOperationId(id)
         await api.createProjectAdvanced({
           name: projectName.trim(),
           mode: "github",
           owner: selectedOwner,
           repo: selectedRepo,
+          operation_id: id,
         })
       } else if (mode === "empty") {
         await api.createProjectAdvanced({
@@ -135,9 +155,14 @@ export function NewProjectFlow({
       )
     } finally {
       setCreating(false)
+      setOperationId(null)
     }
   }
 
+  function handleCancelClone() {
+    if (operationId) api.cancelOperation(operationId).catch(() => {})
+  }
+
   return (
     <Dialog open={open} onOpenChange={handleOpenChange}>
       <DialogContent className="sm:max-w-lg">
@@ -279,19 +304,43 @@ export function NewProjectFlow({
                 </div>
               </div>
             )}
+            {progress && (
+              <div className="space-y-1">
+                <div className="h-2 overflow-hidden rounded-full bg-muted">
+                  <div
+                    className="h-full bg-primary transition-all"
+                    style={{ width: `${progress.percent}%` }}
+                  />
+                </div>
+                <p className="text-xs text-muted-foreground">
+                  {progress.phase} {progress.current}/{progress.total}
+                  {progress.bytes !== null &&
+                    `, ${(progress.bytes / (1 << 20)).toFixed(1)} MiB`}
+                </p>
+              </div>
+            )}
             {error && <p className="text-sm text-destructive">{error}</p>}
             <div className="flex items-center justify-between">
               <Button variant="ghost" size="sm" onClick={handleBack}>
                 <ArrowLeft className="mr-2 h-4 w-4" />
                 Back
               </Button>
-              <Button
-                onClick={handleCreate}
-                disabled={creating || !selectedRepo}
-              >
-                {creating && <Loader2 className="mr-2 h-4 w-4 animate-spin" />}
-                {creating ? "Cloning..." : "Clone & Create"}
-              </Button>
+              <div className="flex gap-2">
+                {creating && operationId && (
+                  <Button variant="outline" onClick={handleCancelClone}>
+                    Cancel
+                  </Button>
+                )}
+                <Button
+                  onClick={handleCreate}
+                  disabled={creating || !selectedRepo}
+                >
+                  {creating && (
+                    <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                  )}
+                  {creating ? "Cloning..." : "Clone & Create"}
+                </Button>
+              </div>
             </div>
           </div>
         )}
@@ -0,0 +1,55 @@
+import { useEffect, useState } from "react"
+import { Bell } from "lucide-react"
+import { api } from "@/api"
+import { setDesktopNotificationSettings } from "@/desktopNotifications"
+import type { DesktopNotificationSettings } from "@/types"
+
+const OPTIONS: { key: keyof DesktopNotificationSettings; label: string }[] = [
+  { key: "amplifier", label: "Amplifier sessions finish or fail" },
+  { key: "sync_conflicts", label: "Sync conflicts" },
+  { key: "milestones", label: "Milestones coming due" },
+]
+
+export function NotificationPreferences() {
+  const [prefs, setPrefs] = useState<DesktopNotificationSettings | null>(null)
+
+  useEffect(() => {
+    api
+      .getConfig()
+      .then((cfg) => setPrefs(cfg.desktop_notifications))
+      .catch(() => {})
+  }, [])
+
+  async function toggle(key: keyof DesktopNotificationSettings) {
+    if (!prefs) return
+    const next = { ...prefs, [key]: !prefs[key] }
+    setPrefs(next)
+    try {
+      const cfg = await api.updateConfig({ desktop_notifications: next })
+      await setDesktopNotificationSettings(cfg.desktop_notifications)
+    } catch {
+      setPrefs(prefs)
+    }
+  }
+
+  if (!prefs) return null
+
+  return (
+    <div className="space-y-2">
+      <h2 className="flex items-center gap-2 text-sm font-medium text-muted-foreground">
+        <Bell className="h-4 w-4" />
+        Desktop notifications when the window is in the background
+      </h2>
+      {OPTIONS.map(({ key, label }) => (
+        <label key={key} className="flex items-center gap-2 text-sm">
+          <input
+            type="checkbox"
+            checked={prefs[key]}
+            onChange={() => toggle(key)}
+          />
+          {label}
+        </label>
+      ))}
+    </div>
+  )
+}
@@ -0,0 +1,204 @@
+import { useEffect, useState } from "react"
+import { Loader2, Sparkles } from "lucide-react"
+import { Button } from "@/components/ui/button"
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from "@/components/ui/dialog"
+import { Label } from "@/components/ui/label"
+import { Textarea } from "@/components/ui/textarea"
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select"
+import { api } from "@/api"
+import type { MilestoneListItem, MilestonePlan } from "@/types"
+
+interface PlanMilestoneDialogProps {
+  open: boolean
+  onOpenChange: (open: boolean) => void
+  projectName: string
+  onApplied: () => void
+}
+
+export function PlanMilestoneDialog({
+  open,
+  onOpenChange,
+  projectName,
+  onApplied,
+}: PlanMilestoneDialogProps) {
+  const [milestones, setMilestones] = useState<MilestoneListItem[]>([])
+  const [milestone, setMilestone] = useState<string>("")
+  const [constraints, setConstraints] = useState("")
+  const [plan, setPlan] = useState<MilestonePlan | null>(null)
+  const [selected, setSelected] = useState<Set<number>>(new Set())
+  const [busy, setBusy] = useState(false)
+  const [error, setError] = useState<string | null>(null)
+
+  useEffect(() => {
+    if (!open) return
+    api
+      .listMilestones(projectName)
+      .then((all) => setMilestones(all.filter((m) => m.state === "open")))
+      .catch(() => {})
+  }, [open, projectName])
+
+  function handleOpenChange(next: boolean) {
+    onOpenChange(next)
+    if (!next) {
+      setMilestone("")
+      setConstraints("")
+      setPlan(null)
+      setError(null)
+    }
+  }
+
+  async function handlePlan() {
+    setBusy(true)
+    setError(null)
+    try {
+      const result = await api.planMilestone(
+        projectName,
+        Number(milestone),
+        constraints.trim(),
+      )
+      setPlan(result)
+      setSelected(new Set(result.issues.map((i) => i.number)))
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Amplifier failed")
+    } finally {
+      setBusy(false)
+    }
+  }
+
+  async function handleApply() {
+    if (!plan) return
+    setBusy(true)
+    setError(null)
+    try {
+      await api.assignMilestone(projectName, plan.milestone, [...selected])
+      onApplied()
+      handleOpenChange(false)
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Failed to apply the plan")
+    } finally {
+      setBusy(false)
+    }
+  }
+
+  function toggle(number: number) {
+    setSelected((prev) => {
+      const next = new Set(prev)
+      if (next.has(number)) next.delete(number)
+      else next.add(number)
+      return next
+    })
+  }
+
+  return (
+    <Dialog open={open} onOpenChange={handleOpenChange}>
+      <DialogContent className="max-w-lg">
+        <DialogHeader>
+          <DialogTitle>Plan Milestone</DialogTitle>
+          <DialogDescription>
+            Amplifier proposes open issues for the milestone. Nothing changes
+            until you apply the plan.
+          </DialogDescription>
+        </DialogHeader>
+        {plan ? (
+          <div className="space-y-3">
+            {plan.summary && <p className="text-sm">{plan.summary}</p>}
+            <div className="max-h-80 space-y-2 overflow-y-auto">
+              {plan.issues.map((issue) => (
+                <label key={issue.number} className="flex gap-2 text-sm">
+                  <input
+                    type="checkbox"
+                    className="mt-1"
+                    checked={selected.has(issue.number)}
+                    onChange={() => toggle(issue.number)}
+                  />
+                  <span>
+                    <span className="font-medium">
+                      #{issue.number} {issue.title}
+                    </span>
+                    {issue.estimate && (
+                      <span className="ml-2 rounded bg-muted px-1 text-xs">
+                        {issue.estimate}
+                      </span>
+                    )}
+                    {issue.rationale && (
+                      <span className="block text-xs text-muted-foreground">
+                        {issue.rationale}
+                      </span>
+                    )}
+                  </span>
+                </label>
+              ))}
+              {plan.issues.length === 0 && (
+                <p className="text-sm text-muted-foreground">
+                  No issues proposed
+                </p>
+              )}
+            </div>
+          </div>
+        ) : (
+          <div className="space-y-3">
+            <div className="space-y-1">
+              <Label>Milestone</Label>
+              <Select value={milestone} onValueChange={setMilestone}>
+                <SelectTrigger className="w-full">
+                  <SelectValue placeholder="Choose an open milestone" />
+                </SelectTrigger>
+                <SelectContent>
+                  {milestones.map((m) => (
+                    <SelectItem key={m.number} value={String(m.number)}>
+                      {m.title}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </div>
+            <div className="space-y-1">
+              <Label htmlFor="plan-constraints">Constraints</Label>
+              <Textarea
+                id="plan-constraints"
+                placeholder="Two weeks for one person, bugs first"
+                value={constraints}
+                onChange={(e) => setConstraints(e.target.value)}
+              />
+            </div>
+          </div>
+        )}
+        {error && <p className="text-sm text-destructive">{error}</p>}
+        <DialogFooter>
+          {plan ? (
+            <>
+              <Button variant="outline" onClick={() => setPlan(null)} disabled={busy}>
+                Back
+              </Button>
+              <Button onClick={handleApply} disabled={busy || selected.size === 0}>
+                Add {selected.size} to Milestone
+              </Button>
+            </>
+          ) : (
+            <Button onClick={handlePlan} disabled={busy || !milestone}>
+              {busy ? (
+                <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+              ) : (
+                <Sparkles className="mr-2 h-4 w-4" />
+              )}
+              {busy ? "Planning..." : "Propose Plan"}
+            </Button>
+          )}
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  )
+}
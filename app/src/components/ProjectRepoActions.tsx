@@ -0,0 +1,132 @@
+import { useCallback, useEffect, useState } from "react"
+import { GitCommitHorizontal, Upload } from "lucide-react"
+import { Button } from "@/components/ui/button"
+import { Input } from "@/components/ui/input"
+import { Label } from "@/components/ui/label"
+import { api } from "@/api"
+import type { ProjectGitStatus } from "@/types"
+
+interface ProjectRepoActionsProps {
+  projectName: string
+}
+
+export function ProjectRepoActions({ projectName }: ProjectRepoActionsProps) {
+  const [projectPath, setProjectPath] = useState<string | null>(null)
+  const [git, setGit] = useState<ProjectGitStatus | null>(null)
+  const [message, setMessage] = useState("")
+  const [paths, setPaths] = useState("")
+  const [busy, setBusy] = useState(false)
+  const [status, setStatus] = useState<string | null>(null)
+
+  const refreshStatus = useCallback(() => {
+    api
+      .getProjectGitStatus(projectName)
+      .then(setGit)
+      .catch(() => setGit(null))
+  }, [projectName])
+
+  useEffect(() => {
+    api
+      .getProject(projectName)
+      .then((p) => {
+        setProjectPath(p.project_path)
+        if (p.project_path) refreshStatus()
+      })
+      .catch(() => {})
+  }, [projectName, refreshStatus])
+
+  async function run(action: () => Promise<string>) {
+    setBusy(true)
+    setStatus(null)
+    try {
+      setStatus(await action())
+    } catch (err) {
+      setStatus(err instanceof Error ? err.message : "Failed")
+    } finally {
+      setBusy(false)
+      refreshStatus()
+    }
+  }
+
+  function handleCommit() {
+    run(async () => {
+      const selected = paths
+        .split(",")
+        .map((p) => p.trim())
+        .filter(Boolean)
+      const { sha } = await api.commitProjectChanges(
+        projectName,
+        message,
+        selected.length > 0 ? selected : undefined,
+      )
+      setMessage("")
+      setPaths("")
+      return `Committed ${sha.slice(0, 7)}`
+    })
+  }
+
+  function handlePush() {
+    run(async () => {
+      await api.pushProject(projectName)
+      return "Pushed"
+    })
+  }
+
+  if (!projectPath) {
+    return (
+      <p className="text-sm text-muted-foreground">
+        This project has no project repository.
+      </p>
+    )
+  }
+
+  return (
+    <div className="space-y-3">
+      <p className="text-sm text-muted-foreground">
+        <code className="rounded bg-muted px-1 py-0.5">{projectPath}</code>
+      </p>
+      {git && (
+        <p className="text-sm text-muted-foreground">
+          {git.branch ?? "Detached HEAD"}
+          {git.upstream && ` (${git.ahead} ahead, ${git.behind} behind)`}
+          {" - "}
+          {git.clean
+            ? "no uncommitted changes"
+            : `${git.modified.length} modified, ${git.untracked.length} untracked`}
+        </p>
+      )}
+      <div className="space-y-1">
+        <Label htmlFor="project-commit-message">Commit message</Label>
+        <Input
+          id="project-commit-message"
+          value={message}
+          onChange={(e) => setMessage(e.target.value)}
+        />
+      </div>
+      <div className="space-y-1">
+        <Label htmlFor="project-commit-paths">Paths</Label>
+        <Input
+          id="project-commit-paths"
+          placeholder="All changes, or e.g. src/main.py, README.md"
+          value={paths}
+          onChange={(e) => setPaths(e.target.value)}
+        />
+      </div>
+      <div className="flex gap-2">
+        <Button
+          size="sm"
+          onClick={handleCommit}
+          disabled={busy || !message.trim()}
+        >
+          <GitCommitHorizontal className="mr-2 h-3.5 w-3.5" />
+          Commit
+        </Button>
+        <Button size="sm" variant="outline" onClick={handlePush} disabled={busy}>
+          <Upload className="mr-2 h-3.5 w-3.5" />
+          Push
+        </Button>
+      </div>
+      {status && <p className="text-sm text-muted-foreground">{status}</p>}
+    </div>
+  )
+}
@@ -0,0 +1,330 @@
+import { useEffect, useState } from "react"
+import { Button } from "@/components/ui/button"
+import { Input } from "@/components/ui/input"
+import { Label } from "@/components/ui/label"
+import { Textarea } from "@/components/ui/textarea"
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select"
+import { api } from "@/api"
+import type {
+  AmplifierProfiles,
+  CommentCommandsConfig,
+  IssueField,
+  MergeField,
+  MergePolicy,
+  ProjectAmplifierProfile,
+  ProjectSettings,
+} from "@/types"
+
+// Radix Select items cannot have an empty value.
+const DEFAULT_PROFILE = "__default"
+
+const FIELDS: { key: IssueField; label: string }[] = [
+  { key: "body", label: "Description" },
+  { key: "labels", label: "Labels" },
+  { key: "assignees", label: "Assignees" },
+]
+
+const MERGE_FIELDS: { key: MergeField; label: string }[] = [
+  { key: "title", label: "Title" },
+  { key: "body", label: "Description" },
+  { key: "state", label: "State" },
+  { key: "labels", label: "Labels" },
+  { key: "assignees", label: "Assignees" },
+  { key: "milestone", label: "Milestone" },
+]
+
+const MERGE_POLICIES: { key: MergePolicy; label: string }[] = [
+  { key: "prefer_latest", label: "Latest edit" },
+  { key: "prefer_local", label: "This machine" },
+  { key: "prefer_remote", label: "Remote" },
+  { key: "union", label: "Combine both" },
+  { key: "prefer_closed", label: "Closed" },
+  { key: "prefer_open", label: "Open" },
+]
+
+function policiesFor(field: MergeField) {
+  return MERGE_POLICIES.filter(({ key }) => {
+    if (key === "union") return field === "labels" || field === "assignees"
+    if (key === "prefer_closed" || key === "prefer_open") return field === "state"
+    return true
+  })
+}
+
+interface ProjectSettingsFormProps {
+  projectName: string
+}
+
+export function ProjectSettingsForm({ projectName }: ProjectSettingsFormProps) {
+  const [settings, setSettings] = useState<ProjectSettings | null>(null)
+  const [defaultLabels, setDefaultLabels] = useState("")
+  const [saving, setSaving] = useState(false)
+  const [error, setError] = useState<string | null>(null)
+  const [profiles, setProfiles] = useState<AmplifierProfiles | null>(null)
+  const [profile, setProfile] = useState<ProjectAmplifierProfile | null>(null)
+  const [commands, setCommands] = useState<CommentCommandsConfig | null>(null)
+
+  function load(s: ProjectSettings) {
+    setSettings(s)
+    setDefaultLabels(s.default_labels.join(", "))
+  }
+
+  useEffect(() => {
+    api
+      .getProjectSettings(projectName)
+      .then(load)
+      .catch(() => {})
+    api
+      .listAmplifierProfiles()
+      .then(setProfiles)
+      .catch(() => {})
+    api
+      .getProjectAmplifierProfile(projectName)
+      .then(setProfile)
+      .catch(() => {})
+    api
+      .getCommentCommands(projectName)
+      .then(setCommands)
+      .catch(() => {})
+  }, [projectName])
+
+  async function handleProfileChange(value: string) {
+    setError(null)
+    try {
+      setProfile(
+        await api.setProjectAmplifierProfile(
+          projectName,
+          value === DEFAULT_PROFILE ? null : value,
+        ),
+      )
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Failed to set profile")
+    }
+  }
+
+  async function handleSyncedCommandsChange() {
+    if (!commands) return
+    setError(null)
+    try {
+      setCommands(
+        await api.setCommentCommands(projectName, { synced: !commands.synced }),
+      )
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Failed to save setting")
+    }
+  }
+
+  function toggleRequired(field: IssueField) {
+    if (!settings) return
+    const required = settings.required_fields.includes(field)
+      ? settings.required_fields.filter((f) => f !== field)
+      : [...settings.required_fields, field]
+    setSettings({ ...settings, required_fields: required })
+  }
+
+  function setMergePolicy(field: MergeField, policy: MergePolicy) {
+    if (!settings) return
+    const policies = { ...settings.merge_policies }
+    if (policy === "prefer_latest") delete policies[field]
+    else policies[field] = policy
+    setSettings({ ...settings, merge_policies: policies })
+  }
+
+  async function handleSave() {
+    if (!settings) return
+    setSaving(true)
+    setError(null)
+    try {
+      load(
+        await api.updateProjectSettings(projectName, {
+          ...settings,
+          default_labels: defaultLabels
+            .split(",")
+            .map((l) => l.trim())
+            .filter(Boolean),
+          amplifier_instructions: settings.amplifier_instructions || null,
+          commit_template: settings.commit_template || null,
+        }),
+      )
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Failed to save settings")
+    } finally {
+      setSaving(false)
+    }
+  }
+
+  if (!settings) return null
+
+  return (
+    <div className="space-y-4">
+      <p className="text-sm text-muted-foreground">
+        Saved in the store, so everyone who syncs it gets the same policies.
+      </p>
+      <div className="space-y-1">
+        <Label htmlFor="project-default-labels">Default labels</Label>
+        <Input
+          id="project-default-labels"
+          placeholder="bug, triage"
+          value={defaultLabels}
+          onChange={(e) => setDefaultLabels(e.target.value)}
+        />
+      </div>
+      <div className="space-y-1">
+        <Label>Required when creating an issue</Label>
+        <div className="flex gap-4">
+          {FIELDS.map(({ key, label }) => (
+            <label key={key} className="flex items-center gap-2 text-sm">
+              <input
+                type="checkbox"
+                checked={settings.required_fields.includes(key)}
+                onChange={() => toggleRequired(key)}
+              />
+              {label}
+            </label>
+          ))}
+        </div>
+      </div>
+      <label className="flex items-center gap-2 text-sm">
+        <input
+          type="checkbox"
+          checked={settings.amplifier_enabled}
+          onChange={() =>
+            setSettings({
+              ...settings,
+              amplifier_enabled: !settings.amplifier_enabled,
+            })
+          }
+        />
+        Allow Amplifier sessions
+      </label>
+      <label className="flex items-center gap-2 text-sm">
+        <input
+          type="checkbox"
+          checked={settings.comment_commands}
+          onChange={() =>
+            setSettings({
+              ...settings,
+              comment_commands: !settings.comment_commands,
+            })
+          }
+        />
+        Run /close, /label, and other commands from comments
+      </label>
+      {commands && (
+        <label className="flex items-center gap-2 text-sm">
+          <input
+            type="checkbox"
+            checked={commands.synced}
+            disabled={!settings.comment_commands}
+            onChange={handleSyncedCommandsChange}
+          />
+          On this machine, also run them from your comments that arrive with a
+          sync (except /amplify)
+        </label>
+      )}
+      <label className="flex items-center gap-2 text-sm">
+        <input
+          type="checkbox"
+          checked={settings.sequential_ids}
+          onChange={() =>
+            setSettings({ ...settings, sequential_ids: !settings.sequential_ids })
+          }
+        />
+        Reserve issue numbers on the remote before creating issues
+      </label>
+      <div className="space-y-1">
+        <Label htmlFor="amplifier-instructions">Amplifier instructions</Label>
+        <Textarea
+          id="amplifier-instructions"
+          placeholder="Appended to every Amplifier prompt"
+          value={settings.amplifier_instructions ?? ""}
+          onChange={(e) =>
+            setSettings({ ...settings, amplifier_instructions: e.target.value })
+          }
+        />
+      </div>
+      {profiles && profile && profiles.profiles.length > 0 && (
+        <div className="space-y-1">
+          <Label>Amplifier profile on this machine</Label>
+          <Select
+            value={profile.profile ?? DEFAULT_PROFILE}
+            onValueChange={handleProfileChange}
+          >
+            <SelectTrigger size="sm" className="w-full">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              <SelectItem value={DEFAULT_PROFILE}>
+                Default ({profiles.default ?? "Anthropic"})
+              </SelectItem>
+              {profiles.profiles.map((p) => (
+                <SelectItem key={p.name} value={p.name}>
+                  {p.name}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+        </div>
+      )}
+      <div className="space-y-1">
+        <Label htmlFor="branch-template">Issue branch name</Label>
+        <Input
+          id="branch-template"
+          placeholder="issue/{number}-{slug}"
+          value={settings.branch_template}
+          onChange={(e) =>
+            setSettings({ ...settings, branch_template: e.target.value })
+          }
+        />
+      </div>
+      <div className="space-y-1">
+        <Label htmlFor="commit-template">Store commit message</Label>
+        <Input
+          id="commit-template"
+          placeholder="{summary}"
+          value={settings.commit_template ?? ""}
+          onChange={(e) =>
+            setSettings({ ...settings, commit_template: e.target.value })
+          }
+        />
+        <p className="text-xs text-muted-foreground">
+          Placeholders: {"{summary}"}, {"{action}"}, {"{number}"}, {"{actor}"}
+        </p>
+      </div>
+      <div className="space-y-1">
+        <Label>When an issue was edited on two machines, keep</Label>
+        <div className="grid grid-cols-2 gap-2 sm:grid-cols-3">
+          {MERGE_FIELDS.map(({ key, label }) => (
+            <div key={key} className="space-y-1">
+              <span className="text-xs text-muted-foreground">{label}</span>
+              <Select
+                value={settings.merge_policies[key] ?? "prefer_latest"}
+                onValueChange={(v) => setMergePolicy(key, v as MergePolicy)}
+              >
+                <SelectTrigger size="sm" className="w-full">
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  {policiesFor(key).map((policy) => (
+                    <SelectItem key={policy.key} value={policy.key}>
+                      {policy.label}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </div>
+          ))}
+        </div>
+      </div>
+      {error && <p className="text-sm text-destructive">{error}</p>}
+      <Button size="sm" onClick={handleSave} disabled={saving}>
+        {saving ? "Saving..." : "Save Project Settings"}
+      </Button>
+    </div>
+  )
+}
@@ -0,0 +1,80 @@
+import { useEffect, useState } from "react"
+import { Download, RefreshCw, X } from "lucide-react"
+import { Button } from "@/components/ui/button"
+import { api } from "@/api"
+import { ws } from "@/ws"
+import type { UpdateStatus } from "@/types"
+
+export function UpdateBanner() {
+  const [status, setStatus] = useState<UpdateStatus | null>(null)
+  const [installed, setInstalled] = useState(false)
+  const [installing, setInstalling] = useState(false)
+  const [error, setError] = useState<string | null>(null)
+
+  useEffect(() => {
+    api
+      .checkForUpdates()
+      .then(setStatus)
+      .catch(() => {
+        // Non-critical: the server checks again on its own interval
+      })
+    const offAvailable = ws.on("update:available", (data) => {
+      setStatus(data as UpdateStatus)
+    })
+    const offInstalled = ws.on("update:installed", () => {
+      setInstalled(true)
+    })
+    return () => {
+      offAvailable()
+      offInstalled()
+    }
+  }, [])
+
+  async function handleInstall() {
+    setInstalling(true)
+    setError(null)
+    try {
+      await api.installUpdate()
+      window.location.reload()
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Failed to install update")
+      setInstalling(false)
+    }
+  }
+
+  if (!installed && !status?.available) return null
+
+  return (
+    <div className="fixed bottom-4 right-4 z-50 flex max-w-sm items-center gap-2 rounded-md border bg-background p-3 text-sm shadow-lg">
+      <div className="flex-1">
+        <p>
+          {installed
+            ? "An update was installed. Reload to use it."
+            : "A new version of Attractor is available."}
+        </p>
+        {error && <p className="text-destructive">{error}</p>}
+      </div>
+      {installed ? (
+        <Button size="sm" onClick={() => window.location.reload()}>
+          <RefreshCw className="mr-2 h-3.5 w-3.5" />
+          Reload
+        </Button>
+      ) : (
+        <Button size="sm" onClick={handleInstall} disabled={installing}>
+          <Download className="mr-2 h-3.5 w-3.5" />
+          {installing ? "Installing..." : "Update"}
+        </Button>
+      )}
+      <Button
+        size="icon-sm"
+        variant="ghost"
+        onClick={() => {
+          setStatus(null)
+          setInstalled(false)
+        }}
+      >
+        <X className="h-3.5 w-3.5" />
+      </Button>
+    </div>
+  )
+}
@@ -0,0 +1,49 @@
+import { api } from "@/api"
+import { ws } from "@/ws"
+import type {
+  DesktopNotificationSettings,
+  Notification as AppNotification,
+  NotificationKind,
+} from "@/types"
+
+const CATEGORY: Partial<
+  Record<NotificationKind, keyof DesktopNotificationSettings>
+> = {
+  amplifier: "amplifier",
+  conflict: "sync_conflicts",
+  milestone: "milestones",
+}
+
+let settings: DesktopNotificationSettings | null = null
+
+/** Ask for permission if any category is on; call after changing settings too. */
+export async function setDesktopNotificationSettings(
+  next: DesktopNotificationSettings,
+): Promise<void> {
+  settings = next
+  const wanted = Object.values(next).some(Boolean)
+  if (
+    wanted &&
+    "Notification" in window &&
+    Notification.permission === "default"
+  ) {
+    await Notification.requestPermission()
+  }
+}
+
+/** Raise OS notifications for enabled categories while the window is unfocused. */
+export function startDesktopNotifications(): () => void {
+  api
+    .getConfig()
+    .then((cfg) => setDesktopNotificationSettings(cfg.desktop_notifications))
+    .catch(() => {})
+
+  return ws.on("notification:created", (data) => {
+    const n = data as AppNotification
+    const category = CATEGORY[n.kind]
+    if (!category || !settings?.[category] || document.hasFocus()) return
+    if (!("Notification" in window) || Notification.permission !== "granted")
+      return
+    new Notification(n.title, { body: n.project, tag: n.id })
+  })
+}
@@ -4,6 +4,10 @@ import {
   ArrowLeft,
   CircleDot,
   CheckCircle2,
+  FileDown,
+  FolderOpen,
+  GitBranch,
+  GitPullRequest,
   MessageSquare,
   MoreHorizontal,
   Pencil,
@@ -13,6 +17,9 @@ import {
   Play,
   Square,
   Loader2,
+  Lock,
+  Sparkles,
+  LockOpen,
   Tag,
 } from "lucide-react"
 import { Button } from "@/components/ui/button"
@@ -30,17 +37,27 @@ import { LabelBadge } from "@/components/LabelBadge"
 import { CreateLabelForm } from "@/components/CreateLabelForm"
 import { TimeAgo } from "@/components/TimeAgo"
 import { LoadingSpinner } from "@/components/LoadingSpinner"
-import { api } from "@/api"
+import { api, ApiError } from "@/api"
 import { ws } from "@/ws"
 import type {
   Issue,
   Comment as IssueComment,
   Label,
   AmplifierSessionInfo,
+  AmplifierSessionLogs,
+  IssuePolishSuggestion,
+  LabelSuggestion,
+  LinkedPullRequest,
 } from "@/types"
 
 const COMMENTS_PER_PAGE = 50
 
+const PR_STATE_COLORS: Record<LinkedPullRequest["state"], string> = {
+  open: "text-green-600",
+  merged: "text-purple-600",
+  closed: "text-destructive",
+}
+
 export default function IssueDetail() {
   const { name, issueNumber: issueNumberStr } = useParams<{
     name: string
@@ -83,12 +100,31 @@ export default function IssueDetail() {
   // Labels sidebar
   const [allLabels, setAllLabels] = useState<Label[]>([])
   const [labelsOpen, setLabelsOpen] = useState(false)
+  const [labelSuggestions, setLabelSuggestions] = useState<
+    LabelSuggestion[] | null
+  >(null)
+  const [suggestingLabels, setSuggestingLabels] = useState(false)
+  const [labelSuggestError, setLabelSuggestError] = useState<string | null>(null)
   const [creatingLabel, setCreatingLabel] = useState(false)
 
+  // Work branch
+  const [branchLoading, setBranchLoading] = useState(false)
+  const [exportMessage, setExportMessage] = useState<string | null>(null)
+  const [conflictMessage, setConflictMessage] = useState<string | null>(null)
+  const [branchError, setBranchError] = useState<string | null>(null)
+  const [prUrl, setPrUrl] = useState("")
+
   // Amplifier
   const [amplifierStatus, setAmplifierStatus] =
     useState<AmplifierSessionInfo | null>(null)
   const [amplifierLoading, setAmplifierLoading] = useState(false)
+  const [amplifierPaths, setAmplifierPaths] = useState("")
+  const [amplifierLog, setAmplifierLog] = useState<AmplifierSessionLogs | null>(
+    null,
+  )
+  const [polish, setPolish] = useState<IssuePolishSuggestion | null>(null)
+  const [polishing, setPolishing] = useState(false)
+  const [polishError, setPolishError] = useState<string | null>(null)
 
   const commentTotalPages = Math.max(
     1,
@@ -133,6 +169,16 @@ export default function IssueDetail() {
     }
   }, [project, issueNumber])
 
+  // Another client saved in between: show its version and keep the drafts open.
+  async function handleUpdateError(err: unknown) {
+    if (err instanceof ApiError && err.code === "STALE_WRITE") {
+      setConflictMessage(
+        "This issue was changed elsewhere and has been reloaded. Saving again will overwrite those changes.",
+      )
+      await fetchIssue()
+    }
+  }
+
   // Initial load
   useEffect(() => {
     fetchIssue()
@@ -153,11 +199,12 @@ export default function IssueDetail() {
       ws.on("comment:created", (data: unknown) => {
         const d = data as { project: string; issueNumber: number }
         if (d.project === project && d.issueNumber === issueNumber) {
+          fetchIssue()
           fetchComments()
         }
       }),
       ws.on("amplifier:started", (data: unknown) => {
-        const d = data as { project: string; issueNumber: number }
+        const d = data as { project: string; issueNumber: number; paths?: string[] }
         if (d.project === project && d.issueNumber === issueNumber) {
           setAmplifierStatus({
             issueNumber,
@@ -165,7 +212,10 @@ export default function IssueDetail() {
             startedAt: new Date().toISOString(),
             finishedAt: null,
             error: null,
+            logFile: null,
+            paths: d.paths ?? [],
           })
+          setAmplifierLog(null)
         }
       }),
       ws.on("amplifier:completed", (data: unknown) => {
@@ -198,11 +248,13 @@ export default function IssueDetail() {
     try {
       const updated = await api.updateIssue(project, issueNumber, {
         title: titleDraft.trim(),
+        expected_version: issue.version,
       })
       setIssue(updated)
       setEditingTitle(false)
-    } catch {
-      // silent
+      setConflictMessage(null)
+    } catch (err) {
+      await handleUpdateError(err)
     } finally {
       setSavingTitle(false)
     }
@@ -221,17 +273,38 @@ export default function IssueDetail() {
     try {
       const updated = await api.updateIssue(project, issueNumber, {
         body: bodyDraft,
+        expected_version: issue.version,
       })
       setIssue(updated)
       setEditingBody(false)
-    } catch {
-      // silent
+      setConflictMessage(null)
+    } catch (err) {
+      await handleUpdateError(err)
     } finally {
       setSavingBody(false)
     }
   }
 
   // -- Issue state toggle --
+  async function handleExportMarkdown() {
+    const destination = window.prompt(
+      "Export this issue to Markdown file",
+      `~/${project}-issue-${issueNumber}.md`,
+    )
+    if (!destination) return
+    setExportMessage(null)
+    try {
+      const result = await api.exportIssueMarkdown(project, issueNumber, destination)
+      setExportMessage(
+        result.attachments > 0
+          ? `Exported to ${result.path} with ${result.attachments} attachments`
+          : `Exported to ${result.path}`,
+      )
+    } catch (err) {
+      setExportMessage(err instanceof Error ? err.message : "Export failed")
+    }
+  }
+
   async function handleToggleState() {
     if (!issue) return
     const newState = issue.state === "open" ? "closed" : "open"
@@ -241,10 +314,25 @@ export default function IssueDetail() {
       const updated = await api.updateIssue(project, issueNumber, {
         state: newState,
         state_reason: stateReason,
+        expected_version: issue.version,
       })
       setIssue(updated)
-    } catch {
-      // silent
+      setConflictMessage(null)
+    } catch (err) {
+      await handleUpdateError(err)
+    }
+  }
+
+  async function handleToggleLock() {
+    if (!issue) return
+    try {
+      setIssue(
+        issue.locked
+          ? await api.unlockIssue(project, issueNumber)
+          : await api.lockIssue(project, issueNumber),
+      )
+    } catch (err) {
+      setConflictMessage(err instanceof Error ? err.message : "Failed to change lock")
     }
   }
 
@@ -256,9 +344,7 @@ export default function IssueDetail() {
       await api.createComment(project, issueNumber, newComment.trim())
       setNewComment("")
       fetchComments()
-      if (issue) {
-        setIssue({ ...issue, comments: issue.comments + 1 })
-      }
+      fetchIssue()
     } catch {
       // silent
     } finally {
@@ -277,6 +363,7 @@ export default function IssueDetail() {
       await api.updateComment(project, commentId, commentDraft)
       setEditingCommentId(null)
       fetchComments()
+      fetchIssue()
     } catch {
       // silent
     } finally {
@@ -288,9 +375,7 @@ export default function IssueDetail() {
     try {
       await api.deleteComment(project, commentId)
       fetchComments()
-      if (issue) {
-        setIssue({ ...issue, comments: Math.max(0, issue.comments - 1) })
-      }
+      fetchIssue()
     } catch {
       // silent
     }
@@ -306,6 +391,18 @@ export default function IssueDetail() {
     }
   }
 
+  async function handleSuggestLabels() {
+    setSuggestingLabels(true)
+    setLabelSuggestError(null)
+    try {
+      setLabelSuggestions(await api.suggestIssueLabels(project, issueNumber))
+    } catch (err) {
+      setLabelSuggestError(err instanceof Error ? err.message : "Amplifier failed")
+    } finally {
+      setSuggestingLabels(false)
+    }
+  }
+
   async function handleRemoveLabel(labelName: string) {
     try {
       await api.removeIssueLabel(project, issueNumber, labelName)
@@ -328,11 +425,68 @@ export default function IssueDetail() {
     fetchIssue()
   }
 
+  // -- Work branch --
+  async function handleCheckoutBranch() {
+    setBranchLoading(true)
+    setBranchError(null)
+    try {
+      setIssue(await api.createIssueBranch(project, issueNumber))
+    } catch (err) {
+      setBranchError(
+        err instanceof Error ? err.message : "Failed to check out branch",
+      )
+    } finally {
+      setBranchLoading(false)
+    }
+  }
+
+  async function handleCreatePullRequest() {
+    setBranchLoading(true)
+    setBranchError(null)
+    try {
+      await api.createPullRequest(project, issueNumber)
+      fetchIssue()
+    } catch (err) {
+      setBranchError(
+        err instanceof Error ? err.message : "Failed to open pull request",
+      )
+    } finally {
+      setBranchLoading(false)
+    }
+  }
+
+  async function handleLinkPullRequest() {
+    if (!prUrl.trim()) return
+    setBranchError(null)
+    try {
+      setIssue(await api.linkPullRequest(project, issueNumber, prUrl.trim()))
+      setPrUrl("")
+    } catch (err) {
+      setBranchError(
+        err instanceof Error ? err.message : "Failed to link pull request",
+      )
+    }
+  }
+
   // -- Amplifier --
   async function handleRunAmplifier() {
     setAmplifierLoading(true)
     try {
-      const status = await api.runAmplifier(project, issueNumber)
+      const git = await api.getProjectGitStatus(project).catch(() => null)
+      if (
+        git &&
+        !git.clean &&
+        !window.confirm(
+          `The project repository has ${git.modified.length + git.untracked.length} uncommitted file(s) that Amplifier may overwrite. Run anyway?`,
+        )
+      ) {
+        return
+      }
+      const paths = amplifierPaths
+        .split(",")
+        .map((p) => p.trim())
+        .filter(Boolean)
+      const status = await api.runAmplifier(project, issueNumber, paths)
       setAmplifierStatus(status)
     } catch {
       // silent
@@ -341,6 +495,49 @@ export default function IssueDetail() {
     }
   }
 
+  async function handleToggleAmplifierLog() {
+    if (amplifierLog) {
+      setAmplifierLog(null)
+      return
+    }
+    try {
+      setAmplifierLog(await api.getAmplifierLogs(project, issueNumber))
+    } catch {
+      // silent
+    }
+  }
+
+  async function handlePolish() {
+    setPolishing(true)
+    setPolishError(null)
+    try {
+      setPolish(await api.polishIssue(project, issueNumber))
+    } catch (err) {
+      setPolishError(err instanceof Error ? err.message : "Amplifier failed")
+    } finally {
+      setPolishing(false)
+    }
+  }
+
+  async function handleApplyPolish() {
+    if (!polish) return
+    try {
+      const updated = await api.updateIssue(project, issueNumber, {
+        title: polish.title,
+        body: polish.body,
+        expected_version: polish.version,
+      })
+      setIssue(updated)
+      setPolish(null)
+      setConflictMessage(null)
+    } catch (err) {
+      if (err instanceof ApiError && err.code === "STALE_WRITE") {
+        setPolish({ ...polish, version: err.detail.current_version as number })
+      }
+      await handleUpdateError(err)
+    }
+  }
+
   async function handleCancelAmplifier() {
     setAmplifierLoading(true)
     try {
@@ -397,8 +594,52 @@ export default function IssueDetail() {
         <span>Issues</span>
         <span>/</span>
         <span className="text-foreground">#{issueNumber}</span>
+        <Button
+          variant="ghost"
+          size="sm"
+          className="ml-auto"
+          title="Export as a Markdown document"
+          onClick={handleExportMarkdown}
+        >
+          <FileDown className="h-4 w-4" />
+        </Button>
+        <Button
+          variant="ghost"
+          size="sm"
+          title="Show the issue's files in the store"
+          onClick={() =>
+            api.revealIssueFiles(project, issueNumber).catch(() => {})
+          }
+        >
+          <FolderOpen className="h-4 w-4" />
+        </Button>
       </div>
 
+      {exportMessage && (
+        <p className="-mt-2 mb-4 text-sm text-muted-foreground">{exportMessage}</p>
+      )}
+      {conflictMessage && (
+        <p className="-mt-2 mb-4 text-sm text-destructive">{conflictMessage}</p>
+      )}
+      {polish && (
+        <div className="mb-4 space-y-2 rounded-md border p-3">
+          <div className="flex items-center gap-2 text-sm font-medium">
+            <Sparkles className="h-4 w-4" />
+            Suggested rewrite
+          </div>
+          <div className="font-semibold">{polish.title}</div>
+          <pre className="whitespace-pre-wrap font-sans text-sm">{polish.body}</pre>
+          <div className="flex gap-2">
+            <Button size="sm" onClick={handleApplyPolish}>
+              Apply
+            </Button>
+            <Button size="sm" variant="outline" onClick={() => setPolish(null)}>
+              Discard
+            </Button>
+          </div>
+        </div>
+      )}
+
       {/* Title */}
       <div className="mb-2">
         {editingTitle ? (
@@ -557,6 +798,13 @@ export default function IssueDetail() {
 
           {/* New comment form */}
           <div className="mt-6 space-y-2">
+            {issue.locked && (
+              <p className="flex items-center gap-2 text-sm text-muted-foreground">
+                <Lock className="h-3.5 w-3.5" />
+                Locked{issue.active_lock_reason && ` as ${issue.active_lock_reason}`}.
+                Only the author and the store's owner can comment.
+              </p>
+            )}
             <Textarea
               placeholder="Leave a comment..."
               value={newComment}
@@ -571,12 +819,22 @@ export default function IssueDetail() {
               >
                 {submittingComment ? "Submitting..." : "Comment"}
               </Button>
-              <Button
-                variant={issue.state === "open" ? "destructive" : "default"}
-                onClick={handleToggleState}
-              >
-                {issue.state === "open" ? "Close issue" : "Reopen issue"}
-              </Button>
+              <div className="flex gap-2">
+                <Button variant="outline" onClick={handleToggleLock}>
+                  {issue.locked ? (
+                    <LockOpen className="mr-1.5 h-3.5 w-3.5" />
+                  ) : (
+                    <Lock className="mr-1.5 h-3.5 w-3.5" />
+                  )}
+                  {issue.locked ? "Unlock" : "Lock"}
+                </Button>
+                <Button
+                  variant={issue.state === "open" ? "destructive" : "default"}
+                  onClick={handleToggleState}
+                >
+                  {issue.state === "open" ? "Close issue" : "Reopen issue"}
+                </Button>
+              </div>
             </div>
           </div>
         </div>
@@ -631,6 +889,51 @@ export default function IssueDetail() {
                   )}
                 </DropdownMenuContent>
               </DropdownMenu>
+              <Button
+                variant="ghost"
+                size="sm"
+                className="mt-1 w-full"
+                onClick={handleSuggestLabels}
+                disabled={suggestingLabels}
+              >
+                {suggestingLabels ? (
+                  <Loader2 className="mr-1 h-3 w-3 animate-spin" />
+                ) : (
+                  <Sparkles className="mr-1 h-3 w-3" />
+                )}
+                {suggestingLabels ? "Suggesting..." : "Suggest labels"}
+              </Button>
+              {labelSuggestError && (
+                <p className="mt-1 text-xs text-destructive">
+                  {labelSuggestError}
+                </p>
+              )}
+              {labelSuggestions && (
+                <div className="mt-1 flex flex-wrap gap-1">
+                  {labelSuggestions
+                    .filter((s) => !issueLabelsSet.has(s.label.name))
+                    .map((s) => (
+                      <button
+                        key={s.label.name}
+                        type="button"
+                        title={`${Math.round(s.confidence * 100)}% match, click to add`}
+                        className="flex items-center gap-1 rounded-full border border-dashed px-2 py-0.5 text-xs hover:bg-muted"
+                        onClick={() => handleAddLabel(s.label.name)}
+                      >
+                        <span
+                          className="inline-block h-2 w-2 rounded-full"
+                          style={{ backgroundColor: `#${s.label.color}` }}
+                        />
+                        {s.label.name}
+                      </button>
+                    ))}
+                  {labelSuggestions.length === 0 && (
+                    <span className="text-xs text-muted-foreground">
+                      No matching labels
+                    </span>
+                  )}
+                </div>
+              )}
               {creatingLabel ? (
                 <div className="mt-2">
                   <CreateLabelForm
@@ -651,14 +954,88 @@ export default function IssueDetail() {
             </div>
           </SidebarSection>
 
+          {/* Work branch and pull requests */}
+          <SidebarSection title="Development" icon={GitBranch}>
+            <div className="space-y-2">
+              {issue.branch && (
+                <code className="block truncate rounded bg-muted px-1 py-0.5 text-xs">
+                  {issue.branch}
+                </code>
+              )}
+              <Button
+                variant="outline"
+                size="sm"
+                className="w-full"
+                onClick={handleCheckoutBranch}
+                disabled={branchLoading}
+              >
+                {issue.branch ? "Check Out" : "Create Branch"}
+              </Button>
+              {issue.branch && (
+                <Button
+                  variant="outline"
+                  size="sm"
+                  className="w-full"
+                  onClick={handleCreatePullRequest}
+                  disabled={branchLoading}
+                >
+                  Open Pull Request
+                </Button>
+              )}
+              {issue.linked_prs.map((pr) => (
+                <a
+                  key={pr.url}
+                  href={pr.url}
+                  target="_blank"
+                  rel="noopener noreferrer"
+                  title={pr.title ?? undefined}
+                  className="flex items-center gap-1.5 text-xs hover:underline"
+                >
+                  <GitPullRequest
+                    className={`h-3.5 w-3.5 shrink-0 ${PR_STATE_COLORS[pr.state]}`}
+                  />
+                  <span className="truncate">
+                    {pr.owner}/{pr.repo}#{pr.number}
+                  </span>
+                  <span className="ml-auto text-muted-foreground">{pr.state}</span>
+                </a>
+              ))}
+              <Input
+                placeholder="Link a pull request URL"
+                className="h-8 text-xs"
+                value={prUrl}
+                onChange={(e) => setPrUrl(e.target.value)}
+                onKeyDown={(e) => {
+                  if (e.key === "Enter") handleLinkPullRequest()
+                }}
+              />
+              {branchError && (
+                <p className="text-xs text-destructive">{branchError}</p>
+              )}
+            </div>
+          </SidebarSection>
+
           {/* Amplifier */}
           <SidebarSection title="Amplifier" icon={Play}>
+            {amplifierStatus?.status !== "running" && (
+              <Input
+                className="mb-2 h-8 text-xs"
+                placeholder="Limit to paths, e.g. src/api, docs"
+                value={amplifierPaths}
+                onChange={(e) => setAmplifierPaths(e.target.value)}
+              />
+            )}
             {amplifierStatus?.status === "running" ? (
               <div className="space-y-2">
                 <div className="flex items-center gap-2 text-sm">
                   <Loader2 className="h-4 w-4 animate-spin" />
                   Running...
                 </div>
+                {amplifierStatus.paths.length > 0 && (
+                  <p className="text-xs text-muted-foreground">
+                    Limited to {amplifierStatus.paths.join(", ")}
+                  </p>
+                )}
                 <Button
                   variant="destructive"
                   size="sm"
@@ -697,6 +1074,26 @@ export default function IssueDetail() {
                     {amplifierStatus.error}
                   </p>
                 )}
+                {amplifierStatus.logFile && (
+                  <Button
+                    variant="ghost"
+                    size="sm"
+                    className="w-full"
+                    onClick={handleToggleAmplifierLog}
+                  >
+                    {amplifierLog ? "Hide Log" : "Show Full Log"}
+                  </Button>
+                )}
+                {amplifierLog && (
+                  <pre
+                    className="max-h-64 overflow-auto rounded bg-muted p-2 text-xs"
+                    title={amplifierLog.log_file}
+                  >
+                    {amplifierLog.truncated > 0 &&
+                      `... ${amplifierLog.truncated} earlier lines\n`}
+                    {amplifierLog.lines.join("\n")}
+                  </pre>
+                )}
                 <Button
                   size="sm"
                   className="w-full"
@@ -718,6 +1115,23 @@ export default function IssueDetail() {
                 Run Amplifier
               </Button>
             )}
+            <Button
+              variant="outline"
+              size="sm"
+              className="mt-2 w-full"
+              onClick={handlePolish}
+              disabled={polishing}
+            >
+              {polishing ? (
+                <Loader2 className="mr-1 h-3 w-3 animate-spin" />
+              ) : (
+                <Sparkles className="mr-1 h-3 w-3" />
+              )}
+              {polishing ? "Rewriting..." : "Suggest Rewrite"}
+            </Button>
+            {polishError && (
+              <p className="mt-1 text-xs text-destructive">{polishError}</p>
+            )}
           </SidebarSection>
         </div>
       </div>
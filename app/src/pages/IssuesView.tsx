@@ -10,7 +10,10 @@ import {
   ChevronRight,
   Tag,
   ArrowUpDown,
+  Download,
+  Flag,
   Settings,
+  Upload,
 } from "lucide-react"
 import { Button } from "@/components/ui/button"
 import {
@@ -27,6 +30,7 @@ import {
   DropdownMenu,
   DropdownMenuCheckboxItem,
   DropdownMenuContent,
+  DropdownMenuItem,
   DropdownMenuTrigger,
 } from "@/components/ui/dropdown-menu"
 import { LabelBadge } from "@/components/LabelBadge"
@@ -34,9 +38,31 @@ import { CreateLabelForm } from "@/components/CreateLabelForm"
 import { TimeAgo } from "@/components/TimeAgo"
 import { LoadingSpinner } from "@/components/LoadingSpinner"
 import { EmptyState } from "@/components/EmptyState"
+import { PlanMilestoneDialog } from "@/components/PlanMilestoneDialog"
 import { api, type ProjectInfo } from "@/api"
 import { ws } from "@/ws"
-import type { Issue, Label } from "@/types"
+import type {
+  Issue,
+  IssueExportFormat,
+  IssueImportFormat,
+  Label,
+  SimilarIssue,
+} from "@/types"
+
+const EXPORT_FORMATS: {
+  format: IssueExportFormat
+  label: string
+  extension: string
+}[] = [
+  { format: "markdown", label: "Markdown", extension: "md" },
+  { format: "csv", label: "CSV", extension: "csv" },
+  { format: "json", label: "JSON", extension: "json" },
+]
+
+const IMPORT_FORMATS: { format: IssueImportFormat; label: string }[] = [
+  { format: "csv", label: "CSV" },
+  { format: "jira", label: "Jira CSV export" },
+]
 
 const PER_PAGE = 30
 
@@ -88,6 +114,9 @@ export default function IssuesView() {
   const [page, setPage] = useState(1)
   const [loading, setLoading] = useState(true)
   const [error, setError] = useState<string | null>(null)
+  const [exportMessage, setExportMessage] = useState<string | null>(null)
+  const [planOpen, setPlanOpen] = useState(false)
+  const [warnings, setWarnings] = useState<string[]>([])
 
   // Filters
   const [stateFilter, setStateFilter] = useState<StateFilter>("open")
@@ -106,9 +135,24 @@ export default function IssuesView() {
   const [creating, setCreating] = useState(false)
   const [createError, setCreateError] = useState<string | null>(null)
   const [creatingLabel, setCreatingLabel] = useState(false)
+  const [similar, setSimilar] = useState<SimilarIssue[]>([])
 
   const totalPages = Math.max(1, Math.ceil(totalCount / PER_PAGE))
 
+  useEffect(() => {
+    if (!createOpen || newTitle.trim().length < 4) {
+      setSimilar([])
+      return
+    }
+    const timer = setTimeout(() => {
+      api
+        .findSimilarIssues(project, newTitle.trim(), newBody.trim() || undefined)
+        .then(setSimilar)
+        .catch(() => setSimilar([]))
+    }, 400)
+    return () => clearTimeout(timer)
+  }, [createOpen, newTitle, newBody, project])
+
   const fetchIssues = useCallback(async () => {
     setLoading(true)
     setError(null)
@@ -123,6 +167,7 @@ export default function IssuesView() {
       })
       setIssues(res.items)
       setTotalCount(res.total_count)
+      setWarnings(res.warnings ?? [])
     } catch (err) {
       setError(err instanceof Error ? err.message : "Failed to load issues")
     } finally {
@@ -162,6 +207,42 @@ export default function IssuesView() {
     }
   }, [project, fetchIssues])
 
+  async function handleExport(format: IssueExportFormat, extension: string) {
+    const destination = window.prompt(
+      "Export the filtered issues to file",
+      `~/${project}-issues.${extension}`,
+    )
+    if (!destination) return
+    setExportMessage(null)
+    try {
+      const result = await api.exportIssuesToFile(project, {
+        format,
+        destination,
+        include_comments: window.confirm("Include comments?"),
+        state: stateFilter,
+        labels: labelFilter.length > 0 ? labelFilter.join(",") : undefined,
+      })
+      setExportMessage(`Exported ${result.issues} issues to ${result.path}`)
+    } catch (err) {
+      setExportMessage(err instanceof Error ? err.message : "Export failed")
+    }
+  }
+
+  async function handleImport(format: IssueImportFormat) {
+    const source = window.prompt("Import issues from CSV file")
+    if (!source) return
+    setExportMessage(null)
+    try {
+      const result = await api.importIssuesFromFile(project, { source, format })
+      setExportMessage(
+        `Imported ${result.issues} issues and ${result.comments} comments`,
+      )
+      fetchIssues()
+    } catch (err) {
+      setExportMessage(err instanceof Error ? err.message : "Import failed")
+    }
+  }
+
   async function handleCreate() {
     if (!newTitle.trim()) return
     setCreating(true)
@@ -240,11 +321,12 @@ export default function IssuesView() {
               setNewLabels([])
               setCreateError(null)
               setCreatingLabel(false)
+              setSimilar([])
             }
           }}
         >
           <DialogTrigger asChild>
-            <Button>
+            <Button disabled={projectInfo?.read_only}>
               <Plus className="mr-2 h-4 w-4" />
               New Issue
             </Button>
@@ -268,6 +350,26 @@ export default function IssuesView() {
                 disabled={creating}
                 rows={4}
               />
+              {similar.length > 0 && (
+                <div className="rounded-md border border-amber-500/50 bg-amber-500/10 p-2 text-sm">
+                  <div className="mb-1 font-medium">This looks like</div>
+                  {similar.map((issue) => (
+                    <button
+                      key={issue.number}
+                      type="button"
+                      className="block truncate text-left hover:underline"
+                      onClick={() =>
+                        navigate(
+                          `/project/${encodeURIComponent(project)}/issues/${issue.number}`,
+                        )
+                      }
+                    >
+                      #{issue.number} {issue.title}
+                      {issue.state === "closed" && " (closed)"}
+                    </button>
+                  ))}
+                </div>
+              )}
               <div>
                 <div className="mb-2 text-sm font-medium">Labels</div>
                 <div className="flex flex-wrap gap-1 mb-2">
@@ -331,10 +433,27 @@ export default function IssuesView() {
               <span className="font-medium text-foreground/70">Issues:</span>{" "}
               <code className="rounded bg-muted px-1 py-0.5">{projectInfo.issues_path}</code>
             </div>
+            {projectInfo.read_only && (
+              <div>
+                Read-only: add a GitHub account for this project to edit and sync
+                it.
+              </div>
+            )}
           </div>
         )}
       </div>
 
+      {warnings.length > 0 && (
+        <div className="mb-4 rounded-md border border-amber-500/50 bg-amber-500/10 p-3 text-sm">
+          {warnings.map((w) => (
+            <div key={w}>{w}</div>
+          ))}
+          <div className="mt-1 text-xs text-muted-foreground">
+            Verify the store from project settings to restore them from history.
+          </div>
+        </div>
+      )}
+
       {/* Filter bar */}
       <div className="mb-4 flex items-center justify-between border-b">
         <div className="flex">
@@ -427,9 +546,65 @@ export default function IssuesView() {
           >
             {direction === "desc" ? "\u2193" : "\u2191"}
           </Button>
+
+          {/* Milestone planning */}
+          <Button
+            variant="ghost"
+            size="sm"
+            title="Plan milestone"
+            disabled={projectInfo?.read_only}
+            onClick={() => setPlanOpen(true)}
+          >
+            <Flag className="h-3.5 w-3.5" />
+          </Button>
+          <PlanMilestoneDialog
+            open={planOpen}
+            onOpenChange={setPlanOpen}
+            projectName={project}
+            onApplied={fetchIssues}
+          />
+
+          {/* Import */}
+          <DropdownMenu>
+            <DropdownMenuTrigger asChild>
+              <Button variant="ghost" size="sm" title="Import issues">
+                <Upload className="h-3.5 w-3.5" />
+              </Button>
+            </DropdownMenuTrigger>
+            <DropdownMenuContent align="end">
+              {IMPORT_FORMATS.map(({ format, label }) => (
+                <DropdownMenuItem key={format} onSelect={() => handleImport(format)}>
+                  {label}
+                </DropdownMenuItem>
+              ))}
+            </DropdownMenuContent>
+          </DropdownMenu>
+
+          {/* Export */}
+          <DropdownMenu>
+            <DropdownMenuTrigger asChild>
+              <Button variant="ghost" size="sm" title="Export issues">
+                <Download className="h-3.5 w-3.5" />
+              </Button>
+            </DropdownMenuTrigger>
+            <DropdownMenuContent align="end">
+              {EXPORT_FORMATS.map(({ format, label, extension }) => (
+                <DropdownMenuItem
+                  key={format}
+                  onSelect={() => handleExport(format, extension)}
+                >
+                  {label}
+                </DropdownMenuItem>
+              ))}
+            </DropdownMenuContent>
+          </DropdownMenu>
         </div>
       </div>
 
+      {exportMessage && (
+        <p className="mb-4 text-sm text-muted-foreground">{exportMessage}</p>
+      )}
+
       {/* Issue list */}
       {loading && (
         <div className="flex justify-center py-12">
@@ -452,7 +627,7 @@ export default function IssuesView() {
               ? `No ${stateFilter} issues. Try changing filters.`
               : "Create an issue to get started."
           }
-          actionLabel="New Issue"
+          actionLabel={projectInfo?.read_only ? undefined : "New Issue"}
           onAction={() => setCreateOpen(true)}
         />
       )}
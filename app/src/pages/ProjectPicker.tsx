@@ -1,6 +1,6 @@
 import { useCallback, useEffect, useState } from "react"
 import { useNavigate } from "react-router-dom"
-import { FolderOpen, Plus, Trash2, Github, Clock } from "lucide-react"
+import { FolderOpen, Plus, Trash2, Github, Clock, ArchiveRestore } from "lucide-react"
 import { Button } from "@/components/ui/button"
 import {
   Dialog,
@@ -14,6 +14,9 @@ import { LoadingSpinner } from "@/components/LoadingSpinner"
 import { EmptyState } from "@/components/EmptyState"
 import { GitHubSetupBanner } from "@/components/GitHubSetupBanner"
 import { NewProjectFlow } from "@/components/NewProjectFlow"
+import { NotificationPreferences } from "@/components/NotificationPreferences"
+import { AppSettingsForm } from "@/components/AppSettingsForm"
+import { AmplifierProfiles } from "@/components/AmplifierProfiles"
 import { api, type ProjectInfo } from "@/api"
 import type { AppConfig } from "@/types"
 
@@ -34,6 +37,7 @@ export default function ProjectPicker() {
   // Delete dialog
   const [deleteTarget, setDeleteTarget] = useState<string | null>(null)
   const [deleting, setDeleting] = useState(false)
+  const [restoreMessage, setRestoreMessage] = useState<string | null>(null)
 
   const fetchProjects = useCallback(async () => {
     setLoading(true)
@@ -74,6 +78,22 @@ export default function ProjectPicker() {
     }
   }
 
+  async function handleRestore() {
+    const archive = window.prompt("Restore a project from backup file")
+    if (!archive) return
+    const repoName = hasGitHubToken
+      ? window.prompt("Push to a new GitHub repo (leave empty to keep it local)")
+      : null
+    setRestoreMessage(null)
+    try {
+      const project = await api.restoreProject(archive, repoName || undefined)
+      navigate(`/project/${encodeURIComponent(project.name)}`)
+    } catch (err) {
+      setRestoreMessage(err instanceof Error ? err.message : "Restore failed")
+      fetchProjects()
+    }
+  }
+
   // Build recent projects list
   const recentNames = config?.recent_projects ?? []
   const recentProjects =
@@ -101,6 +121,9 @@ export default function ProjectPicker() {
               {project.store.github.owner}/{project.store.github.repo}
             </span>
           )}
+          {project.read_only && (
+            <span className="text-xs text-muted-foreground">Read-only</span>
+          )}
         </div>
         <Button
           variant="ghost"
@@ -122,12 +145,22 @@ export default function ProjectPicker() {
 
       <div className="mb-8 flex items-center justify-between">
         <h1 className="text-2xl font-bold">Attractor</h1>
-        <Button onClick={() => setCreateOpen(true)}>
-          <Plus className="mr-2 h-4 w-4" />
-          New Project
-        </Button>
+        <div className="flex gap-2">
+          <Button variant="outline" onClick={handleRestore}>
+            <ArchiveRestore className="mr-2 h-4 w-4" />
+            Restore
+          </Button>
+          <Button onClick={() => setCreateOpen(true)}>
+            <Plus className="mr-2 h-4 w-4" />
+            New Project
+          </Button>
+        </div>
       </div>
 
+      {restoreMessage && (
+        <p className="mb-4 text-sm text-destructive">{restoreMessage}</p>
+      )}
+
       <NewProjectFlow
         open={createOpen}
         onOpenChange={setCreateOpen}
@@ -196,6 +229,10 @@ export default function ProjectPicker() {
             )}
             {projects.map(renderProjectRow)}
           </div>
+
+          <NotificationPreferences />
+          <AppSettingsForm />
+          <AmplifierProfiles />
         </div>
       )}
 
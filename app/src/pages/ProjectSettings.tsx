@@ -2,16 +2,26 @@ import { useCallback, useEffect, useState } from "react"
 import { useParams, useNavigate } from "react-router-dom"
 import {
   ArrowLeft,
+  Archive,
+  Code,
+  FolderOpen,
+  GitBranch,
   Github,
   HardDrive,
   RefreshCw,
   Settings,
+  ShieldCheck,
+  SquareTerminal,
 } from "lucide-react"
 import { Button } from "@/components/ui/button"
 import { Separator } from "@/components/ui/separator"
 import { LoadingSpinner } from "@/components/LoadingSpinner"
 import { GitHubAuthSetup } from "@/components/GitHubAuthSetup"
 import { ConnectGitHubDialog } from "@/components/ConnectGitHubDialog"
+import { DoctorChecklist } from "@/components/DoctorChecklist"
+import { ProjectRepoActions } from "@/components/ProjectRepoActions"
+import { ProjectSettingsForm } from "@/components/ProjectSettingsForm"
+import { AmplifierSettingsForm } from "@/components/AmplifierSettingsForm"
 import { api } from "@/api"
 import type { StoreStatus } from "@/types"
 
@@ -62,6 +72,49 @@ export default function ProjectSettings() {
     }
   }
 
+  async function handleOpen(open: (path: string) => Promise<unknown>) {
+    if (!store) return
+    setSyncMessage(null)
+    try {
+      await open(store.path)
+    } catch (err) {
+      setSyncMessage(err instanceof Error ? err.message : "Failed to open")
+    }
+  }
+
+  async function handleBackup() {
+    const path = window.prompt(
+      "Save a backup of this project to",
+      `~/${projectName}-backup.tar.gz`,
+    )
+    if (!path) return
+    setSyncMessage(null)
+    try {
+      const result = await api.backupProject(projectName, path)
+      setSyncMessage(`Backup saved to ${result.path}`)
+    } catch (err) {
+      setSyncMessage(err instanceof Error ? err.message : "Backup failed")
+    }
+  }
+
+  async function handleVerify() {
+    setSyncMessage(null)
+    try {
+      const result = await api.verifyStore(projectName)
+      const parts = [
+        result.recovered.length > 0 &&
+          `restored issues ${result.recovered.map((n) => `#${n}`).join(", ")}`,
+        result.unrecoverable.length > 0 &&
+          `could not restore ${result.unrecoverable.join(", ")}`,
+      ].filter(Boolean)
+      setSyncMessage(
+        parts.length > 0 ? `Verified: ${parts.join("; ")}` : "All issue files are readable",
+      )
+    } catch (err) {
+      setSyncMessage(err instanceof Error ? err.message : "Verify failed")
+    }
+  }
+
   function handleConnected(newStore: StoreStatus) {
     setStore(newStore)
     fetchData()
@@ -121,6 +174,21 @@ export default function ProjectSettings() {
                   </a>
                 </span>
               </>
+            ) : store?.remote ? (
+              <>
+                <GitBranch className="h-4 w-4" />
+                <span>
+                  Connected to {store.remote.provider}{" "}
+                  <a
+                    href={store.remote.web_url}
+                    target="_blank"
+                    rel="noopener noreferrer"
+                    className="font-medium underline underline-offset-2"
+                  >
+                    {store.remote.owner}/{store.remote.repo}
+                  </a>
+                </span>
+              </>
             ) : (
               <>
                 <HardDrive className="h-4 w-4 text-muted-foreground" />
@@ -136,7 +204,7 @@ export default function ProjectSettings() {
 
           {/* Actions */}
           <div className="flex gap-2">
-            {store?.github ? (
+            {store?.github || store?.remote ? (
               <>
                 <Button size="sm" variant="outline" onClick={handleSync} disabled={syncing}>
                   <RefreshCw className={`mr-2 h-3.5 w-3.5 ${syncing ? "animate-spin" : ""}`} />
@@ -154,6 +222,41 @@ export default function ProjectSettings() {
             )}
           </div>
 
+          <div className="flex gap-2">
+            <Button
+              size="sm"
+              variant="ghost"
+              onClick={() => handleOpen(api.openInEditor)}
+            >
+              <Code className="mr-2 h-3.5 w-3.5" />
+              Open in Editor
+            </Button>
+            <Button
+              size="sm"
+              variant="ghost"
+              onClick={() => handleOpen(api.openInTerminal)}
+            >
+              <SquareTerminal className="mr-2 h-3.5 w-3.5" />
+              Open in Terminal
+            </Button>
+            <Button
+              size="sm"
+              variant="ghost"
+              onClick={() => handleOpen(api.openInFileManager)}
+            >
+              <FolderOpen className="mr-2 h-3.5 w-3.5" />
+              Show Folder
+            </Button>
+            <Button size="sm" variant="ghost" onClick={handleBackup}>
+              <Archive className="mr-2 h-3.5 w-3.5" />
+              Back Up
+            </Button>
+            <Button size="sm" variant="ghost" onClick={handleVerify}>
+              <ShieldCheck className="mr-2 h-3.5 w-3.5" />
+              Verify
+            </Button>
+          </div>
+
           {syncMessage && (
             <p className="text-sm text-muted-foreground">{syncMessage}</p>
           )}
@@ -162,6 +265,36 @@ export default function ProjectSettings() {
 
       <Separator className="my-8" />
 
+      {/* Project Policies */}
+      <section className="space-y-4">
+        <h2 className="text-lg font-semibold">Project Policies</h2>
+        <div className="rounded-lg border p-4">
+          <ProjectSettingsForm projectName={projectName} />
+        </div>
+      </section>
+
+      <Separator className="my-8" />
+
+      {/* Amplifier */}
+      <section className="space-y-4">
+        <h2 className="text-lg font-semibold">Amplifier</h2>
+        <div className="rounded-lg border p-4">
+          <AmplifierSettingsForm projectName={projectName} />
+        </div>
+      </section>
+
+      <Separator className="my-8" />
+
+      {/* Project Repository */}
+      <section className="space-y-4">
+        <h2 className="text-lg font-semibold">Project Repository</h2>
+        <div className="rounded-lg border p-4">
+          <ProjectRepoActions projectName={projectName} />
+        </div>
+      </section>
+
+      <Separator className="my-8" />
+
       {/* GitHub Authentication */}
       <section className="space-y-4">
         <h2 className="text-lg font-semibold">GitHub Authentication</h2>
@@ -170,6 +303,16 @@ export default function ProjectSettings() {
         </div>
       </section>
 
+      <Separator className="my-8" />
+
+      {/* Health Check */}
+      <section className="space-y-4">
+        <h2 className="text-lg font-semibold">Health Check</h2>
+        <div className="rounded-lg border p-4">
+          <DoctorChecklist projectName={projectName} />
+        </div>
+      </section>
+
       {/* Connect Dialog */}
       <ConnectGitHubDialog
         open={connectOpen}
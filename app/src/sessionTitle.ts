@@ -0,0 +1,28 @@
+import { api } from "@/api"
+import { ws } from "@/ws"
+
+const BASE_TITLE = document.title
+
+/** Prefix the tab title with the running Amplifier session count. */
+export function startSessionTitle(): () => void {
+  const refresh = () => {
+    api
+      .getTrayStatus()
+      .then(({ running_sessions }) => {
+        document.title = running_sessions
+          ? `(${running_sessions}) ${BASE_TITLE}`
+          : BASE_TITLE
+      })
+      .catch(() => {})
+  }
+  refresh()
+  const offs = [
+    "amplifier:started",
+    "amplifier:completed",
+    "amplifier:failed",
+  ].map((event) => ws.on(event, refresh))
+  return () => {
+    offs.forEach((off) => off())
+    document.title = BASE_TITLE
+  }
+}
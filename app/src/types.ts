@@ -13,13 +13,48 @@ export interface Label {
   default: boolean
 }
 
+export interface Milestone {
+  id: number
+  number: number
+  title: string
+  description: string | null
+  state: "open" | "closed"
+  due_on: string | null
+  created_at: string
+  updated_at: string
+  closed_at: string | null
+}
+
+export interface MilestoneListItem extends Milestone {
+  is_overdue: boolean
+}
+
+export interface ExternalRef {
+  owner: string
+  repo: string
+  id: number
+  number: number | null
+  url: string
+}
+
+export interface LinkedPullRequest {
+  owner: string
+  repo: string
+  number: number
+  url: string
+  title: string | null
+  state: "open" | "closed" | "merged"
+}
+
+export type StateReason = "completed" | "not_planned" | "reopened"
+
 export interface Issue {
   id: number
   number: number
   title: string
   body: string | null
   state: "open" | "closed"
-  state_reason: string | null
+  state_reason: StateReason | null
   labels: Label[]
   assignees: SimpleUser[]
   comments: number
@@ -29,6 +64,19 @@ export interface Issue {
   closed_by: SimpleUser | null
   author_association: string
   user: SimpleUser
+  milestone: Milestone | null
+  source: ExternalRef | null
+  exported_to: ExternalRef[]
+  mentions: string[]
+  branch: string | null
+  linked_prs: LinkedPullRequest[]
+  locked: boolean
+  active_lock_reason: string | null
+  version: number
+}
+
+export interface WorkspaceIssue extends Issue {
+  project: string
 }
 
 export interface Comment {
@@ -38,6 +86,146 @@ export interface Comment {
   created_at: string
   updated_at: string
   author_association: string
+  source: ExternalRef | null
+  mentions: string[]
+  node_id: string | null
+}
+
+export interface ImportResult {
+  issues: number
+  comments: number
+  labels: number
+  milestones: number
+  skipped_issues: number
+}
+
+export interface ExportResult {
+  issues: number
+  comments: number
+  skipped_issues: number
+}
+
+export interface ExportIssuesRequest {
+  owner: string
+  repo: string
+  state?: "open" | "closed" | "all"
+  labels?: string
+  assignee?: string
+}
+
+export type IssueExportFormat = "markdown" | "csv" | "json"
+
+export interface ExportIssuesFileRequest {
+  format: IssueExportFormat
+  destination: string
+  include_comments?: boolean
+  state?: "open" | "closed" | "all"
+  labels?: string
+  assignee?: string
+  mentioned?: string
+}
+
+export interface IssueExportResult {
+  path: string
+  issues: number
+}
+
+export interface IssueMarkdownExportResult {
+  path: string
+  attachments: number
+}
+
+export type IssueImportFormat = "csv" | "jira"
+
+export interface ImportColumnMapping {
+  title: string
+  body?: string | null
+  state?: string | null
+  labels?: string | null
+  assignees?: string | null
+  milestone?: string | null
+  author?: string | null
+  created_at?: string | null
+  closed_at?: string | null
+  comments?: string | null
+}
+
+export interface ImportIssuesFileRequest {
+  source: string
+  format?: IssueImportFormat
+  mapping?: ImportColumnMapping
+}
+
+export interface PullRequestResult {
+  number: number
+  url: string
+  created: boolean
+}
+
+export type MirrorConflictPolicy = "local" | "remote" | "newest"
+
+export interface MirrorConfig {
+  owner: string
+  repo: string
+  conflict_policy: MirrorConflictPolicy
+}
+
+export interface IssueMirror {
+  owner: string
+  repo: string
+  number: number
+  id: number
+  url: string
+  local_updated_at: string
+  remote_updated_at: string
+  comments: Record<string, number>
+}
+
+export interface MirrorSyncResult {
+  pushed: number[]
+  pulled: number[]
+  conflicts: number[]
+  comments_pushed: number
+  comments_pulled: number
+}
+
+export interface ActivityEvent {
+  sha: string
+  actor: string
+  action: string
+  issue_number: number | null
+  summary: string
+  timestamp: string
+}
+
+export type ApiErrorCode =
+  | "STORE_MISSING"
+  | "COMMIT_SIGNING_FAILED"
+  | "SAML_SSO_REQUIRED"
+  | "REPO_CREATE_FORBIDDEN"
+  | "STALE_WRITE"
+  | "READ_ONLY"
+  | "INVALID_BRANCH"
+  | "STORE_ID_MISMATCH"
+  | "ID_RESERVATION_FAILED"
+  | "STORE_NAME_EXHAUSTED"
+  | "ISSUE_LOCKED"
+  | "AMPLIFIER_FAILED"
+  | "INVALID_AMPLIFIER_SETTINGS"
+  | "VALIDATION_ERROR"
+  | "BAD_REQUEST"
+  | "UNAUTHORIZED"
+  | "FORBIDDEN"
+  | "NOT_FOUND"
+  | "CONFLICT"
+  | "BAD_GATEWAY"
+  | "INTERNAL_SERVER_ERROR"
+  | (string & {})
+
+export interface ApiErrorDetail {
+  error: ApiErrorCode
+  message: string
+  [key: string]: unknown
 }
 
 export interface ListResponse<T> {
@@ -45,25 +233,136 @@ export interface ListResponse<T> {
   total_count: number
   page: number
   per_page: number
+  warnings?: string[]
 }
 
 export interface AmplifierSessionInfo {
+  projectName: string
   issueNumber: number
   status: "running" | "completed" | "failed"
   startedAt: string
   finishedAt: string | null
   error: string | null
+  logFile: string | null
+  paths: string[]
+}
+
+export type AmplifierProvider = "anthropic" | "openai" | "azure" | "local"
+
+export interface AmplifierProfile {
+  name: string
+  provider: AmplifierProvider
+  model: string
+  base_url: string | null
+  has_api_key: boolean
+}
+
+export interface AmplifierProfiles {
+  profiles: AmplifierProfile[]
+  default: string | null
 }
 
+export interface AmplifierProviderSettings {
+  module: string
+  source: string | null
+  default_model: string | null
+  base_url: string | null
+  base_url_key: string
+  enable_prompt_caching: boolean | null
+  priority: number | null
+}
+
+export interface AmplifierSettingsFile {
+  path: string
+  exists: boolean
+  managed: boolean
+  content: string
+  providers: AmplifierProviderSettings[]
+  error: string | null
+}
+
+export interface CommentCommandsConfig {
+  synced: boolean
+}
+
+export interface ProjectAmplifierProfile {
+  profile: string | null
+  effective: string | null
+}
+
+export interface AmplifierSessionLogs {
+  log_file: string
+  lines: string[]
+  truncated: number
+}
+
+export interface OpenProgress {
+  project: string
+  operation_id: string | null
+  phase: string
+  percent: number
+  current: number
+  total: number
+  bytes: number | null
+}
+
+export interface NetworkOperation {
+  id: string
+  kind: string
+  project: string | null
+  started_at: string
+  cancelled: boolean
+}
+
+export interface OpenProject {
+  name: string
+  store_path: string
+  opened_at: string
+  last_accessed: string
+  running_sessions: number
+}
+
+export interface Workspace {
+  name: string
+  projects: string[]
+}
+
+export type GitTransport = "https" | "ssh"
+
 export interface GitHubStoreConfig {
   owner: string
   repo: string
   remote_url: string
+  transport: GitTransport
+  ssh_key_path: string | null
+}
+
+export interface RemoteStoreConfig {
+  provider: string
+  base_url: string
+  owner: string
+  repo: string
+  web_url: string
+  user: string
 }
 
 export interface StoreConfig {
   path: string
   github: GitHubStoreConfig | null
+  remote: RemoteStoreConfig | null
+  branch: string | null
+}
+
+export interface GitProviderInfo {
+  kind: string
+  default_base_url: string
+}
+
+export interface ProviderAccount {
+  provider: string
+  base_url: string
+  user: string
+  validated_at: string
 }
 
 export interface GitHubStatus {
@@ -72,10 +371,67 @@ export interface GitHubStatus {
   validated_at: string | null
 }
 
+export interface GitHubAccount {
+  user: string
+  validated_at: string
+  is_default: boolean
+  source: "token" | "credential_helper"
+  commit_email: string
+}
+
+export interface GitHubEmail {
+  email: string
+  primary: boolean
+  verified: boolean
+}
+
+export interface AccountEmails {
+  available: boolean
+  emails: GitHubEmail[]
+  selected: string | null
+}
+
+export interface CommitSigning {
+  format: "ssh" | "gpg"
+  key: string
+}
+
+export interface CommitIdentity {
+  name: string | null
+  email: string | null
+}
+
 export interface StoreStatus {
   store_id: string
   path: string
   github: GitHubStoreConfig | null
+  remote: RemoteStoreConfig | null
+  github_account: string | null
+  signing: CommitSigning | null
+  branch: string | null
+  author: CommitIdentity | null
+}
+
+export interface VerifyStoreResult {
+  quarantined: string[]
+  recovered: number[]
+  unrecoverable: string[]
+}
+
+export interface StaticSiteResult {
+  branch: string
+  issues: number
+  changed: boolean
+  pushed: boolean
+  url: string | null
+}
+
+export type CollaboratorPermission = "pull" | "triage" | "push" | "maintain" | "admin"
+
+export interface Collaborator {
+  login: string
+  avatar_url: string
+  permission: string
 }
 
 export interface SyncResult {
@@ -83,14 +439,227 @@ export interface SyncResult {
   pushed: boolean
 }
 
+export interface SyncAllResult {
+  results: Record<string, SyncResult>
+  missing: string[]
+}
+
+export interface TrayStatus {
+  running_sessions: number
+  last_project: string | null
+}
+
+export interface DeviceLogin {
+  status: "pending" | "completed" | "failed" | "expired"
+  user_code: string
+  verification_uri: string
+  expires_at: string
+  user: string | null
+  error: string | null
+}
+
+export interface GitHubLogout {
+  removed_accounts: string[]
+  removed_projects: string[]
+}
+
+export interface CapabilityCheck {
+  name: string
+  ok: boolean
+  detail: string
+  fix: string | null
+}
+
+export interface TokenCapabilities {
+  user: string | null
+  token_type: "classic" | "fine-grained" | null
+  scopes: string[] | null
+  checks: CapabilityCheck[]
+  missing: string[]
+}
+
+export interface DoctorReport {
+  ok: boolean
+  checks: CapabilityCheck[]
+}
+
+export interface UpdateStatus {
+  current: string | null
+  latest: string | null
+  available: boolean
+}
+
+export interface LogEntry {
+  time: string
+  level: string
+  logger: string
+  message: string
+  fields: Record<string, string>
+  exception: string | null
+}
+
+export interface RateLimitBucket {
+  limit: number
+  remaining: number
+  used: number
+  reset: number
+}
+
+export interface RateLimitStatus {
+  core: RateLimitBucket
+  search: RateLimitBucket
+}
+
 export interface PatUrl {
   url: string
   required_permissions: string[]
 }
 
+export interface RecentIssue {
+  project: string
+  number: number
+}
+
+export interface DesktopNotificationSettings {
+  amplifier: boolean
+  sync_conflicts: boolean
+  milestones: boolean
+}
+
+export type IssueField = "body" | "labels" | "assignees"
+
+export type MergeField =
+  | "title"
+  | "body"
+  | "state"
+  | "labels"
+  | "assignees"
+  | "milestone"
+
+export type MergePolicy =
+  | "prefer_latest"
+  | "prefer_local"
+  | "prefer_remote"
+  | "union"
+  | "prefer_closed"
+  | "prefer_open"
+
+export interface ProjectSettings {
+  default_labels: string[]
+  required_fields: IssueField[]
+  amplifier_enabled: boolean
+  amplifier_instructions: string | null
+  comment_commands: boolean
+  branch_template: string
+  merge_policies: Partial<Record<MergeField, MergePolicy>>
+  commit_template: string | null
+  sequential_ids: boolean
+}
+
+export interface ProjectGitStatus {
+  branch: string | null
+  upstream: string | null
+  ahead: number
+  behind: number
+  modified: string[]
+  untracked: string[]
+  clean: boolean
+}
+
+export interface AppSettings {
+  auto_sync_interval: number
+  max_amplifier_sessions: number
+  default_labels: string[]
+  editor_command: string | null
+  terminal_command: string | null
+  commit_name: string | null
+  commit_email: string | null
+  commit_as_bot: boolean
+}
+
+export interface SettingsExport {
+  version: number
+  settings: AppSettings
+  desktop_notifications: DesktopNotificationSettings
+}
+
 export interface AppConfig {
   pat_banner_dismissed: boolean
   recent_projects: string[]
+  recent_issues: RecentIssue[]
+  desktop_notifications: DesktopNotificationSettings
+  settings: AppSettings
+}
+
+export type NotificationKind =
+  | "assignment"
+  | "mention"
+  | "amplifier"
+  | "sync"
+  | "conflict"
+  | "milestone"
+
+export interface Notification {
+  id: string
+  kind: NotificationKind
+  project: string
+  issue_number: number | null
+  title: string
+  created_at: string
+  read: boolean
+}
+
+export interface NotificationList {
+  items: Notification[]
+  unread_count: number
+}
+
+export interface QuickSwitcherItems {
+  issues: { project: string; number: number; title: string; state: "open" | "closed" }[]
+  projects: string[]
+  labels: { name: string; color: string; count: number }[]
+  label_project: string | null
+}
+
+export interface IssuePolishSuggestion {
+  number: number
+  version: number
+  title: string
+  body: string
+}
+
+export interface LabelSuggestion {
+  label: Label
+  confidence: number
+}
+
+export interface PlannedIssue {
+  number: number
+  title: string
+  estimate: string | null
+  rationale: string
+}
+
+export interface MilestonePlan {
+  milestone: number
+  summary: string
+  issues: PlannedIssue[]
+}
+
+export interface SimilarIssue {
+  number: number
+  title: string
+  state: "open" | "closed"
+  score: number
+}
+
+export type AutocompleteKind = "labels" | "milestones" | "users" | "issues"
+
+export interface AutocompleteItem {
+  value: string
+  label: string
+  color: string | null
+  avatar_url: string | null
 }
 
 export interface PathValidationResponse {
@@ -114,6 +683,23 @@ export interface GitHubRepo {
   description: string | null
   private: boolean
   html_url: string
+  can_push: boolean
+}
+
+export interface DiscoveredStore {
+  owner: string
+  repo: string
+  html_url: string
+  private: boolean
+  store_id: string | null
+  project_hint: string
+  linked_projects: string[]
+  last_activity: string | null
+}
+
+export interface GitHubOrg {
+  login: string
+  avatar_url: string
 }
 
 export interface CreateProjectRequest {
@@ -122,4 +708,10 @@ export interface CreateProjectRequest {
   path?: string
   owner?: string
   repo?: string
+  url?: string
+  transport?: GitTransport
+  ssh_key_path?: string
+  github_account?: string
+  branch?: string
+  operation_id?: string
 }
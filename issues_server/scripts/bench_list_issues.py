@@ -0,0 +1,90 @@
+"""Time issue listing on a generated store.
+
+Run with: uv run python scripts/bench_list_issues.py [--issues 5000] [--repeat 3]
+"""
+
+import argparse
+import json
+import subprocess
+import tempfile
+import time
+from collections.abc import Callable
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+
+from issues_server.core.models import LOCAL_USER, Issue, IssueFilters, Label
+from issues_server.core.storage import ProjectStorage
+
+_LABELS = [
+    Label(id=1, name="bug", color="d73a4a"),
+    Label(id=2, name="enhancement", color="a2eeef"),
+    Label(id=3, name="docs", color="0075ca"),
+]
+
+
+def build_store(path: Path, count: int) -> ProjectStorage:
+    """A store of *count* issues, a third closed, spread over the labels."""
+    subprocess.run(["git", "init", "-q", str(path)], check=True)
+    storage = ProjectStorage(path)
+    storage.issues_dir.mkdir(parents=True, exist_ok=True)
+    start = datetime(2024, 1, 1, tzinfo=timezone.utc)
+    for number in range(1, count + 1):
+        created = start + timedelta(minutes=number)
+        issue = Issue(
+            id=number,
+            number=number,
+            title=f"Issue {number}",
+            body="Steps to reproduce\n\n1. Open the app\n2. Click around\n" * 4,
+            state="closed" if number % 3 == 0 else "open",
+            labels=[_LABELS[number % len(_LABELS)]],
+            created_at=created,
+            updated_at=created,
+            user=LOCAL_USER,
+        )
+        # Written directly; write_issue's bookkeeping is not what is measured.
+        (storage.issues_dir / f"{number}.json").write_text(
+            json.dumps(issue.model_dump(mode="json"), indent=2) + "\n"
+        )
+    return storage
+
+
+def best_of(repeat: int, run: Callable[[], object]) -> float:
+    times = []
+    for _ in range(repeat):
+        began = time.perf_counter()
+        run()
+        times.append(time.perf_counter() - began)
+    return min(times)
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("--issues", type=int, default=5000)
+    parser.add_argument("--repeat", type=int, default=3)
+    args = parser.parse_args()
+
+    with tempfile.TemporaryDirectory() as tmp:
+        began = time.perf_counter()
+        storage = build_store(Path(tmp), args.issues)
+        print(f"Built {args.issues} issues in {time.perf_counter() - began:.1f}s")
+
+        cases = {
+            "list_issues, first page, all states": lambda: storage.list_issues(
+                IssueFilters(state="all")
+            ),
+            "list_issues, first page, closed bugs": lambda: storage.list_issues(
+                IssueFilters(state="closed", labels="bug")
+            ),
+            "all_issues, all states": lambda: storage.all_issues(
+                IssueFilters(state="all")
+            ),
+            "all_issues, closed bugs": lambda: storage.all_issues(
+                IssueFilters(state="closed", labels="bug")
+            ),
+        }
+        for name, run in cases.items():
+            print(f"{name}: {best_of(args.repeat, run):.3f}s")
+
+
+if __name__ == "__main__":
+    main()
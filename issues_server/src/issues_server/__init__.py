@@ -26,8 +26,31 @@ def main() -> None:
         default=None,
         help="Override listen port.",
     )
+    parser.add_argument(
+        "--mcp",
+        action="store_true",
+        help="Serve the issue tracker to AI agents over MCP on stdin/stdout.",
+    )
+    parser.add_argument(
+        "--project",
+        type=str,
+        default=None,
+        help="Project MCP tools use when a call names none.",
+    )
     args = parser.parse_args()
 
+    if args.mcp:
+        from .core.config import Settings
+        from .core.logs import configure_logging
+        from .mcp_server import run_stdio
+        from .core.network import configure_network
+
+        settings = Settings()
+        configure_logging(settings)
+        configure_network(settings)
+        run_stdio(settings, args.project)
+        return
+
     if args.update_frontend:
         args.production = True
 
@@ -41,7 +64,7 @@ def main() -> None:
 
     # In production mode, fetch the frontend build
     if args.production:
-        from .config import Settings
+        from .core.config import Settings
         from .frontend import fetch_frontend
 
         settings = Settings()
@@ -7,16 +7,36 @@ results back as issue comments.
 
 import asyncio
 import json
+import os
+import re
 import signal
 from dataclasses import dataclass, field
 from datetime import datetime, timezone
 from pathlib import Path
-
-from .models import ATTRACTOR_BOT, AmplifierSessionInfo, Comment, Issue
-from .storage import ProjectStorage
+from typing import BinaryIO
+
+from .core.errors import AppError
+from .core.logs import redact
+from .core.models import (
+    ATTRACTOR_BOT,
+    AmplifierProfile,
+    AmplifierSessionInfo,
+    AmplifierSessionLogs,
+    Comment,
+    Issue,
+    IssuePolishSuggestion,
+    Label,
+    LabelSuggestion,
+    Milestone,
+    MilestonePlan,
+    PlannedIssue,
+)
+from .core.storage import ProjectStorage
+from .notifications import NotificationCenter, new_notification, notify
 from .ws import WebSocketManager
 
-_DEFAULT_SETTINGS_YAML = """\
+# What every project got before profiles; replaced like a generated file.
+LEGACY_SETTINGS_YAML = """\
 config:
   providers:
   - module: provider-anthropic
@@ -29,6 +49,117 @@ config:
     source: git+https://github.com/microsoft/amplifier-module-provider-anthropic@main
 """
 
+# Settings files starting with this line are regenerated when the profile changes.
+SETTINGS_MARKER = (
+    "# Written by Attractor from an Amplifier profile. "
+    "Delete this line to keep your own edits.\n"
+)
+
+# Without a configured profile, sessions use Anthropic as they always have.
+DEFAULT_PROFILE = AmplifierProfile(
+    name="default", provider="anthropic", model="claude-opus-4-6"
+)
+
+# Per provider: Amplifier module, API key variable, and endpoint key and default.
+_PROVIDER_MODULES: dict[str, tuple[str, str | None, str, str | None]] = {
+    "anthropic": (
+        "provider-anthropic",
+        "ANTHROPIC_API_KEY",
+        "base_url",
+        "https://api.anthropic.com",
+    ),
+    "openai": (
+        "provider-openai",
+        "OPENAI_API_KEY",
+        "base_url",
+        "https://api.openai.com/v1",
+    ),
+    "azure": ("provider-azure-openai", "AZURE_OPENAI_API_KEY", "azure_endpoint", None),
+    "local": ("provider-ollama", None, "host", "http://localhost:11434"),
+}
+
+
+def settings_yaml(profile: AmplifierProfile) -> str:
+    """``settings.local.yaml`` selecting *profile*'s provider and model.
+
+    The API key is referenced by variable, so it is never written to disk.
+    """
+    module, key_var, url_key, default_url = _PROVIDER_MODULES[profile.provider]
+    # JSON strings are valid YAML scalars, and quote anything unusual.
+    lines = ["config:", "  providers:", f"  - module: {module}", "    config:"]
+    if key_var:
+        lines.append(f"      api_key: ${{{key_var}}}")
+    if url := profile.base_url or default_url:
+        lines.append(f"      {url_key}: {json.dumps(url)}")
+    lines.append(f"      default_model: {json.dumps(profile.model)}")
+    if profile.provider == "anthropic":
+        lines.append("      enable_prompt_caching: 'true'")
+    lines.append("      priority: 1")
+    lines.append(
+        f"    source: git+https://github.com/microsoft/amplifier-module-{module}@main"
+    )
+    return SETTINGS_MARKER + "\n".join(lines) + "\n"
+
+
+def settings_file(project_dir: Path) -> Path:
+    return project_dir / ".amplifier" / "settings.local.yaml"
+
+
+def _profile_env(profile: AmplifierProfile | None) -> dict[str, str] | None:
+    """Environment passing *profile*'s API key, or None to inherit ours."""
+    if profile is None or not profile.api_key:
+        return None
+    key_var = _PROVIDER_MODULES[profile.provider][1]
+    if key_var is None:
+        return None
+    return {**os.environ, key_var: profile.api_key}
+
+# Session stderr logs kept per log directory; the oldest are deleted first.
+MAX_SESSION_LOGS = 50
+
+# One-off questions block their request, so they get less time than a session.
+ASK_TIMEOUT = 300
+
+# Keeps a planning prompt for a large backlog within the model's context.
+PLAN_MAX_ISSUES = 100
+PLAN_BODY_CHARS = 300
+
+_SCOPE_INSTRUCTIONS = """\
+Only read and change files under these paths; treat the rest of the \
+repository as out of scope unless the issue cannot be solved without it:\
+"""
+
+_POLISH_REQUEST = """\
+Rewrite the issue above so it is clear and easy to act on. Do not change any \
+files. Keep every fact from the original and invent none. Reply with only a \
+JSON object with two string fields: "title", a short specific title, and \
+"body", a Markdown description with "## Summary", "## Steps to reproduce" \
+(only for bugs), "## Expected behavior" and "## Acceptance criteria" sections.\
+"""
+
+_LABELS_REQUEST = """\
+Pick the labels below that fit the issue above. Do not change any files. \
+Use only names from the list. Reply with only a JSON object with a "labels" \
+array of {"name": ..., "confidence": ...} objects, confidence from 0 to 1, \
+best fit first, leaving out labels that do not apply.\
+"""
+
+_PLAN_REQUEST = """\
+Propose which of the open issues above belong in the milestone above. Do not \
+change any files. Keep to the constraints, if any. Use an issue's size or \
+estimate label when it has one, otherwise estimate it as S, M or L. Reply \
+with only a JSON object with a "summary" string explaining the plan and an \
+"issues" array of {"number": ..., "estimate": ..., "rationale": ...} objects, \
+in the order they should be worked on.\
+"""
+
+
+class AmplifierAskError(AppError):
+    """A one-off Amplifier question failed or got an unusable answer."""
+
+    status_code = 502
+    code = "AMPLIFIER_FAILED"
+
 
 def _extract_json(text: str) -> dict | None:
     """Extract the last valid JSON object from *text*.
@@ -66,6 +197,8 @@ class AmplifierSession:
     started_at: str
     finished_at: str | None = None
     error: str | None = None
+    log_path: Path | None = None
+    paths: list[str] = field(default_factory=list)
     task: asyncio.Task | None = field(default=None, repr=False)  # type: ignore[type-arg]
     process: asyncio.subprocess.Process | None = field(default=None, repr=False)
 
@@ -84,19 +217,62 @@ class AmplifierManager:
         return f"{project_name}#{issue_number}"
 
     @staticmethod
-    def _ensure_settings(project_path: Path) -> None:
-        """Write default Amplifier settings if none exist."""
-        settings_path = project_path / ".amplifier" / "settings.local.yaml"
+    def _info(session: AmplifierSession) -> AmplifierSessionInfo:
+        return AmplifierSessionInfo(
+            project_name=session.project_name,
+            issue_number=session.issue_number,
+            status=session.status,
+            started_at=session.started_at,
+            finished_at=session.finished_at,
+            error=session.error,
+            log_file=str(session.log_path) if session.log_path else None,
+            paths=session.paths,
+        )
+
+    @staticmethod
+    def _open_log(
+        log_dir: Path, project_name: str, issue_number: int
+    ) -> tuple[Path, BinaryIO]:
+        """Create the stderr log for a new session, pruning the oldest ones."""
+        log_dir.mkdir(parents=True, exist_ok=True)
+        logs = sorted(log_dir.glob("*.log"), key=lambda p: p.stat().st_mtime)
+        for old in logs[: max(len(logs) - MAX_SESSION_LOGS + 1, 0)]:
+            old.unlink(missing_ok=True)
+        safe_name = re.sub(r"[^A-Za-z0-9._-]", "_", project_name)
+        stamp = datetime.now(timezone.utc).strftime("%Y%m%dT%H%M%S")
+        path = log_dir / f"{safe_name}-{issue_number}-{stamp}.log"
+        return path, path.open("wb")
+
+    @staticmethod
+    def _ensure_settings(
+        project_path: Path, profile: AmplifierProfile | None = None
+    ) -> None:
+        """Write Amplifier settings for *profile*, unless the user wrote their own."""
+        settings_path = settings_file(project_path)
+        content = settings_yaml(profile or DEFAULT_PROFILE)
         if settings_path.exists():
-            return
+            current = settings_path.read_text()
+            if current == content:
+                return
+            if current != LEGACY_SETTINGS_YAML and not current.startswith(
+                SETTINGS_MARKER
+            ):
+                return
         settings_path.parent.mkdir(parents=True, exist_ok=True)
-        settings_path.write_text(_DEFAULT_SETTINGS_YAML)
+        settings_path.write_text(content)
 
     @staticmethod
-    def _build_prompt(issue: Issue) -> str:
+    def _build_prompt(
+        issue: Issue, instructions: str | None = None, paths: list[str] | None = None
+    ) -> str:
         prompt = f"Issue #{issue.number}: {issue.title}"
         if issue.body:
             prompt += f"\n\n{issue.body}"
+        if paths:
+            prompt += "\n\n" + _SCOPE_INSTRUCTIONS
+            prompt += "".join(f"\n- {path}" for path in paths)
+        if instructions:
+            prompt += f"\n\n{instructions}"
         return prompt
 
     # ------------------------------------------------------------------
@@ -111,6 +287,12 @@ class AmplifierManager:
         project_storage: ProjectStorage,
         ws_manager: WebSocketManager,
         project_dir: Path | None = None,
+        notifications: NotificationCenter | None = None,
+        max_sessions: int = 0,
+        instructions: str | None = None,
+        log_dir: Path | None = None,
+        profile: AmplifierProfile | None = None,
+        paths: list[str] | None = None,
     ) -> None:
         """Launch an Amplifier session for the given issue.
 
@@ -118,9 +300,19 @@ class AmplifierManager:
             project_dir: Project metadata directory used for CWD and
                 ``.amplifier/`` settings.  Falls back to the store path
                 when *None* (legacy behaviour).
+            max_sessions: Refuse to start when this many sessions are
+                already running; 0 means no limit.
+            instructions: Project-specific guidance appended to the prompt.
+            log_dir: Directory to write the session's full stderr to. Without
+                it only the last lines of stderr survive, in the error.
+            profile: Provider and model to use; *None* is the built-in
+                Anthropic setup.
+            paths: Files or directories to confine the work to. The CLI has
+                no such option, so they are listed in the prompt.
 
         Raises:
-            ValueError: If a session is already running for this issue.
+            ValueError: If a session is already running for this issue, or
+                *max_sessions* are running.
         """
         key = self._key(project_name, issue_number)
 
@@ -129,38 +321,61 @@ class AmplifierManager:
             raise ValueError(
                 f"Amplifier session already running for {project_name} issue #{issue_number}"
             )
+        running = sum(1 for s in self.sessions.values() if s.status == "running")
+        if max_sessions and running >= max_sessions:
+            raise ValueError(
+                f"{running} Amplifier sessions are already running, the configured limit"
+            )
 
         cwd = project_dir or project_storage.path
-        self._ensure_settings(cwd)
+        self._ensure_settings(cwd, profile)
 
-        prompt = self._build_prompt(issue)
+        prompt = self._build_prompt(issue, instructions, paths)
 
-        process = await asyncio.create_subprocess_exec(
-            "amplifier",
-            "run",
-            "--output-format",
-            "json",
-            prompt,
-            cwd=str(cwd),
-            stdout=asyncio.subprocess.PIPE,
-            stderr=asyncio.subprocess.PIPE,
-        )
+        log_path = None
+        stderr: int | BinaryIO = asyncio.subprocess.PIPE
+        if log_dir is not None:
+            log_path, stderr = self._open_log(log_dir, project_name, issue_number)
+        try:
+            process = await asyncio.create_subprocess_exec(
+                "amplifier",
+                "run",
+                "--output-format",
+                "json",
+                prompt,
+                cwd=str(cwd),
+                env=_profile_env(profile),
+                stdout=asyncio.subprocess.PIPE,
+                stderr=stderr,
+            )
+        finally:
+            # The child holds its own handle, so the log keeps filling.
+            if not isinstance(stderr, int):
+                stderr.close()
 
         session = AmplifierSession(
             project_name=project_name,
             issue_number=issue_number,
             status="running",
             started_at=datetime.now(timezone.utc).isoformat(),
+            log_path=log_path,
+            paths=list(paths or []),
         )
         session.process = process
         self.sessions[key] = session
 
         await ws_manager.broadcast(
             "amplifier:started",
-            {"project": project_name, "issueNumber": issue_number},
+            {
+                "project": project_name,
+                "issueNumber": issue_number,
+                "paths": session.paths,
+            },
         )
 
-        task = asyncio.create_task(self._wait(key, project_storage, ws_manager))
+        task = asyncio.create_task(
+            self._wait(key, project_storage, ws_manager, notifications)
+        )
         session.task = task
 
     async def _wait(
@@ -168,6 +383,7 @@ class AmplifierManager:
         key: str,
         project_storage: ProjectStorage,
         ws_manager: WebSocketManager,
+        notifications: NotificationCenter | None = None,
     ) -> None:
         """Wait for the subprocess to finish and record the result."""
         session = self.sessions[key]
@@ -188,7 +404,10 @@ class AmplifierManager:
                 else:
                     error_msg = parsed.get("error", "Amplifier session failed")
             else:
-                stderr_text = stderr_bytes.decode()
+                if session.log_path is not None:
+                    stderr_text = session.log_path.read_text(errors="replace")
+                else:
+                    stderr_text = stderr_bytes.decode()
                 tail = "\n".join(stderr_text.splitlines()[-10:])
                 error_msg = (
                     tail or f"Process exited with code {session.process.returncode}"
@@ -218,7 +437,9 @@ class AmplifierManager:
                 project_storage.write_issue(issue)
 
             project_storage.commit(
-                f"amplifier: result for issue #{session.issue_number}"
+                f"amplifier: result for issue #{session.issue_number}",
+                event="amplifier.result",
+                issue=session.issue_number,
             )
             project_storage.push()
 
@@ -261,6 +482,186 @@ class AmplifierManager:
                 },
             )
 
+        if notifications is not None:
+            verb = "finished" if session.status == "completed" else "failed"
+            await notify(
+                notifications,
+                ws_manager,
+                new_notification(
+                    "amplifier",
+                    session.project_name,
+                    f"Amplifier {verb} on #{session.issue_number}",
+                    session.issue_number,
+                ),
+            )
+
+    async def _ask(
+        self, prompt: str, project_dir: Path, profile: AmplifierProfile | None
+    ) -> dict:
+        """Run a one-off Amplifier prompt and parse the JSON object it replies with.
+
+        Not tracked as a session, so it neither counts toward nor blocks one.
+        """
+        self._ensure_settings(project_dir, profile)
+        process = await asyncio.create_subprocess_exec(
+            "amplifier",
+            "run",
+            "--output-format",
+            "json",
+            prompt,
+            cwd=str(project_dir),
+            env=_profile_env(profile),
+            stdout=asyncio.subprocess.PIPE,
+            stderr=asyncio.subprocess.PIPE,
+        )
+        try:
+            stdout_bytes, _ = await asyncio.wait_for(
+                process.communicate(), ASK_TIMEOUT
+            )
+        except TimeoutError:
+            process.kill()
+            raise AmplifierAskError(
+                f"Amplifier did not answer within {ASK_TIMEOUT} seconds"
+            )
+
+        parsed = _extract_json(stdout_bytes.decode())
+        if parsed is None or parsed.get("status") != "success":
+            raise AmplifierAskError(
+                (parsed or {}).get("error") or "Amplifier session failed"
+            )
+        reply = _extract_json(parsed.get("response", ""))
+        if reply is None:
+            raise AmplifierAskError("Amplifier's reply was not the JSON asked for")
+        return reply
+
+    async def polish(
+        self,
+        issue: Issue,
+        project_dir: Path,
+        instructions: str | None = None,
+        profile: AmplifierProfile | None = None,
+    ) -> IssuePolishSuggestion:
+        """Ask Amplifier for a clearer title and structured description.
+
+        Nothing is written; the caller applies the suggestion with an update.
+        """
+        reply = await self._ask(
+            self._build_prompt(
+                issue, "\n\n".join(filter(None, [instructions, _POLISH_REQUEST]))
+            ),
+            project_dir,
+            profile,
+        )
+        if not reply.get("title") or not reply.get("body"):
+            raise AmplifierAskError("Amplifier's reply had no title and description")
+        return IssuePolishSuggestion(
+            number=issue.number,
+            version=issue.version,
+            title=str(reply["title"]).strip(),
+            body=str(reply["body"]).strip(),
+        )
+
+    async def suggest_labels(
+        self,
+        issue: Issue,
+        labels: list[Label],
+        project_dir: Path,
+        instructions: str | None = None,
+        profile: AmplifierProfile | None = None,
+    ) -> list[LabelSuggestion]:
+        """Rank the project's *labels* by how well they fit *issue*, best first.
+
+        Names Amplifier makes up, and labels already on the issue, are dropped.
+        """
+        if not labels:
+            return []
+        catalog = "\n".join(
+            f"- {label.name}" + (f": {label.description}" if label.description else "")
+            for label in labels
+        )
+        request = f"{_LABELS_REQUEST}\n\nLabels:\n{catalog}"
+        reply = await self._ask(
+            self._build_prompt(
+                issue, "\n\n".join(filter(None, [instructions, request]))
+            ),
+            project_dir,
+            profile,
+        )
+        by_name = {label.name.lower(): label for label in labels}
+        current = {label.name.lower() for label in issue.labels}
+        suggestions: dict[str, LabelSuggestion] = {}
+        for item in reply.get("labels") or []:
+            if not isinstance(item, dict):
+                continue
+            label = by_name.get(str(item.get("name", "")).lower())
+            if label is None or label.name.lower() in current:
+                continue
+            try:
+                score = min(max(float(item.get("confidence", 0)), 0.0), 1.0)
+            except (TypeError, ValueError):
+                score = 0.0
+            suggestions.setdefault(
+                label.name.lower(), LabelSuggestion(label=label, confidence=score)
+            )
+        return sorted(suggestions.values(), key=lambda s: -s.confidence)
+
+    async def plan_milestone(
+        self,
+        milestone: Milestone,
+        candidates: list[Issue],
+        project_dir: Path,
+        constraints: str | None = None,
+        instructions: str | None = None,
+        profile: AmplifierProfile | None = None,
+    ) -> MilestonePlan:
+        """Propose which of the open *candidates* belong in *milestone*.
+
+        Issues outside *candidates* are dropped from the reply; nothing is written.
+        """
+        prompt = f"Milestone #{milestone.number}: {milestone.title}"
+        if milestone.description:
+            prompt += f"\n\n{milestone.description}"
+        if milestone.due_on:
+            prompt += f"\n\nDue {milestone.due_on.date().isoformat()}"
+        lines = []
+        for issue in candidates:
+            line = f"#{issue.number} {issue.title}"
+            if issue.labels:
+                line += f" [{', '.join(label.name for label in issue.labels)}]"
+            if issue.milestone and issue.milestone.number == milestone.number:
+                line += " (already in this milestone)"
+            if issue.body:
+                line += f"\n  {' '.join(issue.body.split())[:PLAN_BODY_CHARS]}"
+            lines.append(line)
+        prompt += "\n\nOpen issues:\n" + "\n".join(lines)
+        if constraints:
+            prompt += f"\n\nConstraints: {constraints}"
+        prompt += "\n\n" + "\n\n".join(filter(None, [instructions, _PLAN_REQUEST]))
+
+        reply = await self._ask(prompt, project_dir, profile)
+        by_number = {issue.number: issue for issue in candidates}
+        planned: dict[int, PlannedIssue] = {}
+        for item in reply.get("issues") or []:
+            if not isinstance(item, dict):
+                continue
+            try:
+                issue = by_number.get(int(str(item.get("number", "")).lstrip("#")))
+            except ValueError:
+                continue
+            if issue is None or issue.number in planned:
+                continue
+            planned[issue.number] = PlannedIssue(
+                number=issue.number,
+                title=issue.title,
+                estimate=str(item["estimate"]) if item.get("estimate") else None,
+                rationale=str(item.get("rationale") or ""),
+            )
+        return MilestonePlan(
+            milestone=milestone.number,
+            summary=str(reply.get("summary") or ""),
+            issues=list(planned.values()),
+        )
+
     def get_status(
         self, project_name: str, issue_number: int
     ) -> AmplifierSessionInfo | None:
@@ -268,12 +669,27 @@ class AmplifierManager:
         session = self.sessions.get(self._key(project_name, issue_number))
         if session is None:
             return None
-        return AmplifierSessionInfo(
-            issue_number=session.issue_number,
-            status=session.status,
-            started_at=session.started_at,
-            finished_at=session.finished_at,
-            error=session.error,
+        return self._info(session)
+
+    def get_session_logs(
+        self, project_name: str, issue_number: int, tail: int = 200
+    ) -> AmplifierSessionLogs | None:
+        """The last *tail* lines of a session's stderr so far.
+
+        ``None`` when there is no session, or it was started without a log.
+        """
+        session = self.sessions.get(self._key(project_name, issue_number))
+        if session is None or session.log_path is None:
+            return None
+        try:
+            text = session.log_path.read_text(errors="replace")
+        except FileNotFoundError:
+            return None
+        lines = text.splitlines()
+        return AmplifierSessionLogs(
+            log_file=str(session.log_path),
+            lines=[redact(line) for line in lines[-tail:]],
+            truncated=max(len(lines) - tail, 0),
         )
 
     def cancel(self, project_name: str, issue_number: int) -> bool:
@@ -284,15 +700,26 @@ class AmplifierManager:
         session.process.send_signal(signal.SIGTERM)
         return True
 
-    def list_sessions(self) -> list[AmplifierSessionInfo]:
-        """Return info for every tracked session."""
+    def cancel_project(self, project_name: str) -> int:
+        """SIGTERM every running session of *project_name*. Returns how many."""
+        running = [
+            s
+            for s in self.sessions.values()
+            if s.project_name == project_name and s.status == "running"
+        ]
+        return sum(self.cancel(project_name, s.issue_number) for s in running)
+
+    def cancel_all(self) -> int:
+        """SIGTERM every running session. Returns how many."""
+        running = [s for s in self.sessions.values() if s.status == "running"]
+        return sum(self.cancel(s.project_name, s.issue_number) for s in running)
+
+    def list_sessions(
+        self, project_name: str | None = None
+    ) -> list[AmplifierSessionInfo]:
+        """Return info for every tracked session, optionally for one project."""
         return [
-            AmplifierSessionInfo(
-                issue_number=s.issue_number,
-                status=s.status,
-                started_at=s.started_at,
-                finished_at=s.finished_at,
-                error=s.error,
-            )
+            self._info(s)
             for s in self.sessions.values()
+            if project_name is None or s.project_name == project_name
         ]
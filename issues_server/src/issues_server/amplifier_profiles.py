@@ -0,0 +1,58 @@
+"""Named Amplifier provider profiles, kept in ``amplifier-profiles.json``.
+
+API keys go to the OS keyring when it is usable, like provider account tokens.
+"""
+
+import json
+from pathlib import Path
+
+from .core.config import Settings
+from .core.models import AmplifierProfile, AmplifierProfiles, ProjectConfig
+from .core.storage import write_text_atomic
+from .keyring_store import get_secret, set_secret
+
+
+def _profiles_path(settings: Settings) -> Path:
+    return settings.data_dir / "amplifier-profiles.json"
+
+
+def keyring_key(profile: AmplifierProfile) -> str:
+    return f"amplifier:{profile.name}"
+
+
+def read_profiles(settings: Settings) -> AmplifierProfiles:
+    path = _profiles_path(settings)
+    if not path.exists():
+        return AmplifierProfiles()
+    profiles = AmplifierProfiles.model_validate_json(path.read_text())
+    for profile in profiles.profiles:
+        if profile.api_key_ref is not None:
+            profile.api_key = get_secret(profile.api_key_ref)
+    return profiles
+
+
+def write_profiles(settings: Settings, profiles: AmplifierProfiles) -> None:
+    persisted = profiles.model_copy(deep=True)
+    for profile in persisted.profiles:
+        if profile.api_key is None or not settings.use_keyring:
+            continue
+        key = keyring_key(profile)
+        if set_secret(key, profile.api_key):
+            profile.api_key = None
+            profile.api_key_ref = key
+    write_text_atomic(
+        _profiles_path(settings),
+        json.dumps(persisted.model_dump(mode="json"), indent=2) + "\n",
+    )
+
+
+def resolve_profile(
+    settings: Settings, config: ProjectConfig
+) -> AmplifierProfile | None:
+    """The project's profile, else the default one.
+
+    A project whose profile was deleted falls back to the default. None means
+    the built-in Anthropic setup.
+    """
+    profiles = read_profiles(settings)
+    return profiles.find(config.amplifier_profile) or profiles.find(profiles.default)
@@ -0,0 +1,215 @@
+"""Read and patch a project's ``.amplifier/settings.local.yaml``.
+
+Patches are validated before anything is written, so an edit from the app
+cannot leave Amplifier unable to start. A patched file is the user's own and
+is no longer regenerated from the project's profile.
+"""
+
+from __future__ import annotations
+
+from pathlib import Path
+from typing import Any
+from urllib.parse import urlparse
+
+import yaml
+from pydantic import BaseModel
+
+from .amplifier import (
+    DEFAULT_PROFILE,
+    LEGACY_SETTINGS_YAML,
+    SETTINGS_MARKER,
+    settings_file,
+    settings_yaml,
+)
+from .core.errors import AppError
+from .core.models import AmplifierProfile
+from .core.storage import write_text_atomic
+
+# Provider config keys holding the endpoint, depending on the module.
+_URL_KEYS = ("base_url", "azure_endpoint", "host")
+
+
+class AmplifierSettingsError(AppError):
+    """The settings file, or a patch to it, is not usable Amplifier settings."""
+
+    status_code = 422
+    code = "INVALID_AMPLIFIER_SETTINGS"
+
+
+class AmplifierProviderSettings(BaseModel):
+    module: str
+    source: str | None = None
+    default_model: str | None = None
+    base_url: str | None = None
+    # Which config key ``base_url`` came from, e.g. ``host`` for Ollama.
+    base_url_key: str = "base_url"
+    enable_prompt_caching: bool | None = None
+    priority: int | None = None
+
+
+class AmplifierSettingsFile(BaseModel):
+    path: str
+    # False until the first session writes it; ``content`` is what it will get.
+    exists: bool
+    # Still generated from a profile, so rewritten before each session.
+    managed: bool
+    content: str
+    providers: list[AmplifierProviderSettings] = []
+    # Why a hand-edited file could not be read; patching is refused until fixed.
+    error: str | None = None
+
+
+def _parse(text: str) -> Any:
+    try:
+        return yaml.safe_load(text)
+    except yaml.YAMLError as exc:
+        raise AmplifierSettingsError(f"Not valid YAML: {exc}") from exc
+
+
+def _flag(value: object, where: str) -> bool:
+    if isinstance(value, bool):
+        return value
+    if isinstance(value, str) and value.lower() in ("true", "false"):
+        return value.lower() == "true"
+    raise AmplifierSettingsError(f"{where} must be true or false")
+
+
+def _validate(doc: Any) -> list[AmplifierProviderSettings]:
+    """The providers *doc* configures, or why Amplifier could not use it."""
+    config = doc.get("config") if isinstance(doc, dict) else None
+    providers = config.get("providers") if isinstance(config, dict) else None
+    if not isinstance(providers, list) or not providers:
+        raise AmplifierSettingsError("config.providers must list at least one provider")
+
+    result = []
+    for index, provider in enumerate(providers):
+        where = f"config.providers[{index}]"
+        if not isinstance(provider, dict):
+            raise AmplifierSettingsError(f"{where} must be a mapping")
+        module = provider.get("module")
+        if not isinstance(module, str) or not module:
+            raise AmplifierSettingsError(f"{where}.module must be a module name")
+        options = provider.get("config") or {}
+        if not isinstance(options, dict):
+            raise AmplifierSettingsError(f"{where}.config must be a mapping")
+
+        model = options.get("default_model")
+        if model is not None and (not isinstance(model, str) or not model.strip()):
+            raise AmplifierSettingsError(f"{where}.config.default_model must be a name")
+        url_key = next((k for k in _URL_KEYS if options.get(k) is not None), "base_url")
+        url = options.get(url_key)
+        # ``${VAR}`` references are resolved by Amplifier, so they cannot be checked.
+        if url is not None and not str(url).startswith("${"):
+            parsed = urlparse(str(url))
+            if parsed.scheme not in ("http", "https") or not parsed.netloc:
+                raise AmplifierSettingsError(f"{where} endpoint '{url}' is not a URL")
+        caching = options.get("enable_prompt_caching")
+        priority = options.get("priority")
+        if priority is not None and (
+            isinstance(priority, bool) or not isinstance(priority, int)
+        ):
+            raise AmplifierSettingsError(f"{where}.config.priority must be a number")
+
+        result.append(
+            AmplifierProviderSettings(
+                module=module,
+                source=provider.get("source"),
+                default_model=model,
+                base_url=None if url is None else str(url),
+                base_url_key=url_key,
+                enable_prompt_caching=(
+                    None
+                    if caching is None
+                    else _flag(caching, f"{where}.config.enable_prompt_caching")
+                ),
+                priority=priority,
+            )
+        )
+    return result
+
+
+def _merge(target: Any, patch: Any) -> Any:
+    """Deep-merge *patch* into *target*; null removes a key.
+
+    Providers in a patch update the provider with the same ``module``, or are
+    added, instead of replacing the whole list.
+    """
+    if not isinstance(patch, dict):
+        return patch
+    merged = dict(target) if isinstance(target, dict) else {}
+    for key, value in patch.items():
+        if value is None:
+            merged.pop(key, None)
+        elif key == "providers" and isinstance(value, list):
+            providers = list(merged.get(key) or [])
+            for item in value:
+                module = item.get("module") if isinstance(item, dict) else None
+                index = next(
+                    (
+                        i
+                        for i, p in enumerate(providers)
+                        if isinstance(p, dict) and p.get("module") == module
+                    ),
+                    None,
+                )
+                if index is None:
+                    providers.append(_merge({}, item))
+                else:
+                    providers[index] = _merge(providers[index], item)
+            merged[key] = providers
+        else:
+            merged[key] = _merge(merged.get(key), value)
+    return merged
+
+
+def get_amplifier_settings(
+    project_dir: Path, profile: AmplifierProfile | None = None
+) -> AmplifierSettingsFile:
+    """The project's Amplifier settings, or what *profile* would generate."""
+    path = settings_file(project_dir)
+    exists = path.exists()
+    content = path.read_text() if exists else settings_yaml(profile or DEFAULT_PROFILE)
+    settings = AmplifierSettingsFile(
+        path=str(path),
+        exists=exists,
+        managed=(
+            not exists
+            or content == LEGACY_SETTINGS_YAML
+            or content.startswith(SETTINGS_MARKER)
+        ),
+        content=content,
+    )
+    try:
+        settings.providers = _validate(_parse(content))
+    except AmplifierSettingsError as exc:
+        settings.error = exc.message
+    return settings
+
+
+def update_amplifier_settings(
+    project_dir: Path, yaml_patch: str, profile: AmplifierProfile | None = None
+) -> AmplifierSettingsFile:
+    """Merge the YAML mapping *yaml_patch* into the settings and save them.
+
+    Raises:
+        AmplifierSettingsError: If the patch or the result is not valid, or
+            the current file cannot be read; nothing is written then.
+    """
+    current = get_amplifier_settings(project_dir, profile)
+    if current.error is not None:
+        raise AmplifierSettingsError(
+            f"{current.path} needs fixing by hand first: {current.error}"
+        )
+    patch = _parse(yaml_patch)
+    if not isinstance(patch, dict):
+        raise AmplifierSettingsError("The patch must be a YAML mapping")
+    doc = _merge(_parse(current.content), patch)
+    _validate(doc)
+
+    write_text_atomic(settings_file(project_dir), yaml.safe_dump(doc, sort_keys=False))
+    return get_amplifier_settings(project_dir, profile)
+
+
+def reset_amplifier_settings(project_dir: Path) -> None:
+    """Delete the settings so the next session writes them from the profile."""
+    settings_file(project_dir).unlink(missing_ok=True)
@@ -0,0 +1,103 @@
+"""Download GitHub avatars once and serve them from a local cache.
+
+Each avatar is stored as ``{login}`` (image bytes) plus ``{login}.json``
+(content type, ETag, expiry) under ``data_dir/avatars/``. Fresh entries are
+served without a request; stale ones are revalidated with ``If-None-Match``
+and kept as-is when GitHub is unreachable, so avatars keep working offline.
+"""
+
+from __future__ import annotations
+
+import json
+import logging
+import re
+import time
+from dataclasses import asdict, dataclass
+from pathlib import Path
+
+import httpx
+
+from .core.network import http_timeout
+
+logger = logging.getLogger(__name__)
+
+AVATAR_URL = "https://github.com/{login}.png?size=96"
+DEFAULT_MAX_AGE = 24 * 60 * 60
+
+# GitHub logins are alphanumeric with single hyphens; bots end in "[bot]".
+_LOGIN_RE = re.compile(r"^[A-Za-z0-9](?:[A-Za-z0-9-]{0,38})(?:\[bot\])?$")
+_MAX_AGE_RE = re.compile(r"max-age=(\d+)")
+
+
+@dataclass
+class CachedAvatar:
+    path: Path
+    content_type: str
+
+
+@dataclass
+class _AvatarMeta:
+    content_type: str
+    etag: str | None
+    expires_at: float
+
+
+def is_valid_login(login: str) -> bool:
+    return _LOGIN_RE.match(login) is not None
+
+
+def _max_age(resp: httpx.Response) -> int:
+    match = _MAX_AGE_RE.search(resp.headers.get("Cache-Control", ""))
+    return int(match.group(1)) if match else DEFAULT_MAX_AGE
+
+
+def _read_meta(path: Path) -> _AvatarMeta | None:
+    if not path.exists():
+        return None
+    try:
+        return _AvatarMeta(**json.loads(path.read_text()))
+    except (json.JSONDecodeError, TypeError):
+        return None
+
+
+async def get_user_avatar(cache_dir: Path, login: str) -> CachedAvatar | None:
+    """Return the cached avatar for *login*, fetching or revalidating if stale.
+
+    Returns None only when nothing is cached and GitHub can't provide one.
+    """
+    image_path = cache_dir / login
+    meta_path = cache_dir / f"{login}.json"
+    meta = _read_meta(meta_path)
+    if meta is not None and not image_path.exists():
+        meta = None
+
+    if meta is not None and meta.expires_at > time.time():
+        return CachedAvatar(image_path, meta.content_type)
+
+    headers = {}
+    if meta is not None and meta.etag is not None:
+        headers["If-None-Match"] = meta.etag
+    try:
+        async with httpx.AsyncClient(
+            timeout=http_timeout(), follow_redirects=True
+        ) as client:
+            resp = await client.get(AVATAR_URL.format(login=login), headers=headers)
+    except httpx.HTTPError as exc:
+        logger.info("Avatar fetch for %s failed: %s", login, exc)
+        return CachedAvatar(image_path, meta.content_type) if meta else None
+
+    if resp.status_code == 304 and meta is not None:
+        meta.expires_at = time.time() + _max_age(resp)
+    elif resp.status_code == 200:
+        cache_dir.mkdir(parents=True, exist_ok=True)
+        image_path.write_bytes(resp.content)
+        meta = _AvatarMeta(
+            content_type=resp.headers.get("Content-Type", "image/png"),
+            etag=resp.headers.get("ETag"),
+            expires_at=time.time() + _max_age(resp),
+        )
+    else:
+        return CachedAvatar(image_path, meta.content_type) if meta else None
+
+    meta_path.write_text(json.dumps(asdict(meta)))
+    return CachedAvatar(image_path, meta.content_type)
@@ -0,0 +1,124 @@
+"""Single-file project backups: the store's full git history plus project.json.
+
+An archive is a gzipped tarball holding ``project.json`` and ``store.bundle``
+(a ``git bundle`` of every ref), so a store can be rebuilt even after its
+backing repo is gone.
+"""
+
+from __future__ import annotations
+
+import json
+import logging
+import subprocess
+import tarfile
+import tempfile
+from pathlib import Path
+
+from .core.errors import AppError
+from .core.logs import span
+from .core.models import ProjectConfig
+
+logger = logging.getLogger(__name__)
+
+CONFIG_MEMBER = "project.json"
+BUNDLE_MEMBER = "store.bundle"
+
+
+class BackupError(AppError):
+    """The archive could not be written, or is not a project backup."""
+
+    status_code = 400
+    code = "BACKUP_ERROR"
+
+
+def _git(*args: str, cwd: Path | None = None) -> subprocess.CompletedProcess[str]:
+    with span(logger, f"git {args[0]}", repo=cwd):
+        return subprocess.run(
+            ["git", *args], cwd=cwd, capture_output=True, text=True, check=True
+        )
+
+
+def backup_project(config: ProjectConfig, destination: Path) -> Path:
+    """Write a backup of *config* and its store to *destination*; return the path.
+
+    Raises:
+        BackupError: The store is missing or git could not bundle it.
+    """
+    store_path = Path(config.store.path)
+    if not (store_path / ".git").exists():
+        raise BackupError(f"Store not found at {store_path}", path=str(store_path))
+    destination.parent.mkdir(parents=True, exist_ok=True)
+    with tempfile.TemporaryDirectory() as tmp:
+        bundle = Path(tmp) / BUNDLE_MEMBER
+        try:
+            _git("bundle", "create", str(bundle), "--all", cwd=store_path)
+        except subprocess.CalledProcessError as exc:
+            raise BackupError(f"Could not bundle the store: {exc.stderr.strip()}") from exc
+        config_file = Path(tmp) / CONFIG_MEMBER
+        config_file.write_text(
+            json.dumps(config.model_dump(mode="json"), indent=2) + "\n",
+            encoding="utf-8",
+            newline="\n",
+        )
+        with tarfile.open(destination, "w:gz") as archive:
+            archive.add(config_file, arcname=CONFIG_MEMBER)
+            archive.add(bundle, arcname=BUNDLE_MEMBER)
+    return destination
+
+
+def _open_backup(archive: Path) -> tarfile.TarFile:
+    try:
+        tar = tarfile.open(archive, "r:gz")
+    except (OSError, tarfile.TarError) as exc:
+        raise BackupError(f"Could not read {archive}: {exc}") from exc
+    if not {CONFIG_MEMBER, BUNDLE_MEMBER} <= set(tar.getnames()):
+        tar.close()
+        raise BackupError(f"{archive} is not a project backup")
+    return tar
+
+
+def read_backup_config(archive: Path) -> ProjectConfig:
+    """The project config saved in *archive*, without restoring anything."""
+    with _open_backup(archive) as tar:
+        member = tar.extractfile(CONFIG_MEMBER)
+        if member is None:
+            raise BackupError(f"{archive} is not a project backup")
+        return ProjectConfig(**json.loads(member.read().decode("utf-8")))
+
+
+def restore_project(archive: Path, destination: Path) -> ProjectConfig:
+    """Clone the store in *archive* into *destination*; return the saved config.
+
+    The restored store has no remote: the old one may be the repo that was
+    lost, so the caller decides where it is pushed next.
+
+    Raises:
+        BackupError: *archive* is not a project backup, or *destination*
+            already exists.
+    """
+    if destination.exists():
+        raise BackupError(f"{destination} already exists", path=str(destination))
+    with tempfile.TemporaryDirectory() as tmp:
+        with _open_backup(archive) as tar:
+            members = [tar.getmember(CONFIG_MEMBER), tar.getmember(BUNDLE_MEMBER)]
+            tar.extractall(tmp, members=members, filter="data")
+        config = ProjectConfig(
+            **json.loads((Path(tmp) / CONFIG_MEMBER).read_text(encoding="utf-8"))
+        )
+        bundle = Path(tmp) / BUNDLE_MEMBER
+        try:
+            _git("clone", str(bundle), str(destination))
+            # clone only creates the checked-out branch; keep the others too
+            _git(
+                "fetch",
+                "--update-head-ok",
+                str(bundle),
+                "+refs/heads/*:refs/heads/*",
+                cwd=destination,
+            )
+            _git("remote", "remove", "origin", cwd=destination)
+        except subprocess.CalledProcessError as exc:
+            raise BackupError(
+                f"Could not restore the store: {exc.stderr.strip()}"
+            ) from exc
+    return config
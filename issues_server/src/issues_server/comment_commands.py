@@ -0,0 +1,294 @@
+"""Slash commands in issue comments, such as ``/close`` or ``/label bug``.
+
+Commands in a comment created here run with it, in the same commit. Comments
+that arrive another way, such as a store pull, are found by walking the store
+history since the last check. Those only run when this machine's project
+config turns on ``synced_comment_commands``, only for comments by the
+project's own account, and never start Amplifier, so a push to the store
+cannot run an agent here.
+
+Either way the bot replies with what was done, and the reply carries the
+comment's ``node_id``, so a comment is never acted on twice, even after a
+sync renumbers it.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import logging
+import re
+import shlex
+from dataclasses import dataclass
+from datetime import datetime, timezone
+
+from fastapi import HTTPException
+
+from .core.config import Settings
+from .core.errors import AppError
+from .core.models import ATTRACTOR_BOT, Comment, Issue, ProjectConfig
+from .core.projects import load_project_config
+from .core.storage import ProjectStorage, write_text_atomic
+from .deps import get_amplifier_manager, get_notifications
+from .routes.amplifier import start_session
+from .routes.github_auth import get_github_account
+from .routes.projects import get_project_storage, list_projects
+from .ws import WebSocketManager
+
+logger = logging.getLogger(__name__)
+
+_COMMAND = re.compile(
+    r"^/(amplify|close|reopen|label|unlabel)(?![\w/-])[ \t]*(.*)$", re.M
+)
+# Commands in a code block are examples, not requests.
+_CODE_BLOCK = re.compile(r"^(```|~~~).*?(?:^\1|\Z)", re.M | re.S)
+_COMMENT_PATH = re.compile(r"^comments/(\d+)/(\d+)\.json$")
+_REPLY_MARKER = "<!-- attractor-commands: {} -->"
+
+# Store history walks share one lock so a comment is not picked up twice.
+_lock = asyncio.Lock()
+
+
+@dataclass
+class CommentCommand:
+    name: str
+    args: list[str]
+
+    def __str__(self) -> str:
+        return " ".join([f"/{self.name}", *(shlex.quote(a) for a in self.args)])
+
+
+def parse_commands(body: str | None) -> list[CommentCommand]:
+    """Commands at the start of a line of *body*, in order."""
+    commands = []
+    for name, rest in _COMMAND.findall(_CODE_BLOCK.sub("", body or "")):
+        try:
+            words = shlex.split(rest)
+        except ValueError:
+            words = rest.split()
+        args = [a.strip() for word in words for a in word.split(",") if a.strip()]
+        commands.append(CommentCommand(name, args))
+    return commands
+
+
+def _replied(storage: ProjectStorage, issue_number: int, comment: Comment) -> bool:
+    marker = _REPLY_MARKER.format(comment.node_id)
+    page = 1
+    while True:
+        comments = storage.list_comments(issue_number, page, 100)
+        if any(c.user.user_type == "Bot" and marker in c.body for c in comments.items):
+            return True
+        if page * 100 >= comments.total_count:
+            return False
+        page += 1
+
+
+async def run_commands(
+    config: ProjectConfig,
+    storage: ProjectStorage,
+    issue: Issue,
+    comment: Comment,
+    settings: Settings,
+    ws: WebSocketManager,
+    synced: bool = False,
+) -> Comment | None:
+    """Carry out *comment*'s commands on *issue* and write the bot's reply.
+
+    *comment* must already be written, so it has a ``node_id``. The caller
+    commits. Returns the reply, or None when there was nothing to run.
+    ``/amplify`` is refused for *synced* comments.
+    """
+    commands = parse_commands(comment.body)
+    if not commands or comment.user.user_type == "Bot":
+        return None
+    if not storage.read_settings().comment_commands:
+        return None
+
+    now = datetime.now(timezone.utc)
+    labels = {label.name.lower(): label for label in storage.read_labels()}
+    lines = []
+    for command in commands:
+        if command.name == "close":
+            if issue.state == "closed":
+                outcome = "already closed"
+            else:
+                issue.state = "closed"
+                issue.closed_at = now
+                issue.closed_by = comment.user
+                issue.state_reason = "completed"
+                outcome = "closed the issue"
+        elif command.name == "reopen":
+            if issue.state == "open":
+                outcome = "already open"
+            else:
+                issue.state = "open"
+                issue.closed_at = None
+                issue.closed_by = None
+                issue.state_reason = "reopened"
+                outcome = "reopened the issue"
+        elif command.name in ("label", "unlabel"):
+            if not command.args:
+                outcome = "no labels given"
+            else:
+                done, missing = [], []
+                for name in command.args:
+                    label = labels.get(name.lower())
+                    if label is None:
+                        missing.append(name)
+                        continue
+                    on_issue = any(x.name == label.name for x in issue.labels)
+                    if command.name == "label" and not on_issue:
+                        issue.labels.append(label)
+                        done.append(label.name)
+                    elif command.name == "unlabel" and on_issue:
+                        issue.labels = [x for x in issue.labels if x.name != label.name]
+                        done.append(label.name)
+                verb = "added" if command.name == "label" else "removed"
+                parts = [f"{verb} {', '.join(done)}"] if done else ["nothing to change"]
+                if missing:
+                    parts.append(f"no label named {', '.join(missing)}")
+                outcome = "; ".join(parts)
+        elif synced:
+            outcome = "not run, since Amplifier only starts from comments made here"
+        else:
+            try:
+                await start_session(
+                    config,
+                    issue,
+                    storage,
+                    settings,
+                    ws,
+                    get_notifications(),
+                    get_amplifier_manager(),
+                )
+                outcome = "started an Amplifier session"
+            except HTTPException as exc:
+                outcome = f"could not start Amplifier: {exc.detail}"
+        lines.append(f"- `{command}`: {outcome}")
+
+    reply = Comment(
+        id=storage.next_comment_id(),
+        body="\n".join(
+            [
+                f"Commands from comment #{comment.id}:",
+                *lines,
+                "",
+                _REPLY_MARKER.format(comment.node_id),
+            ]
+        ),
+        user=ATTRACTOR_BOT,
+        author_association="BOT",
+        created_at=now,
+        updated_at=now,
+    )
+    storage.write_comment(issue.number, reply)
+    issue.comments += 1
+    issue.updated_at = now
+    storage.write_issue(issue)
+    return reply
+
+
+async def broadcast_reply(
+    ws: WebSocketManager, project: str, issue: Issue, reply: Comment
+) -> None:
+    await ws.broadcast(
+        "comment:created",
+        {
+            "project": project,
+            "issueNumber": issue.number,
+            "comment": reply.model_dump(mode="json"),
+        },
+    )
+    await ws.broadcast(
+        "issue:updated", {"project": project, "issue": issue.model_dump(mode="json")}
+    )
+
+
+def _local_login(config: ProjectConfig, settings: Settings) -> str | None:
+    """The login comments made here are authored as, like ``current_user``.
+
+    None for local-only stores, whose comments are all ``local-user``.
+    """
+    if config.store.github is None:
+        return None
+    account = get_github_account(settings, config.github_account)
+    return account.user if account is not None else None
+
+
+async def _run_project(
+    config: ProjectConfig,
+    storage: ProjectStorage,
+    settings: Settings,
+    ws: WebSocketManager,
+) -> None:
+    cursor_path = settings.data_dir / "projects" / config.name / "commands-cursor"
+    head = storage.head()
+    if head is None:
+        return
+    cursor = cursor_path.read_text().strip() if cursor_path.exists() else None
+    if cursor == head:
+        return
+    # The first check only sets the cursor, so old comments are not replayed.
+    commits = storage.commits_since(cursor) if cursor else None
+    login = _local_login(config, settings)
+    if commits is None or login is None or not config.synced_comment_commands:
+        write_text_atomic(cursor_path, head + "\n")
+        return
+
+    seen: set[str] = set()
+    for commit in commits:
+        for path in commit.files:
+            match = _COMMENT_PATH.match(path)
+            if match is None or path in seen:
+                continue
+            seen.add(path)
+            if storage.read_json_at(f"{commit.sha}^", path) is not None:
+                continue
+            number, comment_id = int(match.group(1)), int(match.group(2))
+            comment = storage.read_comment(number, comment_id)
+            issue = storage.read_issue(number)
+            if comment is None or issue is None or not parse_commands(comment.body):
+                continue
+            if comment.user.login.lower() != login.lower():
+                continue
+            if comment.node_id is None or _replied(storage, number, comment):
+                continue
+            reply = await run_commands(
+                config, storage, issue, comment, settings, ws, synced=True
+            )
+            if reply is None:
+                continue
+            await asyncio.to_thread(
+                storage.commit,
+                f"Run commands from comment #{comment.id} on issue #{number}",
+                event="comment.command",
+                issue=number,
+            )
+            await asyncio.to_thread(storage.push)
+            await broadcast_reply(ws, config.name, issue, reply)
+        # Advanced per commit, so a failure is retried from the first one it hit.
+        write_text_atomic(cursor_path, commit.sha + "\n")
+
+
+async def run_project_commands(
+    config: ProjectConfig,
+    storage: ProjectStorage,
+    settings: Settings,
+    ws: WebSocketManager,
+) -> None:
+    """Run commands in comments added to the project's store since the last check."""
+    async with _lock:
+        try:
+            await _run_project(config, storage, settings, ws)
+        except Exception:
+            logger.exception("Comment commands failed for %s", config.name)
+
+
+async def run_new_commands(settings: Settings, ws: WebSocketManager) -> None:
+    """:func:`run_project_commands` for every project."""
+    for project in list_projects(settings):
+        try:
+            config = load_project_config(project.name, settings)
+            storage = get_project_storage(project.name, settings)
+        except AppError:
+            continue
+        await run_project_commands(config, storage, settings, ws)
@@ -0,0 +1,6 @@
+"""Store and project data with no web framework dependency.
+
+Models, git-backed storage and the git plumbing it needs, and project
+configuration. The FastAPI routes, the MCP server, and scripts all build on
+this package; nothing in it imports from the rest of ``issues_server``.
+"""
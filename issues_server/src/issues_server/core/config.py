@@ -21,5 +21,18 @@ class Settings(BaseSettings):
     frontend_release_tag: str = "frontend-latest"
     frontend_asset_name: str = "frontend-dist.tar.gz"
     github_token: str | None = None
+    github_client_id: str | None = None
+    use_keyring: bool = True
+    http_connect_timeout: float = 10.0
+    http_read_timeout: float = 30.0
+    git_timeout: float = 300.0
+    sync_freshness: float = 30.0
+    mirror_interval: float = 300.0
+    pull_request_interval: float = 600.0
+    notification_interval: float = 60.0
+    update_check_interval: float = 21600.0
+    log_level: str = "INFO"
+    github_compat_api: bool = False
+    github_compat_token: str | None = None
 
     model_config = SettingsConfigDict(env_prefix="ATTRACTOR_")
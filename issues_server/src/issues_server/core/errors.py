@@ -0,0 +1,82 @@
+"""Typed API errors with stable, machine-readable codes.
+
+Every error response has the shape ``{"detail": {"error": CODE, "message":
+..., **payload}}`` so clients can branch on ``error`` instead of parsing
+messages. Plain ``HTTPException`` string details are wrapped the same way,
+using the HTTP status name (e.g. ``NOT_FOUND``) as the code.
+"""
+
+from __future__ import annotations
+
+from http import HTTPStatus
+
+
+class AppError(Exception):
+    """Base for errors the API reports with a specific code.
+
+    Subclasses set ``status_code`` and ``code``; extra keyword arguments are
+    included in the response payload.
+    """
+
+    status_code = 500
+    code = "INTERNAL_ERROR"
+
+    def __init__(
+        self,
+        message: str,
+        *,
+        code: str | None = None,
+        status_code: int | None = None,
+        **payload: object,
+    ) -> None:
+        super().__init__(message)
+        self.message = message
+        if code is not None:
+            self.code = code
+        if status_code is not None:
+            self.status_code = status_code
+        self.payload = payload
+
+    def detail(self) -> dict[str, object]:
+        return {"error": self.code, "message": self.message, **self.payload}
+
+
+def http_error_detail(status_code: int, detail: object) -> dict[str, object]:
+    """Normalize an ``HTTPException`` detail into the typed error shape."""
+    if isinstance(detail, dict) and "error" in detail:
+        return detail
+    try:
+        code = HTTPStatus(status_code).name
+    except ValueError:
+        code = "HTTP_ERROR"
+    if isinstance(detail, dict):
+        return {"error": code, **detail}
+    return {"error": code, "message": str(detail)}
+
+
+class StaleWriteError(AppError):
+    """The issue changed, here or on another machine, since the client loaded it."""
+
+    status_code = 409
+    code = "STALE_WRITE"
+
+    def __init__(self, number: int, expected: int, current: int) -> None:
+        super().__init__(
+            f"Issue #{number} was changed since it was loaded",
+            expected_version=expected,
+            current_version=current,
+        )
+
+
+class RepoCreateForbiddenError(AppError):
+    """The token may not create repositories; the user has to create one by hand."""
+
+    status_code = 403
+    code = "REPO_CREATE_FORBIDDEN"
+
+    def __init__(self, instructions: str, create_url: str | None = None) -> None:
+        super().__init__(
+            "Your token doesn't have permission to create repositories.",
+            instructions=instructions,
+            create_url=create_url,
+        )
@@ -0,0 +1,125 @@
+"""Rotating JSON-lines log under ``data_dir/logs`` that can be read back.
+
+Users attach recent entries to bug reports through ``GET /api/logs`` rather
+than locating the file. Credentials embedded in remote URLs are redacted
+before anything is written.
+"""
+
+from __future__ import annotations
+
+import json
+import logging
+import re
+import time
+from collections.abc import Iterator
+from contextlib import contextmanager
+from datetime import datetime, timezone
+from logging.handlers import RotatingFileHandler
+from pathlib import Path
+
+from .config import Settings
+from .models import LogEntry
+
+LOG_FILE = "attractor.log"
+MAX_BYTES = 5 * 1024 * 1024
+BACKUP_COUNT = 3
+
+_URL_CREDENTIALS = re.compile(r"://[^/@\s]+@")
+
+_handler: RotatingFileHandler | None = None
+
+
+def redact(text: str) -> str:
+    return _URL_CREDENTIALS.sub("://***@", text)
+
+
+class _JsonFormatter(logging.Formatter):
+    def format(self, record: logging.LogRecord) -> str:
+        entry: dict[str, object] = {
+            "time": datetime.fromtimestamp(record.created, timezone.utc).isoformat(),
+            "level": record.levelname,
+            "logger": record.name,
+            "message": redact(record.getMessage()),
+        }
+        fields = getattr(record, "fields", None)
+        if fields:
+            entry["fields"] = {k: redact(str(v)) for k, v in fields.items()}
+        if record.exc_info:
+            entry["exception"] = redact(self.formatException(record.exc_info))
+        return json.dumps(entry)
+
+
+def log_dir(data_dir: Path) -> Path:
+    return data_dir / "logs"
+
+
+def amplifier_log_dir(data_dir: Path) -> Path:
+    """Where each Amplifier session's stderr is kept, one file per session."""
+    return log_dir(data_dir) / "amplifier"
+
+
+def configure_logging(settings: Settings) -> None:
+    """Send the app's logs to the rotating file at ``settings.log_level``."""
+    global _handler
+    logger = logging.getLogger("issues_server")
+    logger.setLevel(settings.log_level.upper())
+    if _handler is not None:
+        logger.removeHandler(_handler)
+        _handler.close()
+    directory = log_dir(settings.data_dir)
+    directory.mkdir(parents=True, exist_ok=True)
+    _handler = RotatingFileHandler(
+        directory / LOG_FILE,
+        maxBytes=MAX_BYTES,
+        backupCount=BACKUP_COUNT,
+        encoding="utf-8",
+    )
+    _handler.setFormatter(_JsonFormatter())
+    logger.addHandler(_handler)
+
+
+@contextmanager
+def span(logger: logging.Logger, name: str, **fields: object) -> Iterator[None]:
+    """Log *name* with its duration at DEBUG, or at WARNING if it raises."""
+    start = time.monotonic()
+    try:
+        yield
+    except Exception as exc:
+        fields["duration_ms"] = round((time.monotonic() - start) * 1000)
+        fields["error"] = type(exc).__name__
+        stderr = getattr(exc, "stderr", None)
+        if stderr:
+            fields["stderr"] = str(stderr).strip()
+        logger.warning("%s failed", name, extra={"fields": fields})
+        raise
+    if logger.isEnabledFor(logging.DEBUG):
+        fields["duration_ms"] = round((time.monotonic() - start) * 1000)
+        logger.debug(name, extra={"fields": fields})
+
+
+def read_recent_logs(
+    data_dir: Path, level: str = "INFO", limit: int = 200
+) -> list[LogEntry]:
+    """Entries at *level* or above, newest first, across rotated files."""
+    threshold = logging.getLevelName(level.upper())
+    if not isinstance(threshold, int):
+        raise ValueError(f"Unknown log level '{level}'")
+    directory = log_dir(data_dir)
+    paths = [directory / LOG_FILE] + [
+        directory / f"{LOG_FILE}.{i}" for i in range(1, BACKUP_COUNT + 1)
+    ]
+    entries: list[LogEntry] = []
+    for path in paths:
+        if not path.exists():
+            continue
+        for line in reversed(path.read_text(encoding="utf-8").splitlines()):
+            try:
+                entry = LogEntry.model_validate_json(line)
+            except ValueError:
+                continue
+            if logging.getLevelName(entry.level) < threshold:
+                continue
+            entries.append(entry)
+            if len(entries) >= limit:
+                return entries
+    return entries
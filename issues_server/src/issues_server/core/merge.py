@@ -0,0 +1,73 @@
+"""Field-level three-way merge of an issue edited on two machines."""
+
+from __future__ import annotations
+
+from .models import Issue, MergeField, MergePolicy
+
+# Attributes that travel together when a side wins a field.
+_FIELDS: dict[MergeField, tuple[str, ...]] = {
+    "title": ("title",),
+    "body": ("body",),
+    "state": ("state", "state_reason", "closed_at", "closed_by"),
+    "labels": ("labels",),
+    "assignees": ("assignees",),
+    "milestone": ("milestone",),
+}
+
+_UNION_KEYS = {"labels": "name", "assignees": "login"}
+
+
+def _values(issue: Issue, attrs: tuple[str, ...]) -> dict[str, object]:
+    return {attr: getattr(issue, attr) for attr in attrs}
+
+
+def _resolve(
+    field: MergeField, policy: MergePolicy, ours: Issue, theirs: Issue
+) -> dict[str, object]:
+    attrs = _FIELDS[field]
+    latest = ours if ours.updated_at >= theirs.updated_at else theirs
+    if policy == "prefer_local":
+        return _values(ours, attrs)
+    if policy == "prefer_remote":
+        return _values(theirs, attrs)
+    if policy == "union":
+        key = _UNION_KEYS[field]
+        items = list(getattr(ours, field))
+        seen = {getattr(item, key) for item in items}
+        items += [i for i in getattr(theirs, field) if getattr(i, key) not in seen]
+        return {field: items}
+    if policy in ("prefer_closed", "prefer_open"):
+        wanted = "closed" if policy == "prefer_closed" else "open"
+        for side in (latest, ours, theirs):
+            if side.state == wanted:
+                return _values(side, attrs)
+    return _values(latest, attrs)
+
+
+def merge_issue(
+    base: Issue | None,
+    ours: Issue,
+    theirs: Issue,
+    policies: dict[MergeField, MergePolicy],
+) -> Issue:
+    """Combine two edits of an issue made since *base*.
+
+    A field changed on one side only keeps that change. A field changed on
+    both is settled by its policy, ``prefer_latest`` when it has none. Fields
+    that are not merged individually come from the most recently updated side.
+    """
+    latest = ours if ours.updated_at >= theirs.updated_at else theirs
+    merged = latest.model_copy(deep=True)
+    for field, attrs in _FIELDS.items():
+        mine, other = _values(ours, attrs), _values(theirs, attrs)
+        original = _values(base, attrs) if base is not None else None
+        if mine == other or other == original:
+            chosen = mine
+        elif mine == original:
+            chosen = other
+        else:
+            chosen = _resolve(field, policies.get(field, "prefer_latest"), ours, theirs)
+        for attr, value in chosen.items():
+            setattr(merged, attr, value)
+    merged.version = max(ours.version, theirs.version)
+    return merged
@@ -0,0 +1,968 @@
+"""Pydantic models mirroring the GitHub Issues API shape."""
+
+from __future__ import annotations
+
+import shlex
+from datetime import datetime
+from typing import Generic, Literal, TypeVar
+
+from pydantic import BaseModel, ConfigDict, Field, computed_field, field_validator
+from pydantic.alias_generators import to_camel
+
+
+def validate_command_template(template: str, require_path: bool = True) -> str:
+    """Check that *template* parses and mentions ``{path}``; return it stripped."""
+    try:
+        args = shlex.split(template)
+    except ValueError as exc:
+        raise ValueError(f"cannot parse command: {exc}") from exc
+    if not args:
+        raise ValueError("command is empty")
+    if require_path and not any("{path}" in arg for arg in args):
+        raise ValueError("command must include {path}")
+    return template.strip()
+
+
+# ---------------------------------------------------------------------------
+# Core domain models
+# ---------------------------------------------------------------------------
+
+
+class SimpleUser(BaseModel):
+    """Minimal GitHub-compatible user representation."""
+
+    model_config = ConfigDict(populate_by_name=True)
+
+    login: str
+    id: int
+    avatar_url: str
+    user_type: str = Field(alias="type")
+
+
+LOCAL_USER = SimpleUser(login="local-user", id=1, avatar_url="", user_type="User")
+
+ATTRACTOR_BOT = SimpleUser(
+    login="attractor-bot",
+    id=0,
+    avatar_url="",
+    user_type="Bot",
+)
+
+
+class Label(BaseModel):
+    """Issue label with colour coding."""
+
+    model_config = ConfigDict(populate_by_name=True)
+
+    id: int
+    name: str
+    color: str
+    description: str | None = None
+    is_default: bool = Field(default=False, alias="default")
+
+
+class Milestone(BaseModel):
+    """Named target grouping issues, optionally with a due date."""
+
+    id: int
+    number: int
+    title: str
+    description: str | None = None
+    state: str = "open"
+    due_on: datetime | None = None
+    created_at: datetime
+    updated_at: datetime
+    closed_at: datetime | None = None
+
+
+class MilestoneListItem(Milestone):
+    """A milestone as listed, with date checks done server-side; never stored."""
+
+    is_overdue: bool = False
+
+
+class ExternalRef(BaseModel):
+    """Link to a GitHub issue or comment a record was imported from or exported to."""
+
+    owner: str
+    repo: str
+    id: int
+    number: int | None = None
+    url: str
+
+
+PullRequestState = Literal["open", "closed", "merged"]
+
+
+class LinkedPullRequest(BaseModel):
+    """A GitHub pull request implementing an issue; ``state`` is refreshed."""
+
+    owner: str
+    repo: str
+    number: int
+    url: str
+    title: str | None = None
+    state: PullRequestState = "open"
+
+
+class Issue(BaseModel):
+    """Full issue object returned by list / get endpoints."""
+
+    id: int
+    number: int
+    title: str
+    body: str | None = None
+    state: str = "open"
+    state_reason: str | None = None
+    labels: list[Label] = []
+    assignees: list[SimpleUser] = []
+    comments: int = 0
+    created_at: datetime
+    updated_at: datetime
+    closed_at: datetime | None = None
+    closed_by: SimpleUser | None = None
+    author_association: str = "OWNER"
+    user: SimpleUser
+    milestone: Milestone | None = None
+    source: ExternalRef | None = None
+    exported_to: list[ExternalRef] = []
+    # Logins @mentioned in the body or any comment, recorded on write.
+    mentions: list[str] = []
+    # Work branch in the project repo, set by ``POST .../issues/{n}/branch``.
+    branch: str | None = None
+    linked_prs: list[LinkedPullRequest] = []
+    locked: bool = False
+    active_lock_reason: str | None = None
+    # Incremented on every write; compared against ``expected_version`` on update.
+    version: int = 0
+
+
+class WorkspaceIssue(Issue):
+    """An issue tagged with the project it belongs to, for cross-project views."""
+
+    project: str
+
+
+class Comment(BaseModel):
+    """Single comment on an issue."""
+
+    id: int
+    body: str
+    user: SimpleUser
+    created_at: datetime
+    updated_at: datetime
+    author_association: str = "OWNER"
+    source: ExternalRef | None = None
+    mentions: list[str] = []
+    # A ULID, unique across machines; older comments only have the numeric id.
+    node_id: str | None = None
+
+
+# ---------------------------------------------------------------------------
+# Persistence metadata
+# ---------------------------------------------------------------------------
+
+
+class Meta(BaseModel):
+    """Auto-increment counters stored alongside project data."""
+
+    next_issue_id: int = 1
+    next_comment_id: int = 1
+
+
+# ---------------------------------------------------------------------------
+# Activity feed
+# ---------------------------------------------------------------------------
+
+
+class ActivityEvent(BaseModel):
+    """A single store change derived from a git commit."""
+
+    sha: str
+    actor: str
+    action: str
+    issue_number: int | None = None
+    summary: str
+    timestamp: datetime
+
+
+class StoreCommit(BaseModel):
+    """A store commit and the files it touched.
+
+    ``actor`` is the person behind a commit authored as the bot.
+    """
+
+    sha: str
+    author_email: str
+    event: str | None = None
+    actor: str | None = None
+    files: list[str] = []
+
+
+# ---------------------------------------------------------------------------
+# Notifications
+# ---------------------------------------------------------------------------
+
+
+NotificationKind = Literal[
+    "assignment", "mention", "amplifier", "sync", "conflict", "milestone"
+]
+
+
+class Notification(BaseModel):
+    """Something that happened to the user, shown in the notification center."""
+
+    id: str
+    kind: NotificationKind
+    project: str
+    issue_number: int | None = None
+    title: str
+    created_at: datetime
+    read: bool = False
+
+
+class NotificationState(BaseModel):
+    """Persisted as ``notifications.json`` in the data directory.
+
+    ``cursors`` maps each project to the last store commit already scanned;
+    ``notified_milestones`` holds the milestone due dates already announced.
+    """
+
+    notifications: list[Notification] = Field(default_factory=list)
+    cursors: dict[str, str] = Field(default_factory=dict)
+    notified_milestones: list[str] = Field(default_factory=list)
+
+
+# ---------------------------------------------------------------------------
+# Logs
+# ---------------------------------------------------------------------------
+
+
+class LogEntry(BaseModel):
+    """One line of the JSON log file."""
+
+    time: datetime
+    level: str
+    logger: str
+    message: str
+    fields: dict[str, str] = Field(default_factory=dict)
+    exception: str | None = None
+
+
+# ---------------------------------------------------------------------------
+# Generic list response
+# ---------------------------------------------------------------------------
+
+T = TypeVar("T")
+
+
+class ListResponse(BaseModel, Generic[T]):
+    """Paginated list wrapper compatible with GitHub-style responses.
+
+    ``warnings`` name files that could not be read and were left out.
+    """
+
+    items: list[T]
+    total_count: int
+    page: int
+    per_page: int
+    warnings: list[str] = []
+
+
+# ---------------------------------------------------------------------------
+# Query / request models
+# ---------------------------------------------------------------------------
+
+
+StateReason = Literal["completed", "not_planned", "reopened"]
+
+
+class IssueFilters(BaseModel):
+    """Query parameters for listing issues."""
+
+    state: str | None = "open"
+    # Matches ``state_reason``, e.g. ``not_planned`` for issues closed as won't-fix.
+    reason: StateReason | None = None
+    labels: str | None = None  # comma-separated label names
+    assignee: str | None = None
+    mentioned: str | None = None
+    sort: str = "created"
+    direction: str = "desc"
+    page: int = 1
+    per_page: int = Field(default=30, le=100)
+
+
+IssueExportFormat = Literal["markdown", "csv", "json"]
+
+
+class ExportIssuesFileRequest(BaseModel):
+    """Write the issues matching the filters to *destination* in *format*."""
+
+    format: IssueExportFormat
+    destination: str
+    include_comments: bool = False
+    state: str | None = "all"
+    labels: str | None = None
+    assignee: str | None = None
+    mentioned: str | None = None
+
+
+class IssueExportResult(BaseModel):
+    path: str
+    issues: int
+
+
+class ExportIssueMarkdownRequest(BaseModel):
+    destination: str
+
+
+class IssueMarkdownExportResult(BaseModel):
+    path: str
+    attachments: int
+
+
+IssueImportFormat = Literal["csv", "jira"]
+
+
+class ImportColumnMapping(BaseModel):
+    """Header of the CSV column each issue field is read from; None skips it.
+
+    A header repeated across columns, as Jira does for labels and comments,
+    contributes every column's value.
+    """
+
+    title: str = "title"
+    body: str | None = "body"
+    state: str | None = "state"
+    labels: str | None = "labels"
+    assignees: str | None = "assignees"
+    milestone: str | None = "milestone"
+    author: str | None = "author"
+    created_at: str | None = "created_at"
+    closed_at: str | None = "closed_at"
+    comments: str | None = None
+
+
+class ImportIssuesFileRequest(BaseModel):
+    """Create an issue for every row of the CSV file at *source*.
+
+    Without a *mapping*, columns are read as this app's CSV export writes
+    them, or as Jira's "Export CSV (all fields)" does for ``jira``.
+    """
+
+    source: str
+    format: IssueImportFormat = "csv"
+    mapping: ImportColumnMapping | None = None
+
+
+IssueField = Literal["body", "labels", "assignees"]
+MergeField = Literal["title", "body", "state", "labels", "assignees", "milestone"]
+MergePolicy = Literal[
+    "prefer_latest",
+    "prefer_local",
+    "prefer_remote",
+    "union",
+    "prefer_closed",
+    "prefer_open",
+]
+
+
+class ProjectSettings(BaseModel):
+    """Team policies kept in the store as ``.attractor/settings.json``.
+
+    ``default_labels`` take precedence over the app-wide default labels.
+    ``required_fields`` must be non-empty when an issue is created.
+    ``amplifier_instructions`` are appended to every Amplifier prompt.
+    ``comment_commands`` runs ``/close``, ``/label`` and the like from comments.
+    ``branch_template`` names issue work branches from ``{number}`` and
+    ``{slug}`` (the title, lowercased and hyphenated).
+    ``merge_policies`` pick the value of a field edited on both sides of a
+    sync; fields without one take the most recently updated side.
+    ``commit_template`` formats store commit messages from ``{summary}``
+    (the default message), ``{action}`` (e.g. ``issue.update``),
+    ``{number}`` and ``{actor}``.
+    ``sequential_ids`` reserves each new issue number by pushing it to the
+    remote first, so numbers are never renumbered after a sync.
+    """
+
+    # Teammates on newer builds may save fields this build does not know.
+    model_config = ConfigDict(extra="ignore")
+
+    default_labels: list[str] = Field(default_factory=list)
+    required_fields: list[IssueField] = Field(default_factory=list)
+    amplifier_enabled: bool = True
+    amplifier_instructions: str | None = None
+    comment_commands: bool = True
+    branch_template: str = "issue/{number}-{slug}"
+    merge_policies: dict[MergeField, MergePolicy] = Field(default_factory=dict)
+    commit_template: str | None = None
+    sequential_ids: bool = False
+
+    @field_validator("branch_template")
+    @classmethod
+    def _check_branch_template(cls, value: str) -> str:
+        if "{number}" not in value:
+            raise ValueError("must include {number}")
+        return value.strip()
+
+    @field_validator("merge_policies")
+    @classmethod
+    def _check_merge_policies(
+        cls, value: dict[MergeField, MergePolicy]
+    ) -> dict[MergeField, MergePolicy]:
+        for field, policy in value.items():
+            if policy == "union" and field not in ("labels", "assignees"):
+                raise ValueError(f"union only applies to list fields, not {field}")
+            if policy in ("prefer_closed", "prefer_open") and field != "state":
+                raise ValueError(f"{policy} only applies to state, not {field}")
+        return value
+
+    @field_validator("commit_template")
+    @classmethod
+    def _check_commit_template(cls, value: str | None) -> str | None:
+        if value is None or not value.strip():
+            return None
+        try:
+            value.format(summary="", action="", number="", actor="")
+        except (KeyError, IndexError, ValueError) as exc:
+            raise ValueError(f"unknown or malformed placeholder: {exc}") from exc
+        return value.strip()
+
+
+class UpdateProjectSettingsRequest(BaseModel):
+    model_config = ConfigDict(extra="forbid")
+
+    default_labels: list[str] | None = None
+    required_fields: list[IssueField] | None = None
+    amplifier_enabled: bool | None = None
+    amplifier_instructions: str | None = None
+    comment_commands: bool | None = None
+    branch_template: str | None = None
+    merge_policies: dict[MergeField, MergePolicy] | None = None
+    commit_template: str | None = None
+    sequential_ids: bool | None = None
+
+
+class CreateIssueRequest(BaseModel):
+    title: str
+    body: str | None = None
+    assignees: list[str] = []
+    labels: list[str] | None = None
+
+
+class FindSimilarIssuesRequest(BaseModel):
+    title: str
+    body: str | None = None
+    limit: int = Field(default=5, ge=1, le=20)
+
+
+class UpdateIssueRequest(BaseModel):
+    title: str | None = None
+    body: str | None = None
+    state: str | None = None
+    state_reason: StateReason | None = None
+    assignees: list[str] | None = None
+    labels: list[str] | None = None
+    # The ``version`` the client loaded; the update is refused if it has moved on.
+    expected_version: int | None = None
+
+
+class PlanMilestoneRequest(BaseModel):
+    # Free text, e.g. "two weeks for one person, bugs first".
+    constraints: str | None = None
+
+
+class AssignMilestoneRequest(BaseModel):
+    issues: list[int]
+
+
+class LockIssueRequest(BaseModel):
+    lock_reason: Literal["off-topic", "too heated", "resolved", "spam"] | None = None
+
+
+class CreateCommentRequest(BaseModel):
+    body: str
+
+
+class UpdateCommentRequest(BaseModel):
+    body: str
+
+
+class CreateLabelRequest(BaseModel):
+    name: str
+    color: str
+    description: str | None = None
+
+
+class UpdateLabelRequest(BaseModel):
+    new_name: str | None = None
+    color: str | None = None
+    description: str | None = None
+
+
+# ---------------------------------------------------------------------------
+# Amplifier integration
+# ---------------------------------------------------------------------------
+
+
+class IssuePolishSuggestion(BaseModel):
+    """Amplifier's rewrite of an issue, for the user to apply or discard.
+
+    ``version`` is the issue's version it was based on; pass it as
+    ``expected_version`` when applying so later edits are not overwritten.
+    """
+
+    number: int
+    version: int
+    title: str
+    body: str
+
+
+class LabelSuggestion(BaseModel):
+    """A project label Amplifier thinks fits an issue, with its 0-1 confidence."""
+
+    label: Label
+    confidence: float
+
+
+class PlannedIssue(BaseModel):
+    number: int
+    title: str
+    estimate: str | None = None
+    rationale: str = ""
+
+
+class MilestonePlan(BaseModel):
+    """Open issues Amplifier proposes for a milestone, in working order."""
+
+    milestone: int
+    summary: str = ""
+    issues: list[PlannedIssue] = []
+
+
+class AmplifierSessionInfo(BaseModel):
+    """Tracks an Amplifier CLI session attached to an issue."""
+
+    model_config = ConfigDict(alias_generator=to_camel, populate_by_name=True)
+
+    project_name: str
+    issue_number: int
+    status: str
+    started_at: str
+    finished_at: str | None = None
+    error: str | None = None
+    log_file: str | None = None
+    paths: list[str] = []
+
+
+class CommentCommandsConfig(BaseModel):
+    """Whether this machine runs commands from its account's synced comments."""
+
+    synced: bool = False
+
+
+class RunAmplifierRequest(BaseModel):
+    # Repo-relative files or directories to confine the session to; empty is all.
+    paths: list[str] = Field(default_factory=list)
+
+
+class AmplifierSessionLogs(BaseModel):
+    """The last lines of a session's stderr, with credentials redacted."""
+
+    log_file: str
+    lines: list[str]
+    # Lines before these that were left out.
+    truncated: int = 0
+
+
+# ---------------------------------------------------------------------------
+# Project / store configuration (Phase 1 – configurable store backing)
+# ---------------------------------------------------------------------------
+
+
+class StoreManifest(BaseModel):
+    """Contents of ``.attractor-store.json`` inside a store directory."""
+
+    store_id: str
+
+
+class GitHubStoreConfig(BaseModel):
+    """Optional GitHub remote metadata for a store.
+
+    ``transport`` picks how git reaches the remote: HTTPS with the account's
+    token, or SSH with the user's agent or ``ssh_key_path``.
+    """
+
+    owner: str
+    repo: str
+    remote_url: str
+    transport: Literal["https", "ssh"] = "https"
+    ssh_key_path: str | None = None
+
+
+class RemoteStoreConfig(BaseModel):
+    """Hosted remote for a store on any supported provider.
+
+    ``user`` picks the provider account whose token is used.
+    """
+
+    provider: str
+    base_url: str
+    owner: str
+    repo: str
+    web_url: str
+    user: str
+
+
+class StoreConfig(BaseModel):
+    """Describes where a project's issues store lives on disk.
+
+    GitHub-backed stores also set ``github`` so GitHub-only features
+    (collaborators, visibility, mirroring) keep working. ``branch`` is the
+    branch the store reads and writes; None uses the remote's default.
+    """
+
+    path: str
+    github: GitHubStoreConfig | None = None
+    remote: RemoteStoreConfig | None = None
+    branch: str | None = None
+
+
+class CommitSigning(BaseModel):
+    """How store commits are signed.
+
+    ``key`` is a GPG key ID, or for SSH a public key path (or ``key::``
+    literal) as accepted by git's ``user.signingKey``.
+    """
+
+    format: Literal["ssh", "gpg"]
+    key: str
+
+
+class CommitIdentity(BaseModel):
+    """Name and email a project's store commits are authored as.
+
+    Either may be left unset to use the app-wide setting or the account's.
+    """
+
+    name: str | None = None
+    email: str | None = None
+
+
+class MirrorConfig(BaseModel):
+    """GitHub repo that selected issues are kept in sync with.
+
+    ``conflict_policy`` decides which side wins when an issue changed in both
+    places since the last sync: ``local``, ``remote``, or ``newest``.
+    """
+
+    owner: str
+    repo: str
+    conflict_policy: Literal["local", "remote", "newest"] = "newest"
+
+
+class IssueMirror(BaseModel):
+    """Link between a local issue and its GitHub mirror (``mirror/{number}.json``).
+
+    The ``*_updated_at`` fields record each side as of the last sync; comments
+    map local IDs to GitHub comment IDs.
+    """
+
+    owner: str
+    repo: str
+    number: int
+    id: int
+    url: str
+    local_updated_at: datetime
+    remote_updated_at: datetime
+    comments: dict[str, int] = {}
+
+
+class ProjectConfig(BaseModel):
+    """Persisted as ``project.json`` inside a project metadata directory."""
+
+    name: str
+    created_at: datetime
+    store_id: str
+    store: StoreConfig
+    project_path: str | None = None
+    github_account: str | None = None
+    mirror: MirrorConfig | None = None
+    signing: CommitSigning | None = None
+    author: CommitIdentity | None = None
+    # Amplifier profile for this project on this machine; None uses the default.
+    amplifier_profile: str | None = None
+    # Kept here rather than in the store, so a push cannot turn it on.
+    synced_comment_commands: bool = False
+
+
+class VerifyStoreResult(BaseModel):
+    """Issue files found corrupt, and which of them were restored from history."""
+
+    quarantined: list[str] = []
+    recovered: list[int] = []
+    unrecoverable: list[str] = []
+
+
+class ProjectGitStatus(BaseModel):
+    """Working tree state of a project repo; ``branch`` is None when detached."""
+
+    branch: str | None
+    upstream: str | None = None
+    ahead: int = 0
+    behind: int = 0
+    modified: list[str] = []
+    untracked: list[str] = []
+
+    @computed_field
+    @property
+    def clean(self) -> bool:
+        return not self.modified and not self.untracked
+
+
+# ---------------------------------------------------------------------------
+# App-level configuration
+# ---------------------------------------------------------------------------
+
+
+class RecentIssue(BaseModel):
+    """An issue the user opened, most recent first in ``AppConfig.recent_issues``."""
+
+    project: str
+    number: int
+
+
+class DesktopNotificationSettings(BaseModel):
+    """Which notification categories raise an OS notification while unfocused."""
+
+    amplifier: bool = True
+    sync_conflicts: bool = True
+    milestones: bool = True
+
+
+class AppSettings(BaseModel):
+    """User preferences, edited through ``/api/config/settings``.
+
+    ``auto_sync_interval`` is in seconds, and 0 disables automatic store
+    syncs. A ``max_amplifier_sessions`` of 0 means no limit.
+    ``default_labels`` are applied to new issues created without labels.
+    ``editor_command`` is a template such as ``zed {path}:{line}``, and
+    ``terminal_command`` one such as ``kitty --directory {path}``; when
+    unset, a known program for the platform is used.
+    ``commit_name`` and ``commit_email`` author store commits instead of the
+    GitHub login and its commit email. With ``commit_as_bot``, store commits
+    are authored as the app's bot and the person is kept in a trailer.
+    """
+
+    model_config = ConfigDict(extra="forbid")
+
+    auto_sync_interval: int = Field(default=0, ge=0)
+    max_amplifier_sessions: int = Field(default=0, ge=0)
+    default_labels: list[str] = Field(default_factory=list)
+    editor_command: str | None = None
+    terminal_command: str | None = None
+    commit_name: str | None = None
+    commit_email: str | None = None
+    commit_as_bot: bool = False
+
+    @field_validator("auto_sync_interval")
+    @classmethod
+    def _check_interval(cls, value: int) -> int:
+        if 0 < value < 60:
+            raise ValueError("must be 0 to disable, or at least 60 seconds")
+        return value
+
+    @field_validator("default_labels")
+    @classmethod
+    def _clean_labels(cls, value: list[str]) -> list[str]:
+        return list(dict.fromkeys(name.strip() for name in value if name.strip()))
+
+    @field_validator("editor_command")
+    @classmethod
+    def _check_editor(cls, value: str | None) -> str | None:
+        return validate_command_template(value) if value else None
+
+    @field_validator("terminal_command")
+    @classmethod
+    def _check_terminal(cls, value: str | None) -> str | None:
+        return validate_command_template(value, require_path=False) if value else None
+
+
+class UpdateAppSettingsRequest(BaseModel):
+    model_config = ConfigDict(extra="forbid")
+
+    auto_sync_interval: int | None = None
+    max_amplifier_sessions: int | None = None
+    default_labels: list[str] | None = None
+    editor_command: str | None = None
+    terminal_command: str | None = None
+    commit_name: str | None = None
+    commit_email: str | None = None
+    commit_as_bot: bool | None = None
+
+
+class SettingsExport(BaseModel):
+    """Portable app configuration, written by ``/api/config/export``.
+
+    Only preferences are included: no tokens, accounts or machine-local
+    history such as recent projects.
+    """
+
+    version: int = 1
+    settings: AppSettings = Field(default_factory=AppSettings)
+    desktop_notifications: DesktopNotificationSettings = Field(
+        default_factory=DesktopNotificationSettings
+    )
+
+
+class AppConfig(BaseModel):
+    """Persisted as ``app-config.json`` in the data directory."""
+
+    pat_banner_dismissed: bool = False
+    recent_projects: list[str] = Field(default_factory=list)
+    recent_issues: list[RecentIssue] = Field(default_factory=list)
+    desktop_notifications: DesktopNotificationSettings = Field(
+        default_factory=DesktopNotificationSettings
+    )
+    settings: AppSettings = Field(default_factory=AppSettings)
+
+
+class GitHubAccount(BaseModel):
+    """A GitHub identity the app can act as.
+
+    When the token lives in the OS keyring, only ``token_ref`` is persisted
+    and ``token`` is filled in at read time. Accounts with source
+    ``credential_helper`` persist no token; it comes from ``git credential fill``.
+    """
+
+    user: str
+    token: str | None = None
+    token_ref: str | None = None
+    source: Literal["token", "credential_helper"] = "token"
+    validated_at: str
+    email: str | None = None
+
+    @property
+    def commit_email(self) -> str:
+        """Email for store commits; GitHub's noreply address until one is chosen."""
+        return self.email or f"{self.user}@users.noreply.github.com"
+
+
+class ProviderAccount(BaseModel):
+    """Credentials for a non-GitHub git host, keyed by provider and base URL.
+
+    Tokens follow the same keyring rules as :class:`GitHubAccount`.
+    """
+
+    provider: str
+    base_url: str
+    user: str
+    token: str | None = None
+    token_ref: str | None = None
+    validated_at: str
+
+
+AmplifierProvider = Literal["anthropic", "openai", "azure", "local"]
+
+
+class AmplifierProfile(BaseModel):
+    """A named model provider for Amplifier sessions.
+
+    ``api_key`` follows the keyring rules of :class:`GitHubAccount`, with
+    ``api_key_ref``; without one the provider's usual environment variable
+    is used. For ``azure`` the model is the deployment name.
+    """
+
+    name: str
+    provider: AmplifierProvider
+    model: str
+    base_url: str | None = None
+    api_key: str | None = None
+    api_key_ref: str | None = None
+
+
+class AmplifierProfiles(BaseModel):
+    """Persisted as ``amplifier-profiles.json`` in the data directory."""
+
+    profiles: list[AmplifierProfile] = Field(default_factory=list)
+    default: str | None = None
+
+    def find(self, name: str | None) -> AmplifierProfile | None:
+        return next((p for p in self.profiles if p.name == name), None)
+
+
+class ProviderAccounts(BaseModel):
+    """Persisted as ``provider-accounts.json`` in the data directory."""
+
+    accounts: list[ProviderAccount] = Field(default_factory=list)
+
+    def find(self, provider: str, base_url: str, user: str) -> ProviderAccount | None:
+        return next(
+            (
+                a
+                for a in self.accounts
+                if (a.provider, a.base_url, a.user) == (provider, base_url, user)
+            ),
+            None,
+        )
+
+
+class Workspace(BaseModel):
+    """A named set of projects whose issues can be viewed together."""
+
+    name: str
+    projects: list[str] = Field(default_factory=list)
+
+
+class Workspaces(BaseModel):
+    """Persisted as ``workspaces.json`` in the data directory."""
+
+    workspaces: list[Workspace] = Field(default_factory=list)
+
+    def find(self, name: str) -> Workspace | None:
+        return next((w for w in self.workspaces if w.name == name), None)
+
+
+class GitHubAccounts(BaseModel):
+    """Persisted as ``github-accounts.json`` in the data directory."""
+
+    default: str | None = None
+    accounts: list[GitHubAccount] = Field(default_factory=list)
+
+    def find(self, user: str) -> GitHubAccount | None:
+        return next((a for a in self.accounts if a.user == user), None)
+
+
+# ---------------------------------------------------------------------------
+# Filesystem validation
+# ---------------------------------------------------------------------------
+
+
+class PathValidationResponse(BaseModel):
+    """Result of validating a filesystem path for use as a project directory."""
+
+    path: str
+    status: Literal[
+        "not_found",
+        "empty",
+        "has_content",
+        "git_repo",
+        "already_registered",
+        "not_a_directory",
+        "permission_denied",
+    ]
+    project_name: str | None = None
+    suggested_name: str | None = None
+
+
+# ---------------------------------------------------------------------------
+# Updates
+# ---------------------------------------------------------------------------
+
+
+class UpdateStatus(BaseModel):
+    """Commit SHAs of the cached frontend build and the latest published one."""
+
+    current: str | None = None
+    latest: str | None = None
+    available: bool = False
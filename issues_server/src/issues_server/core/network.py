@@ -0,0 +1,290 @@
+"""Timeouts and cancellation for GitHub API and git network operations."""
+
+from __future__ import annotations
+
+import logging
+import os
+import re
+import shlex
+import subprocess
+import threading
+import uuid
+from collections.abc import Callable, Iterator
+from contextlib import contextmanager
+from contextvars import ContextVar
+from dataclasses import dataclass, field
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import IO
+
+import httpx
+
+from .config import Settings
+from .errors import AppError
+from .logs import span
+
+logger = logging.getLogger(__name__)
+
+_http_timeout = httpx.Timeout(30.0, connect=10.0)
+_git_timeout = 300.0
+
+_lock = threading.Lock()
+_running: dict[int, subprocess.Popen[str]] = {}
+_cancelled: set[int] = set()
+
+
+class NetworkOperationCancelled(AppError):
+    """Raised when a git network operation is cancelled by the user."""
+
+    status_code = 409
+    code = "OPERATION_CANCELLED"
+
+
+@dataclass
+class NetworkOperation:
+    """A user-visible task, such as a clone, whose git commands cancel together."""
+
+    id: str
+    kind: str
+    project: str | None
+    started_at: datetime
+    cancelled: bool = False
+    pids: set[int] = field(default_factory=set)
+
+
+_operations: dict[str, NetworkOperation] = {}
+_current_operation: ContextVar[NetworkOperation | None] = ContextVar(
+    "network_operation", default=None
+)
+
+
+@dataclass
+class GitProgress:
+    """One line of git's ``--progress`` output, e.g. ``Receiving objects``."""
+
+    phase: str
+    percent: int
+    current: int
+    total: int
+    bytes: int | None = None
+
+
+_PROGRESS = re.compile(
+    r"^(?:remote: )?(?P<phase>[A-Za-z ]+):\s+(?P<percent>\d+)% "
+    r"\((?P<current>\d+)/(?P<total>\d+)\)"
+    r"(?:, (?P<size>[\d.]+) (?P<unit>[KMGT]?i?B))?"
+)
+_UNITS = {"B": 1, "KiB": 1 << 10, "MiB": 1 << 20, "GiB": 1 << 30, "TiB": 1 << 40}
+
+
+def parse_git_progress(line: str) -> GitProgress | None:
+    match = _PROGRESS.match(line.strip())
+    if match is None:
+        return None
+    size = match["size"]
+    return GitProgress(
+        phase=match["phase"].strip(),
+        percent=int(match["percent"]),
+        current=int(match["current"]),
+        total=int(match["total"]),
+        bytes=int(float(size) * _UNITS.get(match["unit"], 1)) if size else None,
+    )
+
+
+def configure_network(settings: Settings) -> None:
+    """Apply timeout settings. Called once at startup."""
+    global _http_timeout, _git_timeout
+    _http_timeout = httpx.Timeout(
+        settings.http_read_timeout, connect=settings.http_connect_timeout
+    )
+    _git_timeout = settings.git_timeout
+
+
+def http_timeout() -> httpx.Timeout:
+    """Timeout to use for GitHub API requests."""
+    return _http_timeout
+
+
+def ssh_command(key_path: str | None = None) -> str:
+    """``core.sshCommand`` for SSH remotes: never prompt, optionally pin a key.
+
+    Without *key_path*, ssh falls back to the agent and default keys.
+    """
+    command = "ssh -o BatchMode=yes"
+    if key_path:
+        command += f" -i {shlex.quote(key_path)} -o IdentitiesOnly=yes"
+    return command
+
+
+@contextmanager
+def network_operation(
+    kind: str, project: str | None = None, operation_id: str | None = None
+) -> Iterator[NetworkOperation]:
+    """Group the git commands run inside the block under one cancellable operation.
+
+    *operation_id* lets the client name the operation up front, so it can
+    cancel a request that has not returned yet. The operation follows the
+    context into ``asyncio.to_thread`` workers.
+    """
+    operation = NetworkOperation(
+        id=operation_id or uuid.uuid4().hex,
+        kind=kind,
+        project=project,
+        started_at=datetime.now(timezone.utc),
+    )
+    with _lock:
+        if operation.id in _operations:
+            raise AppError(
+                f"Operation {operation.id} is already running",
+                code="OPERATION_EXISTS",
+                status_code=409,
+            )
+        _operations[operation.id] = operation
+    token = _current_operation.set(operation)
+    try:
+        yield operation
+    finally:
+        _current_operation.reset(token)
+        with _lock:
+            _operations.pop(operation.id, None)
+
+
+def current_network_operation() -> NetworkOperation | None:
+    return _current_operation.get()
+
+
+def list_network_operations() -> list[NetworkOperation]:
+    with _lock:
+        return list(_operations.values())
+
+
+def cancel_operation(operation_id: str) -> bool:
+    """Cancel one operation: kill its running git commands and refuse new ones."""
+    with _lock:
+        operation = _operations.get(operation_id)
+        if operation is None:
+            return False
+        operation.cancelled = True
+        procs = [_running[pid] for pid in operation.pids if pid in _running]
+        _cancelled.update(p.pid for p in procs)
+    for proc in procs:
+        proc.kill()
+    return True
+
+
+def _read_progress(
+    stream: IO[str], on_progress: Callable[[GitProgress], None], lines: list[str]
+) -> None:
+    # git redraws progress with carriage returns, so split on those too.
+    buffer = ""
+    for char in iter(lambda: stream.read(1), ""):
+        if char not in "\r\n":
+            buffer += char
+            continue
+        lines.append(buffer + char)
+        progress = parse_git_progress(buffer)
+        if progress is not None:
+            on_progress(progress)
+        buffer = ""
+    lines.append(buffer)
+
+
+def run_git_network(
+    args: list[str],
+    cwd: Path | None = None,
+    on_progress: Callable[[GitProgress], None] | None = None,
+    env: dict[str, str] | None = None,
+) -> subprocess.CompletedProcess[str]:
+    """Run a git command that talks to a remote, with timeout and cancellation.
+
+    Prompts are disabled so a missing credential fails fast instead of
+    hanging, and git aborts transfers that stall below 1 KB/s for a minute.
+    With *on_progress*, pass ``--progress`` in *args*; each progress line is
+    parsed and reported from a reader thread. *env* is added to the
+    environment, which keeps secrets out of the command line.
+
+    Raises:
+        subprocess.CalledProcessError: git exited with an error.
+        TimeoutError: The command exceeded the configured git timeout.
+        NetworkOperationCancelled: :func:`cancel_network_operations` or
+            :func:`cancel_operation` killed it.
+    """
+    with span(logger, f"git {args[0]}", cwd=cwd or ""):
+        return _run_git_network(args, cwd, on_progress, env)
+
+
+def _run_git_network(
+    args: list[str],
+    cwd: Path | None = None,
+    on_progress: Callable[[GitProgress], None] | None = None,
+    env: dict[str, str] | None = None,
+) -> subprocess.CompletedProcess[str]:
+    operation = _current_operation.get()
+    if operation is not None and operation.cancelled:
+        raise NetworkOperationCancelled(f"git {args[0]} was cancelled")
+    cmd = [
+        "git",
+        "-c",
+        "http.lowSpeedLimit=1000",
+        "-c",
+        "http.lowSpeedTime=60",
+        *args,
+    ]
+    proc = subprocess.Popen(
+        cmd,
+        cwd=cwd,
+        stdout=subprocess.PIPE,
+        stderr=subprocess.PIPE,
+        text=True,
+        env={**os.environ, **(env or {}), "GIT_TERMINAL_PROMPT": "0"},
+    )
+    with _lock:
+        _running[proc.pid] = proc
+        if operation is not None:
+            operation.pids.add(proc.pid)
+            if operation.cancelled:
+                _cancelled.add(proc.pid)
+                proc.kill()
+    stderr_lines: list[str] = []
+    reader: threading.Thread | None = None
+    if on_progress is not None:
+        assert proc.stderr is not None
+        reader = threading.Thread(
+            target=_read_progress,
+            args=(proc.stderr, on_progress, stderr_lines),
+            daemon=True,
+        )
+        reader.start()
+    try:
+        if reader is None:
+            stdout, stderr = proc.communicate(timeout=_git_timeout)
+        else:
+            proc.wait(timeout=_git_timeout)
+            reader.join()
+            assert proc.stdout is not None
+            stdout, stderr = proc.stdout.read(), "".join(stderr_lines)
+    except subprocess.TimeoutExpired:
+        proc.kill()
+        proc.wait()
+        raise TimeoutError(f"git {args[0]} timed out after {_git_timeout:.0f}s")
+    finally:
+        with _lock:
+            _running.pop(proc.pid, None)
+            was_cancelled = proc.pid in _cancelled
+            _cancelled.discard(proc.pid)
+
+    if was_cancelled:
+        raise NetworkOperationCancelled(f"git {args[0]} was cancelled")
+    if proc.returncode != 0:
+        raise subprocess.CalledProcessError(proc.returncode, cmd, stdout, stderr)
+    return subprocess.CompletedProcess(cmd, proc.returncode, stdout, stderr)
+
+
+def cancel_network_operations() -> int:
+    """Kill every in-flight git network operation. Returns how many were killed."""
+    with _lock:
+        procs = list(_running.values())
+        _cancelled.update(p.pid for p in procs)
+    for proc in procs:
+        proc.kill()
+    return len(procs)
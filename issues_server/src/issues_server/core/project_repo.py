@@ -0,0 +1,163 @@
+"""Commit and push the project repository a project points at.
+
+Unlike the store, the project repo belongs to the user: only the paths they
+pick are committed (everything changed when none are given), and its remotes
+are left untouched. Pushes to github.com get the account token through the
+environment rather than a rewritten remote URL.
+"""
+
+from __future__ import annotations
+
+import base64
+import logging
+import re
+import subprocess
+from pathlib import Path
+
+from .errors import AppError
+from .logs import span
+from .models import ProjectGitStatus
+from .network import run_git_network
+
+logger = logging.getLogger(__name__)
+
+
+class ProjectRepoError(AppError):
+    """The project repo is missing, has nothing to commit, or git refused."""
+
+    status_code = 409
+    code = "PROJECT_REPO_ERROR"
+
+
+_GITHUB_REMOTE = re.compile(r"github\.com[:/]([^/]+)/([^/]+?)(?:\.git)?/?$")
+
+
+def issue_branch_name(template: str, number: int, title: str) -> str:
+    """Fill *template* with the issue number and a slug of its title."""
+    slug = re.sub(r"[^a-z0-9]+", "-", title.lower()).strip("-")[:40].rstrip("-")
+    return template.replace("{number}", str(number)).replace("{slug}", slug or "issue")
+
+
+def github_auth_env(token: str) -> dict[str, str]:
+    """git config, via the environment, that sends *token* to github.com only."""
+    basic = base64.b64encode(f"x-access-token:{token}".encode()).decode()
+    return {
+        "GIT_CONFIG_COUNT": "1",
+        "GIT_CONFIG_KEY_0": "http.https://github.com/.extraheader",
+        "GIT_CONFIG_VALUE_0": f"AUTHORIZATION: basic {basic}",
+    }
+
+
+class ProjectRepo:
+    def __init__(self, path: Path, author: tuple[str, str] | None = None) -> None:
+        if not (path / ".git").exists():
+            raise ProjectRepoError(f"{path} is not a git repository", path=str(path))
+        self.path = path
+        self.author = author  # (name, email); git's own config is used when None
+
+    def _git(self, *args: str, check: bool = True) -> subprocess.CompletedProcess[str]:
+        with span(logger, f"git {args[0]}", repo=self.path):
+            return subprocess.run(
+                ["git", *args],
+                cwd=self.path,
+                capture_output=True,
+                text=True,
+                check=check,
+            )
+
+    def _identity(self) -> list[str]:
+        if self.author is None:
+            return []
+        name, email = self.author
+        return ["-c", f"user.name={name}", "-c", f"user.email={email}"]
+
+    def status(self) -> ProjectGitStatus:
+        """Current branch, its distance from upstream, and uncommitted files."""
+        result = self._git("status", "--porcelain=v2", "--branch", "-z")
+        status = ProjectGitStatus(branch=None)
+        records = iter(result.stdout.split("\0"))
+        for record in records:
+            if record.startswith("# branch.head "):
+                head = record.removeprefix("# branch.head ")
+                status.branch = None if head == "(detached)" else head
+            elif record.startswith("# branch.upstream "):
+                status.upstream = record.removeprefix("# branch.upstream ")
+            elif record.startswith("# branch.ab "):
+                ahead, behind = record.removeprefix("# branch.ab ").split()
+                status.ahead, status.behind = int(ahead), -int(behind)
+            elif record.startswith("? "):
+                status.untracked.append(record[2:])
+            elif record.startswith("1 "):
+                status.modified.append(record.split(" ", 8)[8])
+            elif record.startswith("2 "):
+                status.modified.append(record.split(" ", 9)[9])
+                next(records, None)  # the rename's original path
+            elif record.startswith("u "):
+                status.modified.append(record.split(" ", 10)[10])
+        return status
+
+    def github_repo(self) -> tuple[str, str] | None:
+        """(owner, repo) when origin is on github.com, over HTTPS or SSH."""
+        result = self._git("remote", "get-url", "origin", check=False)
+        match = _GITHUB_REMOTE.search(result.stdout.strip())
+        return (match.group(1), match.group(2)) if match else None
+
+    def checkout_branch(self, branch: str) -> bool:
+        """Switch to *branch*, creating it from HEAD if needed; True if created.
+
+        Raises:
+            ProjectRepoError: The name is not a valid branch name, or git
+                refused to switch (e.g. local changes would be overwritten).
+        """
+        valid = self._git("check-ref-format", "--branch", branch, check=False)
+        if valid.returncode != 0:
+            raise ProjectRepoError(f"'{branch}' is not a valid branch name")
+        exists = self._git(
+            "rev-parse", "--verify", "--quiet", f"refs/heads/{branch}", check=False
+        )
+        created = exists.returncode != 0
+        try:
+            self._git("checkout", *(["-b"] if created else []), branch)
+        except subprocess.CalledProcessError as exc:
+            raise ProjectRepoError(
+                f"Could not switch to {branch}: {exc.stderr.strip()}"
+            ) from exc
+        return created
+
+    def commit(self, message: str, paths: list[str] | None = None) -> str:
+        """Stage *paths* (or every change) and commit them; return the new sha.
+
+        Raises:
+            ProjectRepoError: Nothing was staged, or git rejected the commit.
+        """
+        pathspec = ["--", *(p.replace("\\", "/") for p in paths)] if paths else []
+        try:
+            self._git("add", "-A", *pathspec)
+        except subprocess.CalledProcessError as exc:
+            raise ProjectRepoError(
+                f"Could not stage changes: {exc.stderr.strip()}"
+            ) from exc
+        staged = self._git("diff", "--cached", "--quiet", *pathspec, check=False)
+        if staged.returncode == 0:
+            raise ProjectRepoError("There are no changes to commit")
+        try:
+            self._git(*self._identity(), "commit", "-m", message, *pathspec)
+        except subprocess.CalledProcessError as exc:
+            raise ProjectRepoError(f"Could not commit: {exc.stderr.strip()}") from exc
+        return self._git("rev-parse", "HEAD").stdout.strip()
+
+    def push(self, token: str | None = None, branch: str | None = None) -> None:
+        """Push *branch* (default: the current one) to origin, setting its upstream.
+
+        Raises:
+            ProjectRepoError: There is no origin, or the push was rejected.
+        """
+        if self._git("remote", "get-url", "origin", check=False).returncode != 0:
+            raise ProjectRepoError("The project repository has no origin remote")
+        env = github_auth_env(token) if token else None
+        try:
+            run_git_network(
+                ["push", "-u", "origin", branch or "HEAD"], cwd=self.path, env=env
+            )
+        except subprocess.CalledProcessError as exc:
+            raise ProjectRepoError(f"Push was rejected: {exc.stderr.strip()}") from exc
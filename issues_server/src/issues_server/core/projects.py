@@ -0,0 +1,87 @@
+"""Project configuration on disk and opening a project's store."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+from .config import Settings
+from .errors import AppError
+from .models import ProjectConfig, StoreManifest
+from .storage import ProjectStorage, StoreIdMismatchError, write_text_atomic
+
+
+class ProjectNotFoundError(AppError):
+    status_code = 404
+    code = "NOT_FOUND"
+
+
+class StoreUnavailableError(AppError):
+    """The store directory or its manifest is missing."""
+
+    status_code = 500
+    code = "INTERNAL_SERVER_ERROR"
+
+
+def load_project_config(name: str, settings: Settings) -> ProjectConfig:
+    """Read project.json for a project. Raises 404 if not found."""
+    project_dir = settings.data_dir / "projects" / name
+    config_path = project_dir / "project.json"
+    if not config_path.exists():
+        raise ProjectNotFoundError(f"Project '{name}' not found")
+    data = json.loads(config_path.read_text())
+    return ProjectConfig(**data)
+
+
+def save_project_config(config: ProjectConfig, settings: Settings) -> None:
+    """Write project.json for a project."""
+    config_path = settings.data_dir / "projects" / config.name / "project.json"
+    write_text_atomic(
+        config_path,
+        json.dumps(config.model_dump(mode="json"), indent=2, default=str) + "\n",
+    )
+
+
+def read_store_manifest(store_path: Path) -> StoreManifest:
+    """Read .attractor-store.json from a store directory."""
+    manifest_path = store_path / ".attractor-store.json"
+    if not manifest_path.exists():
+        raise StoreUnavailableError(f"Store manifest not found at {store_path}")
+    data = json.loads(manifest_path.read_text())
+    return StoreManifest(**data)
+
+
+def write_store_manifest(store_path: Path, store_id: str) -> None:
+    """Write .attractor-store.json to a store directory."""
+    manifest_path = store_path / ".attractor-store.json"
+    write_text_atomic(manifest_path, json.dumps({"store_id": store_id}, indent=2) + "\n")
+
+
+def open_project_storage(
+    config: ProjectConfig,
+    author: tuple[str, str] | None = None,
+    read_only: bool = False,
+    sync_freshness: float = 0.0,
+    actor: str | None = None,
+    user: str | None = None,
+) -> ProjectStorage:
+    """The project's store, after checking it is the one the project was created with."""
+    store_path = Path(config.store.path)
+    if not store_path.exists():
+        raise StoreUnavailableError(f"Store directory not found at {store_path}")
+    manifest = read_store_manifest(store_path)
+    if manifest.store_id != config.store_id:
+        raise StoreIdMismatchError(
+            "Store ID mismatch. Store may have been reassigned."
+        )
+    return ProjectStorage(
+        store_path,
+        author,
+        read_only,
+        config.store.branch,
+        sync_freshness,
+        actor,
+        config.store_id,
+        user,
+        config.store.github.owner if config.store.github else None,
+    )
@@ -0,0 +1,77 @@
+"""Likely duplicates of an issue about to be filed, by text similarity."""
+
+from __future__ import annotations
+
+import re
+
+from pydantic import BaseModel
+
+from .models import Issue
+
+# Titles carry most of the signal; bodies only nudge the score.
+TITLE_WEIGHT = 0.75
+MIN_SCORE = 0.4
+
+_WORD = re.compile(r"[a-z0-9]+")
+_STOPWORDS = frozenset(
+    "a an and are as at be but by can do does for from how i if in is it its no not"
+    " of on or so that the this to was we when where which why will with you".split()
+)
+
+
+class SimilarIssue(BaseModel):
+    number: int
+    title: str
+    state: str
+    score: float
+
+
+def _words(text: str) -> set[str]:
+    return {w for w in _WORD.findall(text.lower()) if w not in _STOPWORDS}
+
+
+def _trigrams(text: str) -> set[str]:
+    # Per word, so "crash on save" and "save crashes" still share most trigrams.
+    return {
+        padded[i : i + 3]
+        for word in _WORD.findall(text.lower())
+        for padded in [f"  {word} "]
+        for i in range(len(padded) - 2)
+    }
+
+
+def _dice(a: set[str], b: set[str]) -> float:
+    if not a or not b:
+        return 0.0
+    return 2 * len(a & b) / (len(a) + len(b))
+
+
+def find_similar_issues(
+    issues: list[Issue], title: str, body: str | None = None, limit: int = 5
+) -> list[SimilarIssue]:
+    """The *issues* most like *title* and *body*, best first, scored 0 to 1.
+
+    Titles are compared by character trigrams, which tolerate typos and word
+    forms, and bodies by shared words.
+    """
+    title_grams = _trigrams(title)
+    body_words = _words(f"{title} {body or ''}")
+    if not title_grams:
+        return []
+    scored = []
+    for issue in issues:
+        score = TITLE_WEIGHT * _dice(title_grams, _trigrams(issue.title))
+        score += (1 - TITLE_WEIGHT) * _dice(
+            body_words, _words(f"{issue.title} {issue.body or ''}")
+        )
+        if score >= MIN_SCORE:
+            scored.append(
+                SimilarIssue(
+                    number=issue.number,
+                    title=issue.title,
+                    state=issue.state,
+                    score=round(score, 3),
+                )
+            )
+    scored.sort(key=lambda s: (-s.score, -s.number))
+    return scored[:limit]
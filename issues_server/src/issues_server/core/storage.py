@@ -0,0 +1,1327 @@
+"""Git-backed JSON file storage for issue tracking projects."""
+
+from __future__ import annotations
+
+import json
+import logging
+import os
+import re
+import secrets
+import subprocess
+import tempfile
+import time
+from concurrent.futures import ThreadPoolExecutor
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+from typing import TypeVar
+
+from pydantic import ValidationError
+
+from .errors import AppError
+from .logs import span
+from .merge import merge_issue
+from .models import (
+    ActivityEvent,
+    CommitSigning,
+    Comment,
+    Issue,
+    IssueFilters,
+    IssueMirror,
+    Label,
+    ListResponse,
+    Meta,
+    Milestone,
+    MilestoneListItem,
+    ProjectSettings,
+    StoreCommit,
+    VerifyStoreResult,
+)
+from .network import run_git_network
+
+logger = logging.getLogger(__name__)
+
+IssueT = TypeVar("IssueT", bound=Issue)
+
+# Ordered so that more specific subjects match before generic ones.
+_COMMIT_ACTIONS: list[tuple[re.Pattern[str], str]] = [
+    (re.compile(r"^Create issue #(\d+)"), "issue.create"),
+    (re.compile(r"^Update issue #(\d+)"), "issue.update"),
+    (re.compile(r"^Add comment #\d+ on issue #(\d+)"), "comment.create"),
+    (re.compile(r"^Update comment #\d+"), "comment.update"),
+    (re.compile(r"^Delete comment #\d+"), "comment.delete"),
+    (re.compile(r"^amplifier: result for issue #(\d+)"), "amplifier.result"),
+    (re.compile(r"^Run commands from comment #\d+ on issue #(\d+)"), "comment.command"),
+    (re.compile(r"^Link branch .+ to issue #(\d+)"), "issue.branch"),
+    (re.compile(r"^Lock issue #(\d+)"), "issue.lock"),
+    (re.compile(r"^Unlock issue #(\d+)"), "issue.unlock"),
+    (re.compile(r"^Link pull request .+ to issue #(\d+)"), "issue.pull_request"),
+    (re.compile(r"labels? (?:to|on|from) issue #(\d+)"), "issue.labels"),
+    (re.compile(r"^Add \d+ issues? to milestone "), "milestone.assign"),
+    (re.compile(r"^Create label "), "label.create"),
+    (re.compile(r"^Update label "), "label.update"),
+    (re.compile(r"^Delete label "), "label.delete"),
+    (re.compile(r"^Update project settings"), "settings.update"),
+]
+
+
+# Trailers on store commits, for tooling (e.g. GitHub Actions) and the activity feed.
+TRAILER_EVENT = "Attractor-Event"
+TRAILER_ISSUE = "Attractor-Issue"
+TRAILER_ACTOR = "Attractor-Actor"
+TRAILER_RESERVATION = "Attractor-Reservation"
+
+# Suffix of in-progress atomic writes; leftovers from a crash are never committed.
+TEMP_SUFFIX = ".tmp"
+_EXCLUDE_TEMP = f":(exclude,glob)**/*{TEMP_SUFFIX}"
+
+# Unreadable store files are moved here, keeping their path within the store.
+QUARANTINE_DIR = Path(".attractor") / "quarantine"
+
+_DIVERGED = re.compile(r"Not possible to fast-forward|diverg", re.IGNORECASE)
+_PUSH_REJECTED = re.compile(r"\[rejected\]|fetch first|non-fast-forward")
+
+# When each store last pulled, by path. Stores are opened per request, so
+# this has to outlive any one ProjectStorage.
+_last_synced: dict[Path, float] = {}
+
+# Origin URL whose store ID last matched, by store path, so pushes fetch for
+# the check only when origin changes.
+_verified_remotes: dict[Path, str] = {}
+
+# Issue files are small, so reads are dominated by per-file latency.
+_LOAD_WORKERS = 8
+
+_ISSUE_FILE = re.compile(r"issues/(\d+)\.json")
+
+# Export maps are keyed by local ID; every other ID map holds local IDs as values.
+_LOCAL_KEYED_MAPS = "github-export-"
+
+_REMOTE_MISSING = re.compile(r"repository not found|repository '.*' not found", re.I)
+
+
+def _git_path(path: str | Path) -> str:
+    """A store-relative path in the forward-slash form git expects on every OS."""
+    return str(path).replace("\\", "/")
+
+
+class StoreMissingError(AppError):
+    """The store's remote repository no longer exists or is not accessible."""
+
+    status_code = 410
+    code = "STORE_MISSING"
+
+
+class ReadOnlyStoreError(AppError):
+    """The store's remote needs credentials that are not set up on this machine."""
+
+    status_code = 403
+    code = "READ_ONLY"
+
+
+_SIGNING_FAILED = re.compile(
+    r"failed to sign|gpg failed|couldn't load public key|failed to write commit object",
+    re.I,
+)
+
+
+class StoreIdMismatchError(AppError):
+    """The store, or the remote it pushes to, belongs to a different project."""
+
+    status_code = 409
+    code = "STORE_ID_MISMATCH"
+
+
+class IdReservationError(AppError):
+    """Other machines kept taking the next issue number first."""
+
+    status_code = 409
+    code = "ID_RESERVATION_FAILED"
+
+
+class IssueLockedError(AppError):
+    """Only the issue's author or the store's owner may change a locked issue."""
+
+    status_code = 403
+    code = "ISSUE_LOCKED"
+
+    def __init__(self, number: int, reason: str | None = None) -> None:
+        super().__init__(f"Issue #{number} is locked", lock_reason=reason)
+
+
+class StoreBranchError(AppError):
+    """The configured store branch is not a valid branch name."""
+
+    status_code = 400
+    code = "INVALID_BRANCH"
+
+
+class CommitSigningError(AppError):
+    """git could not sign a store commit with the configured key."""
+
+    code = "COMMIT_SIGNING_FAILED"
+
+
+# GitHub login rules: alphanumerics and single inner hyphens, at most 39 chars.
+_MENTION = re.compile(
+    r"(?<![\w@/])@([A-Za-z0-9](?:[A-Za-z0-9]|-(?=[A-Za-z0-9])){0,38})"
+)
+
+
+def parse_mentions(text: str | None) -> list[str]:
+    """Logins @mentioned in *text*, in order of first appearance, once each."""
+    seen: dict[str, str] = {}
+    for login in _MENTION.findall(text or ""):
+        seen.setdefault(login.lower(), login)
+    return list(seen.values())
+
+
+def is_diverged(exc: subprocess.CalledProcessError) -> bool:
+    """Whether a failed pull was refused because the branches have diverged."""
+    return bool(_DIVERGED.search(exc.stderr or ""))
+
+
+def _read_bytes(path: Path) -> bytes | OSError:
+    """File contents, or the error, so one bad file does not end a pool map."""
+    try:
+        return path.read_bytes()
+    except OSError as exc:
+        return exc
+
+
+def _classify_commit(subject: str) -> tuple[str, int | None]:
+    """Map a store commit subject to an activity action and issue number."""
+    for pattern, action in _COMMIT_ACTIONS:
+        match = pattern.search(subject)
+        if match:
+            issue_number = int(match.group(1)) if match.groups() else None
+            return action, issue_number
+    return "other", None
+
+
+def sort_issues(issues: list[IssueT], filters: IssueFilters) -> None:
+    """Sort *issues* in place by ``filters.sort`` and ``filters.direction``."""
+    if filters.sort == "updated":
+        issues.sort(key=lambda i: i.updated_at)
+    elif filters.sort == "comments":
+        issues.sort(key=lambda i: i.comments)
+    else:
+        issues.sort(key=lambda i: i.created_at)
+
+    if filters.direction == "desc":
+        issues.reverse()
+
+
+_CROCKFORD = "0123456789ABCDEFGHJKMNPQRSTVWXYZ"
+
+
+def new_ulid() -> str:
+    """A ULID: 48 bits of milliseconds then 80 random bits, in Crockford base32."""
+    value = (time.time_ns() // 1_000_000) << 80 | secrets.randbits(80)
+    return "".join(_CROCKFORD[(value >> shift) & 31] for shift in range(125, -1, -5))
+
+
+def comment_sort_key(comment: Comment) -> tuple[datetime, str]:
+    """Creation order, with ties broken the same way on every machine."""
+    return comment.created_at, comment.node_id or f"{comment.id:026d}"
+
+
+def write_text_atomic(path: Path, text: str) -> None:
+    """Replace *path* with *text* so readers see the old or new file, never a partial one.
+
+    The data is written to a temporary sibling, flushed to disk, and renamed
+    over *path*; the directory is then synced so the rename survives a crash.
+    """
+    path.parent.mkdir(parents=True, exist_ok=True)
+    fd, tmp = tempfile.mkstemp(
+        dir=path.parent, prefix=f".{path.name}.", suffix=TEMP_SUFFIX
+    )
+    try:
+        # Fixed encoding and line endings keep files identical across OSes.
+        with os.fdopen(fd, "w", encoding="utf-8", newline="\n") as f:
+            f.write(text)
+            f.flush()
+            os.fsync(f.fileno())
+        os.replace(tmp, path)
+    except BaseException:
+        Path(tmp).unlink(missing_ok=True)
+        raise
+    _fsync_dir(path.parent)
+
+
+def _fsync_dir(directory: Path) -> None:
+    # Windows cannot open directories; NTFS renames are journaled anyway.
+    if os.name == "nt":
+        return
+    fd = os.open(directory, os.O_RDONLY)
+    try:
+        os.fsync(fd)
+    finally:
+        os.close(fd)
+
+
+class ProjectStorage:
+    """Manages a single project stored as JSON files in a local git repo.
+
+    Directory layout::
+
+        {project_path}/
+          .git/
+          meta.json
+          labels.json
+          milestones.json
+          issues/
+            1.json
+            2.json
+          comments/
+            1/
+              1.json
+              2.json
+          maps/
+            {name}.json
+          mirror/
+            {number}.json
+          .attractor/
+            settings.json
+    """
+
+    def __init__(
+        self,
+        project_path: Path,
+        author: tuple[str, str] | None = None,
+        read_only: bool = False,
+        branch: str | None = None,
+        sync_freshness: float = 0.0,
+        actor: str | None = None,
+        store_id: str | None = None,
+        user: str | None = None,
+        owner: str | None = None,
+    ) -> None:
+        self.path = project_path
+        self.author = author  # (name, email); git's own config is used when None
+        self.branch = branch  # None keeps whatever branch the store has checked out
+        # sync() is a no-op within this many seconds of the last pull.
+        self.sync_freshness = sync_freshness
+        # The person behind commits authored as the bot, kept as a trailer.
+        self.actor = actor
+        # Pushes are refused when origin's manifest names another store.
+        self.store_id = store_id
+        # Who is writing and who owns the remote; None for local-only stores.
+        self.user = user
+        self.owner = owner
+        # Every write starts with sync(), so refusing there keeps the tree clean.
+        self.read_only = read_only
+        self.issues_dir = project_path / "issues"
+        self.comments_dir = project_path / "comments"
+        self.mirror_dir = project_path / "mirror"
+
+    # ------------------------------------------------------------------
+    # Git helpers
+    # ------------------------------------------------------------------
+
+    def _git(
+        self,
+        *args: str,
+        check: bool = True,
+        input: str | None = None,
+        env: dict[str, str] | None = None,
+    ) -> subprocess.CompletedProcess[str]:
+        with span(logger, f"git {args[0]}", store=self.path):
+            return subprocess.run(
+                ["git", *args],
+                cwd=self.path,
+                capture_output=True,
+                text=True,
+                check=check,
+                input=input,
+                env={**os.environ, **env} if env else None,
+            )
+
+    def _identity(self) -> list[str]:
+        if self.author is None:
+            return []
+        name, email = self.author
+        return ["-c", f"user.name={name}", "-c", f"user.email={email}"]
+
+    # ------------------------------------------------------------------
+    # JSON helpers
+    # ------------------------------------------------------------------
+
+    def _write_json(self, path: Path, data: object) -> None:
+        write_text_atomic(path, json.dumps(data, indent=2, default=str) + "\n")
+
+    def _read_json(self, path: Path) -> object:
+        return json.loads(path.read_text(encoding="utf-8"))
+
+    # ------------------------------------------------------------------
+    # Project lifecycle
+    # ------------------------------------------------------------------
+
+    def init(self) -> None:
+        self.path.mkdir(parents=True, exist_ok=True)
+        self._git("init")
+        self.issues_dir.mkdir(exist_ok=True)
+        self.comments_dir.mkdir(exist_ok=True)
+        self._write_json(
+            self.path / "meta.json",
+            {"next_issue_id": 1, "next_comment_id": 1},
+        )
+        self._write_json(self.path / "labels.json", [])
+        self._git("add", "-A", "--", ".", _EXCLUDE_TEMP)
+        self._git(
+            "commit", "-m", "Initialize project", "-m", f"{TRAILER_EVENT}: store.init"
+        )
+
+    def exists(self) -> bool:
+        return (self.path / ".git").is_dir()
+
+    # ------------------------------------------------------------------
+    # Meta
+    # ------------------------------------------------------------------
+
+    def read_meta(self) -> Meta:
+        path = self.path / "meta.json"
+        if not path.exists():
+            return Meta()
+        return Meta.model_validate(self._read_json(path))
+
+    def write_meta(self, meta: Meta) -> None:
+        self._write_json(self.path / "meta.json", meta.model_dump(mode="json"))
+
+    def next_issue_id(self) -> int:
+        meta = self.read_meta()
+        current = meta.next_issue_id
+        meta.next_issue_id = current + 1
+        self.write_meta(meta)
+        return current
+
+    def reserve_issue_id(self, attempts: int = 5) -> int:
+        """Take the next issue number by committing and pushing the bump alone.
+
+        A rejected push means another machine took a number first: the bump
+        is dropped, the store pulls, and it tries again. Without a remote this
+        is :meth:`next_issue_id`. Call before writing anything else.
+
+        Raises:
+            IdReservationError: Every attempt lost the race.
+        """
+        if not self._has_remote():
+            return self.next_issue_id()
+        self._check_remote_store_id()
+        for _ in range(attempts):
+            self.sync(force=True)
+            number = self.next_issue_id()
+            # Two machines bumping to the same number in the same second would
+            # otherwise make identical commits, and both pushes would succeed.
+            self.commit(
+                f"Reserve issue #{number}",
+                event="issue.reserve",
+                issue=number,
+                extra_trailers={TRAILER_RESERVATION: new_ulid()},
+            )
+            try:
+                self._run_remote(["push", "-u", "origin", "HEAD"])
+                return number
+            except subprocess.CalledProcessError as exc:
+                if not _PUSH_REJECTED.search(exc.stderr or ""):
+                    raise
+                self._git("reset", "--hard", "HEAD~1")
+        raise IdReservationError(
+            f"Could not reserve an issue number after {attempts} attempts; "
+            "other machines are creating issues. Try again."
+        )
+
+    def next_comment_id(self) -> int:
+        meta = self.read_meta()
+        current = meta.next_comment_id
+        meta.next_comment_id = current + 1
+        self.write_meta(meta)
+        return current
+
+    # ------------------------------------------------------------------
+    # Issues
+    # ------------------------------------------------------------------
+
+    def read_issue(self, number: int) -> Issue | None:
+        path = self.issues_dir / f"{number}.json"
+        if not path.exists():
+            return None
+        return Issue.model_validate(self._read_json(path))
+
+    def write_issue(self, issue: Issue) -> None:
+        issue.mentions = parse_mentions(
+            " ".join(
+                [issue.body or ""]
+                + [c.body for c in self._read_comments(issue.number)]
+            )
+        )
+        issue.version += 1
+        path = self.issues_dir / f"{issue.number}.json"
+        self._write_json(path, issue.model_dump(mode="json"))
+
+    def check_unlocked(self, issue: Issue) -> None:
+        """Refuse user edits to a locked issue unless its author or the owner is writing.
+
+        Sync and import writes skip this, since they replay changes already made.
+        """
+        if not issue.locked or self.user is None:
+            return
+        if self.user not in (issue.user.login, self.owner):
+            raise IssueLockedError(issue.number, issue.active_lock_reason)
+
+    def _refresh_mentions(self, issue_number: int) -> None:
+        issue = self.read_issue(issue_number)
+        if issue is not None:
+            self.write_issue(issue)
+
+    def _load_issues(
+        self, filters: IssueFilters
+    ) -> tuple[list[Issue], list[str]]:
+        """Parse the issue files that can match *filters*' state and labels.
+
+        Files are read on a thread pool, since cold reads wait on the disk.
+        One whose text lacks the wanted state or a required label name is
+        skipped without being parsed. Files that do not parse are quarantined
+        and reported as warnings.
+        """
+        if not self.issues_dir.exists():
+            return [], []
+        needles: list[re.Pattern[str]] = []
+        if filters.state and filters.state != "all":
+            state = re.escape(json.dumps(filters.state))
+            needles.append(re.compile(rf'"state":\s*{state}'))
+        if filters.labels:
+            needles += [
+                re.compile(re.escape(json.dumps(name.strip())))
+                for name in filters.labels.split(",")
+            ]
+
+        paths = list(self.issues_dir.glob("*.json"))
+        with ThreadPoolExecutor(max_workers=_LOAD_WORKERS) as pool:
+            contents = pool.map(_read_bytes, paths)
+            issues: list[Issue] = []
+            warnings: list[str] = []
+            for path, data in zip(paths, contents):
+                try:
+                    if isinstance(data, OSError):
+                        raise data
+                    text = data.decode("utf-8")
+                except FileNotFoundError:
+                    # Deleted since the directory was listed.
+                    continue
+                except (OSError, UnicodeDecodeError) as exc:
+                    warnings.append(self._quarantine(path, exc))
+                    continue
+                if not all(needle.search(text) for needle in needles):
+                    continue
+                try:
+                    issues.append(Issue.model_validate_json(text))
+                except ValidationError as exc:
+                    warnings.append(self._quarantine(path, exc))
+            return issues, warnings
+
+    def _quarantine(self, path: Path, error: Exception) -> str:
+        """Move a corrupt store file under ``.attractor/quarantine/``; a warning.
+
+        Read-only stores keep the file in place so their tree stays clean.
+        """
+        relative = path.relative_to(self.path)
+        logger.warning("Unreadable store file %s: %s", relative, error)
+        if self.read_only:
+            return f"{_git_path(relative)} could not be read and was skipped"
+        target = self.path / QUARANTINE_DIR / relative
+        target.parent.mkdir(parents=True, exist_ok=True)
+        os.replace(path, target)
+        return (
+            f"{_git_path(relative)} could not be read and was moved to "
+            f"{_git_path(target.relative_to(self.path))}"
+        )
+
+    def _matching_issues(
+        self, filters: IssueFilters
+    ) -> tuple[list[Issue], list[str]]:
+        """Issues matching *filters*, sorted but not paginated, and load warnings."""
+        issues, warnings = self._load_issues(filters)
+
+        # State filter
+        if filters.state and filters.state != "all":
+            issues = [i for i in issues if i.state == filters.state]
+
+        if filters.reason is not None:
+            issues = [i for i in issues if i.state_reason == filters.reason]
+
+        # Labels filter (AND logic)
+        if filters.labels:
+            required = {name.strip() for name in filters.labels.split(",")}
+            issues = [
+                i for i in issues if required <= {label.name for label in i.labels}
+            ]
+
+        # Assignee filter
+        if filters.assignee is not None:
+            if filters.assignee == "none":
+                issues = [i for i in issues if not i.assignees]
+            elif filters.assignee == "*":
+                issues = [i for i in issues if i.assignees]
+            else:
+                login = filters.assignee
+                issues = [
+                    i for i in issues if any(a.login == login for a in i.assignees)
+                ]
+
+        if filters.mentioned is not None:
+            login = filters.mentioned.lower()
+            issues = [i for i in issues if login in {m.lower() for m in i.mentions}]
+
+        sort_issues(issues, filters)
+        return issues, warnings
+
+    def list_issues(self, filters: IssueFilters) -> ListResponse[Issue]:
+        issues, warnings = self._matching_issues(filters)
+        total_count = len(issues)
+
+        # Paginate
+        per_page = min(filters.per_page, 100)
+        page = max(filters.page, 1)
+        offset = (page - 1) * per_page
+        items = issues[offset : offset + per_page]
+
+        return ListResponse(
+            items=items,
+            total_count=total_count,
+            page=page,
+            per_page=per_page,
+            warnings=warnings,
+        )
+
+    def all_issues(self, filters: IssueFilters) -> list[Issue]:
+        """Every issue matching *filters*, across all pages."""
+        return self._matching_issues(filters)[0]
+
+    def verify(self) -> VerifyStoreResult:
+        """Quarantine unreadable issue files, then restore what history allows.
+
+        A quarantined issue is restored from its newest committed version that
+        still parses, unless a readable file has since taken its place. The
+        caller commits.
+        """
+        _, warnings = self._load_issues(IssueFilters(state="all"))
+        result = VerifyStoreResult(quarantined=warnings)
+        quarantine = self.path / QUARANTINE_DIR / "issues"
+        if not quarantine.exists():
+            return result
+        for path in sorted(quarantine.glob("*.json")):
+            relative = _git_path(path.relative_to(self.path))
+            if not path.stem.isdigit():
+                result.unrecoverable.append(relative)
+                continue
+            number = int(path.stem)
+            if (self.issues_dir / path.name).exists():
+                result.unrecoverable.append(relative)
+                continue
+            issue = self._last_good_issue(number)
+            if issue is None:
+                result.unrecoverable.append(relative)
+                continue
+            self._write_json(self.issues_dir / path.name, issue.model_dump(mode="json"))
+            path.unlink()
+            result.recovered.append(number)
+        return result
+
+    def _last_good_issue(self, number: int) -> Issue | None:
+        path = f"issues/{number}.json"
+        revs = self._git("rev-list", "HEAD", "--", path, check=False).stdout.split()
+        for rev in revs:
+            result = self._git("show", f"{rev}:{path}", check=False)
+            if result.returncode != 0:
+                continue
+            try:
+                return Issue.model_validate_json(result.stdout)
+            except ValidationError:
+                continue
+        return None
+
+    def delete_issue(self, number: int) -> None:
+        issue_path = self.issues_dir / f"{number}.json"
+        if issue_path.exists():
+            issue_path.unlink()
+        comments_path = self.comments_dir / str(number)
+        if comments_path.exists():
+            for f in comments_path.iterdir():
+                f.unlink()
+            comments_path.rmdir()
+
+    # ------------------------------------------------------------------
+    # Comments
+    # ------------------------------------------------------------------
+
+    def read_comment(self, issue_number: int, comment_id: int) -> Comment | None:
+        path = self.comments_dir / str(issue_number) / f"{comment_id}.json"
+        if not path.exists():
+            return None
+        return Comment.model_validate(self._read_json(path))
+
+    def write_comment(self, issue_number: int, comment: Comment) -> None:
+        comment.mentions = parse_mentions(comment.body)
+        path = self.comments_dir / str(issue_number) / f"{comment.id}.json"
+        if comment.node_id is None and not path.exists():
+            comment.node_id = new_ulid()
+        self._write_json(path, comment.model_dump(mode="json"))
+        self._refresh_mentions(issue_number)
+
+    def _read_comments(self, issue_number: int) -> list[Comment]:
+        comment_dir = self.comments_dir / str(issue_number)
+        if not comment_dir.exists():
+            return []
+        return [
+            Comment.model_validate(self._read_json(path))
+            for path in comment_dir.glob("*.json")
+        ]
+
+    def list_comments(
+        self, issue_number: int, page: int = 1, per_page: int = 30
+    ) -> ListResponse[Comment]:
+        comments = self._read_comments(issue_number)
+
+        comments.sort(key=comment_sort_key)
+        total_count = len(comments)
+
+        per_page = min(per_page, 100)
+        page = max(page, 1)
+        offset = (page - 1) * per_page
+        items = comments[offset : offset + per_page]
+
+        return ListResponse(
+            items=items,
+            total_count=total_count,
+            page=page,
+            per_page=per_page,
+        )
+
+    def all_comments(self, issue_number: int) -> list[Comment]:
+        comments: list[Comment] = []
+        page = 1
+        while True:
+            batch = self.list_comments(issue_number, page, 100)
+            comments.extend(batch.items)
+            if len(comments) >= batch.total_count:
+                return comments
+            page += 1
+
+    def find_comment(self, comment_id: int) -> tuple[int, Comment] | None:
+        if not self.comments_dir.exists():
+            return None
+        for issue_dir in self.comments_dir.iterdir():
+            if not issue_dir.is_dir():
+                continue
+            path = issue_dir / f"{comment_id}.json"
+            if path.exists():
+                comment = Comment.model_validate(self._read_json(path))
+                return int(issue_dir.name), comment
+        return None
+
+    def delete_comment(self, issue_number: int, comment_id: int) -> None:
+        path = self.comments_dir / str(issue_number) / f"{comment_id}.json"
+        if path.exists():
+            path.unlink()
+            self._refresh_mentions(issue_number)
+
+    # ------------------------------------------------------------------
+    # Labels
+    # ------------------------------------------------------------------
+
+    def read_labels(self) -> list[Label]:
+        path = self.path / "labels.json"
+        if not path.exists():
+            return []
+        data = self._read_json(path)
+        return [Label.model_validate(item) for item in data]  # type: ignore[union-attr]
+
+    def write_labels(self, labels: list[Label]) -> None:
+        self._write_json(
+            self.path / "labels.json",
+            [label.model_dump(mode="json") for label in labels],
+        )
+
+    # ------------------------------------------------------------------
+    # Milestones
+    # ------------------------------------------------------------------
+
+    def read_milestones(self) -> list[Milestone]:
+        path = self.path / "milestones.json"
+        if not path.exists():
+            return []
+        data = self._read_json(path)
+        return [Milestone.model_validate(item) for item in data]  # type: ignore[union-attr]
+
+    def list_milestones(
+        self, due_within: timedelta | None = None, overdue: bool | None = None
+    ) -> list[MilestoneListItem]:
+        """Milestones with ``is_overdue`` worked out, optionally filtered.
+
+        *due_within* keeps open milestones due no later than that from now,
+        overdue ones included; *overdue* keeps only overdue or only other ones.
+        Naive due dates are taken as UTC.
+        """
+        now = datetime.now(timezone.utc)
+        items: list[MilestoneListItem] = []
+        for milestone in self.read_milestones():
+            due = milestone.due_on
+            if due is not None and due.tzinfo is None:
+                due = due.replace(tzinfo=timezone.utc)
+            is_open = milestone.state == "open"
+            item = MilestoneListItem(
+                **milestone.model_dump(exclude={"due_on"}),
+                due_on=due,
+                is_overdue=is_open and due is not None and due < now,
+            )
+            if due_within is not None and (
+                not is_open or due is None or due - now > due_within
+            ):
+                continue
+            if overdue is not None and item.is_overdue != overdue:
+                continue
+            items.append(item)
+        return items
+
+    def write_milestones(self, milestones: list[Milestone]) -> None:
+        self._write_json(
+            self.path / "milestones.json",
+            [m.model_dump(mode="json") for m in milestones],
+        )
+
+    # ------------------------------------------------------------------
+    # Project settings
+    # ------------------------------------------------------------------
+
+    def read_settings(self) -> ProjectSettings:
+        path = self.path / ".attractor" / "settings.json"
+        if not path.exists():
+            return ProjectSettings()
+        return ProjectSettings.model_validate(self._read_json(path))
+
+    def write_settings(self, settings: ProjectSettings) -> None:
+        self._write_json(
+            self.path / ".attractor" / "settings.json",
+            settings.model_dump(mode="json"),
+        )
+
+    # ------------------------------------------------------------------
+    # ID maps
+    # ------------------------------------------------------------------
+
+    def read_id_map(self, name: str) -> dict[str, dict[str, int]]:
+        """Read a named mapping of external IDs to local IDs (``maps/{name}.json``)."""
+        path = self.path / "maps" / f"{name}.json"
+        if not path.exists():
+            return {}
+        return self._read_json(path)  # type: ignore[return-value]
+
+    def write_id_map(self, name: str, data: dict[str, dict[str, int]]) -> None:
+        self._write_json(self.path / "maps" / f"{name}.json", data)
+
+    # ------------------------------------------------------------------
+    # Mirrors
+    # ------------------------------------------------------------------
+
+    def read_mirror(self, number: int) -> IssueMirror | None:
+        path = self.mirror_dir / f"{number}.json"
+        if not path.exists():
+            return None
+        return IssueMirror.model_validate(self._read_json(path))
+
+    def write_mirror(self, number: int, mirror: IssueMirror) -> None:
+        self._write_json(self.mirror_dir / f"{number}.json", mirror.model_dump(mode="json"))
+
+    def delete_mirror(self, number: int) -> None:
+        (self.mirror_dir / f"{number}.json").unlink(missing_ok=True)
+
+    def list_mirrored_issues(self) -> list[int]:
+        if not self.mirror_dir.exists():
+            return []
+        return sorted(int(p.stem) for p in self.mirror_dir.glob("*.json"))
+
+    # ------------------------------------------------------------------
+    # Activity
+    # ------------------------------------------------------------------
+
+    def head(self) -> str | None:
+        result = self._git("rev-parse", "--verify", "--quiet", "HEAD", check=False)
+        return result.stdout.strip() if result.returncode == 0 else None
+
+    def author_email(self) -> str | None:
+        """Email that store commits made here are authored with."""
+        if self.author is not None:
+            return self.author[1]
+        result = self._git("config", "user.email", check=False)
+        return result.stdout.strip() or None
+
+    def commits_since(self, since: str) -> list[StoreCommit] | None:
+        """Commits after *since* up to HEAD, oldest first.
+
+        Returns None when *since* is not in the store's history.
+        """
+        trailer = "%(trailers:key={},valueonly,separator=%x2C)"
+        event_trailer = trailer.format(TRAILER_EVENT)
+        actor_trailer = trailer.format(TRAILER_ACTOR)
+        result = self._git(
+            "log",
+            "--reverse",
+            "--name-only",
+            f"--format=%x1e%H%x1f%ae%x1f{event_trailer}%x1f{actor_trailer}",
+            f"{since}..HEAD",
+            check=False,
+        )
+        if result.returncode != 0:
+            return None
+        commits: list[StoreCommit] = []
+        for record in result.stdout.split("\x1e")[1:]:
+            header, _, files = record.partition("\n")
+            sha, email, event, actor = header.split("\x1f", 3)
+            commits.append(
+                StoreCommit(
+                    sha=sha,
+                    author_email=email,
+                    event=event or None,
+                    actor=actor or None,
+                    files=[f for f in files.splitlines() if f],
+                )
+            )
+        return commits
+
+    def read_json_at(self, rev: str, path: str | Path) -> object | None:
+        """Parse *path* as it was at *rev*, or None if it did not exist."""
+        result = self._git("show", f"{rev}:{_git_path(path)}", check=False)
+        if result.returncode != 0:
+            return None
+        return json.loads(result.stdout)
+
+    def list_activity(
+        self,
+        since: datetime | None = None,
+        page: int = 1,
+        per_page: int = 30,
+    ) -> ListResponse[ActivityEvent]:
+        """Return store commits as activity events, newest first."""
+        trailer_fmt = "%(trailers:key={},valueonly,separator=%x2C)"
+        fields = [
+            "%H",
+            "%an",
+            "%aI",
+            trailer_fmt.format(TRAILER_EVENT),
+            trailer_fmt.format(TRAILER_ISSUE),
+            trailer_fmt.format(TRAILER_ACTOR),
+            "%s",
+        ]
+        args = ["log", f"--format={'%x1f'.join(fields)}"]
+        if since is not None:
+            args.append(f"--since={since.isoformat()}")
+        result = self._git(*args, check=False)
+
+        events: list[ActivityEvent] = []
+        for line in result.stdout.splitlines():
+            sha, author, timestamp, event, issue, actor, subject = line.split("\x1f", 6)
+            # Commits made before trailers existed are classified by subject.
+            action, issue_number = _classify_commit(subject)
+            if event:
+                action = event
+                issue_number = int(issue) if issue.isdigit() else None
+            events.append(
+                ActivityEvent(
+                    sha=sha,
+                    actor=actor or author,
+                    action=action,
+                    issue_number=issue_number,
+                    summary=subject,
+                    timestamp=datetime.fromisoformat(timestamp),
+                )
+            )
+
+        total_count = len(events)
+        per_page = min(per_page, 100)
+        page = max(page, 1)
+        offset = (page - 1) * per_page
+        items = events[offset : offset + per_page]
+
+        return ListResponse(
+            items=items,
+            total_count=total_count,
+            page=page,
+            per_page=per_page,
+        )
+
+    # ------------------------------------------------------------------
+    # Git operations
+    # ------------------------------------------------------------------
+
+    def commit(
+        self,
+        message: str,
+        event: str | None = None,
+        issue: int | None = None,
+        extra_trailers: dict[str, str] | None = None,
+    ) -> None:
+        """Commit all changes, recording *event*, *issue* and any extras as trailers."""
+        self._git("add", "-A", "--", ".", _EXCLUDE_TEMP)
+        result = self._git("diff", "--cached", "--quiet", check=False)
+        if result.returncode != 0:
+            trailers = []
+            if event is not None:
+                trailers.append(f"{TRAILER_EVENT}: {event}")
+            if issue is not None:
+                trailers.append(f"{TRAILER_ISSUE}: {issue}")
+            if self.actor is not None:
+                trailers.append(f"{TRAILER_ACTOR}: {self.actor}")
+            for key, value in (extra_trailers or {}).items():
+                trailers.append(f"{key}: {value}")
+            body = ["-m", "\n".join(trailers)] if trailers else []
+            message = self._commit_message(message, event, issue)
+            try:
+                self._git(*self._identity(), "commit", "-m", message, *body)
+            except subprocess.CalledProcessError as exc:
+                if _SIGNING_FAILED.search(exc.stderr or ""):
+                    raise CommitSigningError(
+                        "Could not sign the store commit. Check that the signing key "
+                        "is available without a passphrase prompt (e.g. loaded in "
+                        f"an agent): {exc.stderr.strip()}"
+                    ) from exc
+                raise
+
+    def _commit_message(
+        self, summary: str, event: str | None, issue: int | None
+    ) -> str:
+        """*summary* formatted by the project's ``commit_template``, if it has one."""
+        template = self.read_settings().commit_template
+        if template is None:
+            return summary
+        actor = ""
+        if "{actor}" in template:
+            if self.author is not None:
+                actor = self.author[0]
+            else:
+                actor = self._git("config", "user.name", check=False).stdout.strip()
+        message = template.format(
+            summary=summary,
+            action=event or "",
+            number="" if issue is None else issue,
+            actor=actor,
+        )
+        return message.strip() or summary
+
+    def _has_remote(self) -> bool:
+        result = self._git("remote", "get-url", "origin", check=False)
+        return result.returncode == 0
+
+    def _run_remote(self, args: list[str]) -> None:
+        try:
+            run_git_network(args, cwd=self.path)
+        except subprocess.CalledProcessError as exc:
+            if _REMOTE_MISSING.search(exc.stderr or ""):
+                raise StoreMissingError(
+                    "The store's remote repository was not found. It may have been "
+                    "deleted or your token may have lost access."
+                ) from exc
+            raise
+
+    def sync(self, force: bool = False) -> None:
+        """Pull from origin, merging in issues and comments created offline.
+
+        Skipped when the store pulled within ``sync_freshness`` seconds,
+        unless *force* is set; a push that is then rejected pulls and retries.
+
+        When both sides allocated IDs since they last agreed, the local ones
+        are renumbered after the remote's before merging. Issues edited on
+        both sides are merged field by field under the project's merge
+        policies. Any other conflict is raised as the original pull error.
+        """
+        if self.read_only:
+            raise ReadOnlyStoreError(
+                "This project is read-only until an account for its remote is added"
+            )
+        key = self.path.resolve()
+        last = _last_synced.get(key)
+        if not force and last is not None:
+            if time.monotonic() - last < self.sync_freshness:
+                return
+        if not self.use_branch():
+            return
+        try:
+            self._run_remote(["pull", "--ff-only"])
+        except subprocess.CalledProcessError as exc:
+            if not is_diverged(exc) or not self._merge_upstream():
+                raise
+        _last_synced[key] = time.monotonic()
+
+    def use_branch(self) -> bool:
+        """Check out the configured branch; True if it has an upstream to pull.
+
+        A branch that origin does not have yet is created from the current
+        HEAD and published by the next push.
+
+        Raises:
+            StoreBranchError: The configured name is not a valid branch name.
+        """
+        has_remote = self._has_remote()
+        if self.branch is None:
+            return has_remote
+        branch = self.branch
+        valid = self._git("check-ref-format", "--branch", branch, check=False)
+        if valid.returncode != 0:
+            raise StoreBranchError(f"'{branch}' is not a valid branch name")
+        if has_remote:
+            self._run_remote(["fetch", "origin"])
+        on_origin = self._ref_exists(f"refs/remotes/origin/{branch}")
+        current = self._git("symbolic-ref", "--short", "-q", "HEAD", check=False)
+        if current.stdout.strip() != branch:
+            if self._ref_exists(f"refs/heads/{branch}"):
+                self._git("checkout", branch)
+            elif on_origin:
+                self._git("checkout", "-b", branch, "--track", f"origin/{branch}")
+            else:
+                self._git("checkout", "-b", branch)
+        if on_origin:
+            self._git("branch", f"--set-upstream-to=origin/{branch}", branch)
+        return on_origin
+
+    def _ref_exists(self, ref: str) -> bool:
+        result = self._git("rev-parse", "--verify", "--quiet", ref, check=False)
+        return result.returncode == 0
+
+    def _merge_upstream(self) -> bool:
+        base = self._git("merge-base", "HEAD", "@{upstream}", check=False)
+        if base.returncode != 0:
+            return False
+        base_meta = Meta.model_validate(
+            self.read_json_at(base.stdout.strip(), "meta.json") or {}
+        )
+        remote_meta = Meta.model_validate(
+            self.read_json_at("@{upstream}", "meta.json") or {}
+        )
+        issue_shift = max(0, remote_meta.next_issue_id - base_meta.next_issue_id)
+        comment_shift = max(0, remote_meta.next_comment_id - base_meta.next_comment_id)
+        if issue_shift or comment_shift:
+            self._renumber_since(base_meta, issue_shift, comment_shift)
+            self.commit("Renumber issues created offline", event="store.renumber")
+
+        merge = self._git(
+            *self._identity(), "merge", "--no-edit", "@{upstream}", check=False
+        )
+        if merge.returncode == 0:
+            return True
+        conflicts = self._git("diff", "--name-only", "--diff-filter=U").stdout.split()
+        issues: list[tuple[int, Issue | None, Issue, Issue]] = []
+        for path in conflicts:
+            if path == "meta.json":
+                continue
+            match = _ISSUE_FILE.fullmatch(path)
+            ours_data = self.read_json_at(":2", path)
+            theirs_data = self.read_json_at(":3", path)
+            if match is None or ours_data is None or theirs_data is None:
+                self._git("merge", "--abort", check=False)
+                return False
+            base_data = self.read_json_at(":1", path)
+            issues.append(
+                (
+                    int(match.group(1)),
+                    Issue.model_validate(base_data) if base_data else None,
+                    Issue.model_validate(ours_data),
+                    Issue.model_validate(theirs_data),
+                )
+            )
+
+        if "meta.json" in conflicts:
+            ours = Meta.model_validate(self.read_json_at(":2", "meta.json") or {})
+            theirs = Meta.model_validate(self.read_json_at(":3", "meta.json") or {})
+            self.write_meta(
+                Meta(
+                    next_issue_id=max(ours.next_issue_id, theirs.next_issue_id),
+                    next_comment_id=max(ours.next_comment_id, theirs.next_comment_id),
+                )
+            )
+        policies = self.read_settings().merge_policies
+        for number, base_issue, ours_issue, theirs_issue in issues:
+            merged = merge_issue(base_issue, ours_issue, theirs_issue, policies)
+            merged.comments = len(self._read_comments(number))
+            self.write_issue(merged)
+        self._git("add", "--", *conflicts)
+        self._git(*self._identity(), "commit", "--no-edit")
+        return True
+
+    def _renumber_since(self, base: Meta, issue_shift: int, comment_shift: int) -> None:
+        """Move IDs allocated locally since *base* up by the given shifts."""
+        meta = self.read_meta()
+
+        def issue_id(n: int) -> int:
+            return n + issue_shift if n >= base.next_issue_id else n
+
+        def comment_id(n: int) -> int:
+            return n + comment_shift if n >= base.next_comment_id else n
+
+        # Highest first, so a moved file never lands on one not yet moved.
+        for number in range(meta.next_issue_id - 1, base.next_issue_id - 1, -1):
+            new = issue_id(number)
+            issue = self.read_issue(number)
+            if issue is not None:
+                issue.id = issue.number = new
+                self._write_json(
+                    self.issues_dir / f"{new}.json", issue.model_dump(mode="json")
+                )
+                (self.issues_dir / f"{number}.json").unlink()
+            comment_dir = self.comments_dir / str(number)
+            if comment_dir.is_dir():
+                comment_dir.rename(self.comments_dir / str(new))
+            mirror_path = self.mirror_dir / f"{number}.json"
+            if mirror_path.exists():
+                mirror_path.rename(self.mirror_dir / f"{new}.json")
+
+        moved = [
+            path
+            for path in self.comments_dir.glob("*/*.json")
+            if int(path.stem) >= base.next_comment_id
+        ]
+        for path in sorted(moved, key=lambda p: int(p.stem), reverse=True):
+            comment = Comment.model_validate(self._read_json(path))
+            comment.id = comment_id(comment.id)
+            self._write_json(
+                path.with_name(f"{comment.id}.json"), comment.model_dump(mode="json")
+            )
+            path.unlink()
+
+        for number in self.list_mirrored_issues():
+            mirror = self.read_mirror(number)
+            if mirror is not None and mirror.comments:
+                mirror.comments = {
+                    str(comment_id(int(local))): remote
+                    for local, remote in mirror.comments.items()
+                }
+                self.write_mirror(number, mirror)
+
+        for map_path in (self.path / "maps").glob("*.json"):
+            id_map = self.read_id_map(map_path.stem)
+            for kind, ids in id_map.items():
+                shift_id = issue_id if kind == "issues" else comment_id
+                if map_path.stem.startswith(_LOCAL_KEYED_MAPS):
+                    id_map[kind] = {str(shift_id(int(k))): v for k, v in ids.items()}
+                else:
+                    id_map[kind] = {k: shift_id(v) for k, v in ids.items()}
+            self.write_id_map(map_path.stem, id_map)
+
+        meta.next_issue_id += issue_shift
+        meta.next_comment_id += comment_shift
+        self.write_meta(meta)
+
+    def push(self) -> None:
+        if not self._has_remote():
+            return
+        self._check_remote_store_id()
+        try:
+            self._run_remote(["push", "-u", "origin", "HEAD"])
+        except subprocess.CalledProcessError as exc:
+            if not _PUSH_REJECTED.search(exc.stderr or ""):
+                raise
+            self.sync(force=True)
+            self._run_remote(["push", "-u", "origin", "HEAD"])
+
+    def _check_remote_store_id(self) -> None:
+        """Raise if origin holds a different store, e.g. after it was re-pointed.
+
+        A remote with nothing to fetch yet, or without a manifest, passes. Once
+        a URL passes it is not fetched again until origin is re-pointed.
+        """
+        if self.store_id is None:
+            return
+        key = self.path.resolve()
+        url = self._git("remote", "get-url", "origin", check=False).stdout.strip()
+        if _verified_remotes.get(key) == url:
+            return
+        current = self._git("symbolic-ref", "--short", "-q", "HEAD", check=False)
+        for ref in (current.stdout.strip(), "HEAD"):
+            if not ref:
+                continue
+            try:
+                self._run_remote(["fetch", "origin", ref])
+            except subprocess.CalledProcessError:
+                continue
+            manifest = self.read_json_at("FETCH_HEAD", ".attractor-store.json")
+            remote_id = manifest.get("store_id") if isinstance(manifest, dict) else None
+            if remote_id is not None and remote_id != self.store_id:
+                raise StoreIdMismatchError(
+                    f"The remote holds store {remote_id}, not this project's "
+                    f"{self.store_id}. Check where origin points before pushing."
+                )
+            _verified_remotes[key] = url
+            return
+
+    def write_branch(self, branch: str, files: dict[str, str], message: str) -> bool:
+        """Commit *files* as the entire tree of *branch*, leaving the worktree alone.
+
+        The branch gets a new commit on top of its previous tip. Returns False
+        when the content is unchanged and nothing was committed.
+        """
+        ref = f"refs/heads/{branch}"
+        parent = self._git("rev-parse", "--verify", "--quiet", ref, check=False)
+        parent_sha = parent.stdout.strip() if parent.returncode == 0 else None
+
+        with tempfile.TemporaryDirectory() as tmp:
+            index = {"GIT_INDEX_FILE": str(Path(tmp) / "index")}
+            for path, content in sorted(files.items()):
+                blob = self._git("hash-object", "-w", "--stdin", input=content)
+                self._git(
+                    "update-index",
+                    "--add",
+                    "--cacheinfo",
+                    f"100644,{blob.stdout.strip()},{_git_path(path)}",
+                    env=index,
+                )
+            tree = self._git("write-tree", env=index).stdout.strip()
+
+        if parent_sha is not None:
+            parent_tree = self._git("rev-parse", f"{parent_sha}^{{tree}}").stdout.strip()
+            if parent_tree == tree:
+                return False
+        parents = ["-p", parent_sha] if parent_sha else []
+        try:
+            commit = self._git(
+                *self._identity(), "commit-tree", tree, *parents, "-m", message
+            )
+        except subprocess.CalledProcessError as exc:
+            if _SIGNING_FAILED.search(exc.stderr or ""):
+                raise CommitSigningError(
+                    f"Could not sign the {branch} commit: {exc.stderr.strip()}"
+                ) from exc
+            raise
+        self._git("update-ref", ref, commit.stdout.strip())
+        return True
+
+    def push_branch(self, branch: str) -> bool:
+        """Force-push *branch* to origin. Returns False when there is no remote.
+
+        Only used for generated branches, which are rebuilt from the store.
+        """
+        if not self._has_remote():
+            return False
+        self._run_remote(["push", "origin", f"+refs/heads/{branch}:refs/heads/{branch}"])
+        return True
+
+    def set_remote(self, url: str) -> None:
+        """Set or update the git remote 'origin' URL."""
+        if self._has_remote():
+            self._git("remote", "set-url", "origin", url)
+        else:
+            self._git("remote", "add", "origin", url)
+
+    def set_ssh_command(self, command: str | None) -> None:
+        """Set ``core.sshCommand`` for this store, or unset it when None."""
+        if command is not None:
+            self._git("config", "core.sshCommand", command)
+        else:
+            self._git("config", "--unset", "core.sshCommand", check=False)
+
+    def set_signing(self, signing: CommitSigning | None) -> None:
+        """Sign every store commit with *signing*, or stop signing when None."""
+        if signing is None:
+            for key in ("commit.gpgSign", "gpg.format", "user.signingKey"):
+                self._git("config", "--unset", key, check=False)
+            return
+        self._git("config", "gpg.format", "openpgp" if signing.format == "gpg" else "ssh")
+        self._git("config", "user.signingKey", signing.key)
+        self._git("config", "commit.gpgSign", "true")
@@ -7,7 +7,14 @@ Storage and Amplifier dependencies will be added in later modules.
 from functools import lru_cache
 
 from .amplifier import AmplifierManager
-from .config import Settings
+from .core.config import Settings
+from .device_login import DeviceLoginManager
+from .frontend import UpdateScheduler
+from .github_issues import MirrorScheduler
+from .notifications import NotificationCenter, NotificationScheduler
+from .open_projects import OpenProjectRegistry
+from .pull_requests import PullRequestScheduler
+from .store_sync import StoreSyncScheduler
 from .ws import WebSocketManager
 
 
@@ -33,3 +40,72 @@ _amplifier_manager = AmplifierManager()
 def get_amplifier_manager() -> AmplifierManager:
     """Return the shared Amplifier session manager instance."""
     return _amplifier_manager
+
+
+# Module-level singleton -- one device login flow shared across the application.
+_device_login_manager = DeviceLoginManager()
+
+
+def get_device_login_manager() -> DeviceLoginManager:
+    """Return the shared GitHub device login manager instance."""
+    return _device_login_manager
+
+
+# Module-level singleton -- one mirror scheduler shared across the application.
+_mirror_scheduler = MirrorScheduler()
+
+
+def get_mirror_scheduler() -> MirrorScheduler:
+    """Return the shared GitHub Issues mirror scheduler instance."""
+    return _mirror_scheduler
+
+
+# Module-level singleton -- one linked pull request refresher.
+_pull_request_scheduler = PullRequestScheduler()
+
+
+def get_pull_request_scheduler() -> PullRequestScheduler:
+    """Return the shared linked pull request refresh scheduler instance."""
+    return _pull_request_scheduler
+
+
+# Module-level singleton -- one registry of open projects shared across the application.
+_open_projects = OpenProjectRegistry()
+
+
+def get_open_projects() -> OpenProjectRegistry:
+    """Return the shared registry of open projects."""
+    return _open_projects
+
+
+@lru_cache
+def get_notifications() -> NotificationCenter:
+    """Return the shared notification center for the configured data directory."""
+    return NotificationCenter(get_settings().data_dir)
+
+
+# Module-level singleton -- one notification scan loop shared across the application.
+_notification_scheduler = NotificationScheduler()
+
+
+def get_notification_scheduler() -> NotificationScheduler:
+    """Return the shared notification scan scheduler instance."""
+    return _notification_scheduler
+
+
+# Module-level singleton -- one automatic store sync loop shared across the application.
+_store_sync_scheduler = StoreSyncScheduler()
+
+
+def get_store_sync_scheduler() -> StoreSyncScheduler:
+    """Return the shared automatic store sync scheduler instance."""
+    return _store_sync_scheduler
+
+
+# Module-level singleton -- one frontend update check loop shared across the application.
+_update_scheduler = UpdateScheduler()
+
+
+def get_update_scheduler() -> UpdateScheduler:
+    """Return the shared frontend update check scheduler instance."""
+    return _update_scheduler
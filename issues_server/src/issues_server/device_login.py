@@ -0,0 +1,116 @@
+"""GitHub OAuth device authorization flow.
+
+Lets users sign in by entering a short code on github.com instead of
+pasting a Personal Access Token. A background task polls GitHub until the
+user approves, denies, or the code expires.
+"""
+
+import asyncio
+from collections.abc import Awaitable, Callable
+from dataclasses import dataclass, field
+from datetime import datetime, timedelta, timezone
+
+from .github_client import poll_device_token, request_device_code
+from .ws import WebSocketManager
+
+DEVICE_SCOPE = "repo read:user user:email"
+
+
+@dataclass
+class DeviceLogin:
+    """State of one in-flight device authorization."""
+
+    user_code: str
+    verification_uri: str
+    expires_at: datetime
+    interval: int
+    status: str = "pending"  # "pending", "completed", "failed", "expired"
+    user: str | None = None
+    error: str | None = None
+    task: asyncio.Task | None = field(default=None, repr=False)  # type: ignore[type-arg]
+
+
+class DeviceLoginManager:
+    """Run at most one device authorization at a time."""
+
+    def __init__(self) -> None:
+        self.current: DeviceLogin | None = None
+
+    async def start(
+        self,
+        client_id: str,
+        on_token: Callable[[str], Awaitable[str]],
+        ws_manager: WebSocketManager,
+    ) -> DeviceLogin:
+        """Request a device code and begin polling in the background.
+
+        Args:
+            on_token: Persists the granted token and returns the GitHub login.
+
+        Any previous pending flow is cancelled.
+        """
+        self.cancel()
+        data = await request_device_code(client_id, DEVICE_SCOPE)
+        login = DeviceLogin(
+            user_code=data["user_code"],
+            verification_uri=data["verification_uri"],
+            expires_at=datetime.now(timezone.utc)
+            + timedelta(seconds=int(data["expires_in"])),
+            interval=int(data.get("interval", 5)),
+        )
+        login.task = asyncio.create_task(
+            self._poll(login, client_id, data["device_code"], on_token, ws_manager)
+        )
+        self.current = login
+        return login
+
+    def cancel(self) -> bool:
+        """Stop polling for the current flow. Returns True if one was pending."""
+        login = self.current
+        if login is None or login.status != "pending" or login.task is None:
+            return False
+        login.task.cancel()
+        login.status = "failed"
+        login.error = "Cancelled"
+        return True
+
+    async def _poll(
+        self,
+        login: DeviceLogin,
+        client_id: str,
+        device_code: str,
+        on_token: Callable[[str], Awaitable[str]],
+        ws_manager: WebSocketManager,
+    ) -> None:
+        try:
+            while datetime.now(timezone.utc) < login.expires_at:
+                await asyncio.sleep(login.interval)
+                data = await poll_device_token(client_id, device_code)
+                error = data.get("error")
+                if error == "authorization_pending":
+                    continue
+                if error == "slow_down":
+                    login.interval = int(data.get("interval", login.interval + 5))
+                    continue
+                if error is not None:
+                    login.status = "failed"
+                    login.error = data.get("error_description", error)
+                    break
+                login.user = await on_token(data["access_token"])
+                login.status = "completed"
+                break
+            else:
+                login.status = "expired"
+                login.error = "The device code expired before it was approved."
+        except asyncio.CancelledError:
+            raise
+        except Exception as exc:
+            login.status = "failed"
+            login.error = str(exc)
+
+        if login.status == "completed":
+            await ws_manager.broadcast("github:login_completed", {"user": login.user})
+        else:
+            await ws_manager.broadcast(
+                "github:login_failed", {"status": login.status, "error": login.error}
+            )
@@ -1,18 +1,136 @@
 """Download and cache the frontend build from GitHub Releases."""
 
+from __future__ import annotations
+
+import asyncio
 import logging
 import shutil
 import tarfile
 import tempfile
+from collections.abc import Awaitable, Callable
 from pathlib import Path
 
 import httpx
 
-from .config import Settings
+from .core.config import Settings
+from .core.errors import AppError
+from .core.models import UpdateStatus
+from .ws import WebSocketManager
 
 logger = logging.getLogger(__name__)
 
 
+class UpdateError(AppError):
+    status_code = 502
+    code = "UPDATE_FAILED"
+
+
+def _github_headers(settings: Settings, accept: str) -> dict[str, str]:
+    headers = {"Accept": accept}
+    if settings.github_token:
+        headers["Authorization"] = f"token {settings.github_token}"
+    return headers
+
+
+def _latest_release(settings: Settings) -> tuple[str, str]:
+    """The published build's commit SHA and tarball download URL.
+
+    Raises:
+        RuntimeError: The release or its asset cannot be found.
+    """
+    api_url = (
+        f"https://api.github.com/repos/{settings.frontend_repo}"
+        f"/releases/tags/{settings.frontend_release_tag}"
+    )
+    response = httpx.get(
+        api_url,
+        headers=_github_headers(settings, "application/vnd.github+json"),
+        timeout=30,
+    )
+
+    if response.status_code == 404:
+        raise RuntimeError(
+            "Release not found. Has the GitHub Action run at least once?"
+        )
+    if response.status_code in (401, 403):
+        raise RuntimeError("Authentication required. Set ATTRACTOR_GITHUB_TOKEN.")
+    if response.status_code != 200:
+        raise RuntimeError(
+            f"GitHub API error {response.status_code}: {response.text}"
+        )
+
+    release = response.json()
+
+    # Find the matching asset
+    asset = next(
+        (
+            a
+            for a in release.get("assets", [])
+            if a["name"] == settings.frontend_asset_name
+        ),
+        None,
+    )
+    if asset is None:
+        raise RuntimeError(
+            f"Asset '{settings.frontend_asset_name}' not found in release "
+            f"'{settings.frontend_release_tag}'. "
+            f"Available: {[a['name'] for a in release.get('assets', [])]}"
+        )
+
+    commit_sha: str = release.get("body", "").strip() or "unknown"
+    return commit_sha, asset["browser_download_url"]
+
+
+def _download(settings: Settings, commit_sha: str, download_url: str) -> Path:
+    """Replace the cached build with the tarball at *download_url*."""
+    cache_dir = settings.data_dir / "frontend_cache"
+
+    # ------------------------------------------------------------------
+    # 1. Download the tarball to a temporary file
+    # ------------------------------------------------------------------
+    with tempfile.NamedTemporaryFile(suffix=".tar.gz", delete=False) as tmp:
+        tmp_path = Path(tmp.name)
+        with httpx.stream(
+            "GET",
+            download_url,
+            headers=_github_headers(settings, "application/octet-stream"),
+            follow_redirects=True,
+            timeout=120,
+        ) as stream:
+            for chunk in stream.iter_bytes():
+                tmp.write(chunk)
+
+    # ------------------------------------------------------------------
+    # 2. Clear old cache and extract
+    # ------------------------------------------------------------------
+    if cache_dir.exists():
+        shutil.rmtree(cache_dir)
+    cache_dir.mkdir(parents=True, exist_ok=True)
+
+    with tarfile.open(tmp_path) as tar:
+        tar.extractall(path=cache_dir)  # noqa: S202
+
+    tmp_path.unlink(missing_ok=True)
+
+    # ------------------------------------------------------------------
+    # 3. Write version marker
+    # ------------------------------------------------------------------
+    (cache_dir / ".version").write_text(commit_sha)
+
+    logger.info(
+        "Frontend build downloaded and cached in %s (commit: %s)",
+        cache_dir,
+        commit_sha,
+    )
+    return cache_dir
+
+
+def installed_frontend_version(settings: Settings) -> str | None:
+    """The commit SHA of the cached build, or None if none is cached."""
+    marker = settings.data_dir / "frontend_cache" / ".version"
+    return marker.read_text().strip() if marker.exists() else None
+
+
 def fetch_frontend(settings: Settings, force: bool = False) -> Path:
     """Download the frontend tarball from GitHub Releases and cache it locally.
 
@@ -27,107 +145,15 @@ def fetch_frontend(settings: Settings, force: bool = False) -> Path:
         SystemExit: If the download fails and no cached build is available.
     """
     cache_dir = settings.data_dir / "frontend_cache"
-    marker = cache_dir / ".version"
     index = cache_dir / "index.html"
 
-    # ------------------------------------------------------------------
     # Cache hit – return immediately unless forced
-    # ------------------------------------------------------------------
     if index.exists() and not force:
         logger.info("Using cached frontend build from %s", cache_dir)
         return cache_dir
 
-    # ------------------------------------------------------------------
-    # Build request headers
-    # ------------------------------------------------------------------
-    headers: dict[str, str] = {"Accept": "application/vnd.github+json"}
-    if settings.github_token:
-        headers["Authorization"] = f"token {settings.github_token}"
-
     try:
-        # --------------------------------------------------------------
-        # 1. Query the GitHub Releases API
-        # --------------------------------------------------------------
-        api_url = (
-            f"https://api.github.com/repos/{settings.frontend_repo}"
-            f"/releases/tags/{settings.frontend_release_tag}"
-        )
-        response = httpx.get(api_url, headers=headers, timeout=30)
-
-        if response.status_code == 404:
-            raise RuntimeError(
-                "Release not found. Has the GitHub Action run at least once?"
-            )
-        if response.status_code in (401, 403):
-            raise RuntimeError("Authentication required. Set ATTRACTOR_GITHUB_TOKEN.")
-        if response.status_code != 200:
-            raise RuntimeError(
-                f"GitHub API error {response.status_code}: {response.text}"
-            )
-
-        release = response.json()
-
-        # Find the matching asset
-        asset = next(
-            (
-                a
-                for a in release.get("assets", [])
-                if a["name"] == settings.frontend_asset_name
-            ),
-            None,
-        )
-        if asset is None:
-            raise RuntimeError(
-                f"Asset '{settings.frontend_asset_name}' not found in release "
-                f"'{settings.frontend_release_tag}'. "
-                f"Available: {[a['name'] for a in release.get('assets', [])]}"
-            )
-
-        download_url: str = asset["browser_download_url"]
-        commit_sha: str = release.get("body", "").strip() or "unknown"
-
-        # --------------------------------------------------------------
-        # 2. Download the tarball to a temporary file
-        # --------------------------------------------------------------
-        download_headers: dict[str, str] = {"Accept": "application/octet-stream"}
-        if settings.github_token:
-            download_headers["Authorization"] = f"token {settings.github_token}"
-
-        with tempfile.NamedTemporaryFile(suffix=".tar.gz", delete=False) as tmp:
-            tmp_path = Path(tmp.name)
-            with httpx.stream(
-                "GET",
-                download_url,
-                headers=download_headers,
-                follow_redirects=True,
-                timeout=120,
-            ) as stream:
-                for chunk in stream.iter_bytes():
-                    tmp.write(chunk)
-
-        # --------------------------------------------------------------
-        # 3. Clear old cache and extract
-        # --------------------------------------------------------------
-        if cache_dir.exists():
-            shutil.rmtree(cache_dir)
-        cache_dir.mkdir(parents=True, exist_ok=True)
-
-        with tarfile.open(tmp_path) as tar:
-            tar.extractall(path=cache_dir)  # noqa: S202
-
-        tmp_path.unlink(missing_ok=True)
-
-        # --------------------------------------------------------------
-        # 4. Write version marker
-        # --------------------------------------------------------------
-        marker.write_text(commit_sha)
-
-        logger.info(
-            "Frontend build downloaded and cached in %s (commit: %s)",
-            cache_dir,
-            commit_sha,
-        )
-        return cache_dir
+        return _download(settings, *_latest_release(settings))
 
     except (httpx.HTTPError, Exception) as exc:  # noqa: BLE001
         logger.warning("Failed to download frontend build: %s", exc)
@@ -141,3 +167,83 @@ def fetch_frontend(settings: Settings, force: bool = False) -> Path:
             "Check your network connection or build the frontend manually.\n"
             f"  Error: {exc}"
         ) from exc
+
+
+def check_for_updates(settings: Settings) -> UpdateStatus:
+    """Compare the cached build with the latest published one.
+
+    Outside production mode the frontend is not a downloaded build, so there
+    is nothing to compare and no request is made.
+
+    Raises:
+        UpdateError: The release could not be looked up.
+    """
+    if not settings.production:
+        return UpdateStatus()
+    current = installed_frontend_version(settings)
+    try:
+        latest, _ = _latest_release(settings)
+    except (httpx.HTTPError, RuntimeError) as exc:
+        raise UpdateError(f"Could not check for updates: {exc}") from exc
+    return UpdateStatus(
+        current=current,
+        latest=latest,
+        available=latest != current,
+    )
+
+
+def install_update(settings: Settings) -> UpdateStatus:
+    """Download the latest build over the cached one; takes effect on reload.
+
+    Raises:
+        UpdateError: Not in production mode, or the download failed.
+    """
+    if not settings.production:
+        raise UpdateError(
+            "Updates are only installed in production mode",
+            code="CONFLICT",
+            status_code=409,
+        )
+    try:
+        latest = _download(settings, *_latest_release(settings))
+    except (httpx.HTTPError, OSError, RuntimeError, tarfile.TarError) as exc:
+        raise UpdateError(f"Could not install update: {exc}") from exc
+    version = installed_frontend_version(settings)
+    logger.info("Frontend updated in %s", latest)
+    return UpdateStatus(current=version, latest=version, available=False)
+
+
+async def notify_available_update(
+    settings: Settings, ws: WebSocketManager, scheduler: UpdateScheduler
+) -> None:
+    """Broadcast ``update:available`` the first time a new build is seen."""
+    status = await asyncio.to_thread(check_for_updates, settings)
+    if status.available and status.latest != scheduler.announced:
+        scheduler.announced = status.latest
+        await ws.broadcast("update:available", status.model_dump())
+
+
+class UpdateScheduler:
+    """Check for a newer frontend build on an interval; 0 disables checks."""
+
+    def __init__(self) -> None:
+        self._task: asyncio.Task | None = None  # type: ignore[type-arg]
+        self.announced: str | None = None
+
+    def start(self, interval: float, run: Callable[[], Awaitable[None]]) -> None:
+        self.stop()
+        if interval > 0:
+            self._task = asyncio.create_task(self._loop(interval, run))
+
+    def stop(self) -> None:
+        if self._task is not None:
+            self._task.cancel()
+            self._task = None
+
+    async def _loop(self, interval: float, run: Callable[[], Awaitable[None]]) -> None:
+        while True:
+            await asyncio.sleep(interval)
+            try:
+                await run()
+            except Exception:
+                logger.exception("Scheduled update check failed")
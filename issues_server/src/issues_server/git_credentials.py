@@ -0,0 +1,51 @@
+"""Borrow GitHub credentials from git's configured credential helpers.
+
+Lets users who already authenticate git through ``gh auth``, osxkeychain,
+or Git Credential Manager use the app without pasting a token. The token is
+never persisted by the app; it is re-read from the helper as needed.
+"""
+
+import os
+import subprocess
+import time
+
+# Helpers can be slow (GCM starts a .NET process); avoid one spawn per request.
+CACHE_TTL = 300.0
+
+_cache: dict[str, tuple[float, str | None]] = {}
+
+
+def credential_fill(host: str = "github.com") -> str | None:
+    """Return the password/token git would use for *host*, or None.
+
+    Interactive prompts are disabled so a helper with nothing stored fails
+    instead of blocking the server.
+    """
+    cached = _cache.get(host)
+    if cached is not None and time.monotonic() - cached[0] < CACHE_TTL:
+        return cached[1]
+
+    try:
+        proc = subprocess.run(
+            ["git", "credential", "fill"],
+            input=f"protocol=https\nhost={host}\n\n",
+            capture_output=True,
+            text=True,
+            timeout=30,
+            env={**os.environ, "GIT_TERMINAL_PROMPT": "0", "GCM_INTERACTIVE": "never"},
+        )
+    except (OSError, subprocess.TimeoutExpired):
+        return None
+
+    token = None
+    if proc.returncode == 0:
+        fields = dict(
+            line.split("=", 1) for line in proc.stdout.splitlines() if "=" in line
+        )
+        token = fields.get("password") or None
+    _cache[host] = (time.monotonic(), token)
+    return token
+
+
+def clear_cache() -> None:
+    _cache.clear()
@@ -1,7 +1,105 @@
 """Thin wrapper over GitHub REST API for attractor store operations."""
 
+import asyncio
+import base64
+import logging
+import re
+import time
+from collections import OrderedDict
+from dataclasses import dataclass
+
 import httpx
 
+from .core.errors import AppError
+from .core.network import http_timeout
+
+logger = logging.getLogger(__name__)
+
+MAX_RETRIES = 3
+MAX_RETRY_WAIT = 60.0
+ETAG_CACHE_SIZE = 256
+# GitHub rejects longer search queries.
+SEARCH_QUERY_LIMIT = 256
+
+
+_SSO_REQUIRED_RE = re.compile(r"required;\s*url=(\S+)")
+_SSO_ORG_RE = re.compile(r"/orgs/([^/]+)/sso")
+
+
+class SamlSsoRequiredError(AppError):
+    """The token must be authorized for an organization that enforces SAML SSO."""
+
+    status_code = 403
+    code = "SAML_SSO_REQUIRED"
+
+    def __init__(self, authorization_url: str) -> None:
+        match = _SSO_ORG_RE.search(authorization_url)
+        self.organization = match.group(1) if match else None
+        self.authorization_url = authorization_url
+        target = (
+            f"the '{self.organization}' organization"
+            if self.organization
+            else "this organization"
+        )
+        super().__init__(
+            f"Your token must be authorized for SAML single sign-on to access {target}.",
+            organization=self.organization,
+            authorization_url=authorization_url,
+        )
+
+
+@dataclass
+class _CachedResponse:
+    etag: str
+    content: bytes
+    headers: dict[str, str]
+
+
+# Keyed by (token, path, params) so accounts never see each other's data.
+_etag_cache: OrderedDict[tuple, _CachedResponse] = OrderedDict()
+
+
+def clear_response_cache() -> None:
+    """Drop cached API responses, e.g. after signing out."""
+    _etag_cache.clear()
+
+
+DEVICE_CODE_URL = "https://github.com/login/device/code"
+ACCESS_TOKEN_URL = "https://github.com/login/oauth/access_token"
+DEVICE_GRANT_TYPE = "urn:ietf:params:oauth:grant-type:device_code"
+
+
+async def request_device_code(client_id: str, scope: str) -> dict:
+    """Start the OAuth device flow (POST /login/device/code)."""
+    async with httpx.AsyncClient(timeout=http_timeout()) as client:
+        resp = await client.post(
+            DEVICE_CODE_URL,
+            headers={"Accept": "application/json"},
+            data={"client_id": client_id, "scope": scope},
+        )
+        resp.raise_for_status()
+        return resp.json()
+
+
+async def poll_device_token(client_id: str, device_code: str) -> dict:
+    """Exchange a device code for a token (POST /login/oauth/access_token).
+
+    Returns the raw response, which carries either ``access_token`` or an
+    ``error`` such as ``authorization_pending`` or ``slow_down``.
+    """
+    async with httpx.AsyncClient(timeout=http_timeout()) as client:
+        resp = await client.post(
+            ACCESS_TOKEN_URL,
+            headers={"Accept": "application/json"},
+            data={
+                "client_id": client_id,
+                "device_code": device_code,
+                "grant_type": DEVICE_GRANT_TYPE,
+            },
+        )
+        resp.raise_for_status()
+        return resp.json()
+
 
 class GitHubClient:
     """GitHub API client using a Personal Access Token."""
@@ -16,66 +114,455 @@ class GitHubClient:
             "X-GitHub-Api-Version": "2022-11-28",
         }
 
+    # ------------------------------------------------------------------
+    # Transport
+    # ------------------------------------------------------------------
+
+    @staticmethod
+    def _retry_delay(resp: httpx.Response, attempt: int) -> float | None:
+        """Seconds to wait before retrying a rate-limited response, or None.
+
+        Primary limits report ``X-RateLimit-Remaining: 0`` with a reset epoch;
+        secondary limits send ``Retry-After``. Permission 403s carry neither
+        and are not retried.
+        """
+        if resp.status_code not in (403, 429):
+            return None
+        retry_after = resp.headers.get("Retry-After")
+        if retry_after is not None:
+            return float(retry_after)
+        if resp.headers.get("X-RateLimit-Remaining") == "0":
+            reset = resp.headers.get("X-RateLimit-Reset")
+            if reset is not None:
+                return max(float(reset) - time.time(), 1.0)
+        if resp.status_code == 429:
+            return float(2**attempt)
+        return None
+
+    def _cache_key(self, path: str, params: object) -> tuple:
+        items = tuple(sorted(params.items())) if isinstance(params, dict) else ()
+        return (self.token, path, items)
+
+    async def _request(self, method: str, path: str, **kwargs: object) -> httpx.Response:
+        """Send a request, backing off and retrying when rate limited.
+
+        GETs are revalidated with ``If-None-Match``; a 304 is answered from
+        the cache and does not count against the rate limit.
+        """
+        headers = self._headers
+        cache_key: tuple | None = None
+        cached: _CachedResponse | None = None
+        if method == "GET":
+            cache_key = self._cache_key(path, kwargs.get("params"))
+            cached = _etag_cache.get(cache_key)
+            if cached is not None:
+                headers = {**self._headers, "If-None-Match": cached.etag}
+
+        async with httpx.AsyncClient(timeout=http_timeout()) as client:
+            for attempt in range(MAX_RETRIES + 1):
+                resp = await client.request(
+                    method, f"{self.BASE_URL}{path}", headers=headers, **kwargs
+                )
+                delay = self._retry_delay(resp, attempt)
+                if delay is None or attempt == MAX_RETRIES or delay > MAX_RETRY_WAIT:
+                    break
+                logger.warning(
+                    "GitHub rate limit hit on %s %s; retrying in %.0fs",
+                    method,
+                    path,
+                    delay,
+                )
+                await asyncio.sleep(delay)
+
+        if resp.status_code == 403:
+            sso = _SSO_REQUIRED_RE.search(resp.headers.get("X-GitHub-SSO", ""))
+            if sso is not None:
+                raise SamlSsoRequiredError(sso.group(1))
+        if cache_key is None:
+            return resp
+        if resp.status_code == 304 and cached is not None:
+            _etag_cache.move_to_end(cache_key)
+            return httpx.Response(
+                200,
+                headers=cached.headers,
+                content=cached.content,
+                request=resp.request,
+            )
+        etag = resp.headers.get("ETag")
+        if resp.status_code == 200 and etag is not None:
+            # The body is already decoded, so its transfer headers no longer apply.
+            headers = {
+                k: v
+                for k, v in resp.headers.items()
+                if k.lower() not in ("content-encoding", "content-length")
+            }
+            _etag_cache[cache_key] = _CachedResponse(
+                etag=etag, content=resp.content, headers=headers
+            )
+            _etag_cache.move_to_end(cache_key)
+            while len(_etag_cache) > ETAG_CACHE_SIZE:
+                _etag_cache.popitem(last=False)
+        return resp
+
+    async def _paginate(self, path: str, params: dict | None = None) -> list[dict]:
+        """GET every page of a list endpoint, 100 items at a time."""
+        items: list[dict] = []
+        page = 1
+        while True:
+            resp = await self._request(
+                "GET", path, params={**(params or {}), "per_page": 100, "page": page}
+            )
+            resp.raise_for_status()
+            batch = resp.json()
+            items.extend(batch)
+            if len(batch) < 100:
+                break
+            page += 1
+        return items
+
+    # ------------------------------------------------------------------
+    # Endpoints
+    # ------------------------------------------------------------------
+
+    async def get_rate_limit_status(self) -> dict:
+        """GET /rate_limit -- current quotas (does not count against them)."""
+        resp = await self._request("GET", "/rate_limit")
+        resp.raise_for_status()
+        return resp.json()
+
     async def get_authenticated_user(self) -> dict:
         """GET /user -- validate token, return user info."""
-        async with httpx.AsyncClient() as client:
-            resp = await client.get(f"{self.BASE_URL}/user", headers=self._headers)
-            resp.raise_for_status()
-            return resp.json()
+        resp = await self._request("GET", "/user")
+        resp.raise_for_status()
+        return resp.json()
+
+    async def get_user(self, login: str) -> dict | None:
+        """GET /users/{login}, or None when there is no such user."""
+        resp = await self._request("GET", f"/users/{login}")
+        if resp.status_code == 404:
+            return None
+        resp.raise_for_status()
+        return resp.json()
+
+    async def list_emails(self) -> list[dict] | None:
+        """GET /user/emails, or None when the token lacks the email scope."""
+        resp = await self._request("GET", "/user/emails")
+        if resp.status_code in (403, 404):
+            return None
+        resp.raise_for_status()
+        return resp.json()
+
+    async def get_token_scopes(self) -> list[str] | None:
+        """Return OAuth scopes for classic tokens, or None for fine-grained tokens."""
+        resp = await self._request("GET", "/user")
+        resp.raise_for_status()
+        header = resp.headers.get("X-OAuth-Scopes")
+        if header is None:
+            return None
+        return [s.strip() for s in header.split(",") if s.strip()]
+
+    async def get_repo(self, owner: str, repo: str) -> dict:
+        """GET /repos/{owner}/{repo} -- includes the caller's ``permissions``."""
+        resp = await self._request("GET", f"/repos/{owner}/{repo}")
+        resp.raise_for_status()
+        return resp.json()
+
+    async def get_file_content(self, owner: str, repo: str, path: str) -> bytes | None:
+        """Raw bytes of *path* on the default branch via the contents API, or None."""
+        resp = await self._request("GET", f"/repos/{owner}/{repo}/contents/{path}")
+        if resp.status_code == 404:
+            return None
+        resp.raise_for_status()
+        return base64.b64decode(resp.json()["content"])
+
+    async def can_create_repos(self) -> bool:
+        """Probe repo-creation permission without creating anything.
+
+        GitHub authorizes before validating the payload, so an empty name
+        yields 422 when creation is allowed and 403/404 when it is not.
+        """
+        resp = await self._request("POST", "/user/repos", json={"name": ""})
+        return resp.status_code == 422
 
     async def repo_exists(self, owner: str, repo: str) -> bool:
         """Check if a repo exists (HEAD /repos/{owner}/{repo})."""
-        async with httpx.AsyncClient() as client:
-            resp = await client.head(
-                f"{self.BASE_URL}/repos/{owner}/{repo}",
-                headers=self._headers,
-            )
-            return resp.status_code == 200
+        resp = await self._request("HEAD", f"/repos/{owner}/{repo}")
+        return resp.status_code == 200
 
     async def create_repo(
         self, name: str, private: bool = True, description: str = ""
     ) -> dict:
         """Create a new repo for the authenticated user (POST /user/repos)."""
-        async with httpx.AsyncClient() as client:
-            resp = await client.post(
-                f"{self.BASE_URL}/user/repos",
-                headers=self._headers,
-                json={
-                    "name": name,
-                    "private": private,
-                    "description": description,
-                    "auto_init": False,
-                },
-            )
-            if resp.status_code == 403:
-                raise PermissionError("Token lacks permission to create repositories.")
-            resp.raise_for_status()
-            return resp.json()
+        resp = await self._request(
+            "POST",
+            "/user/repos",
+            json={
+                "name": name,
+                "private": private,
+                "description": description,
+                "auto_init": False,
+            },
+        )
+        if resp.status_code == 403:
+            raise PermissionError("Token lacks permission to create repositories.")
+        resp.raise_for_status()
+        return resp.json()
+
+    async def update_repo(self, owner: str, repo: str, fields: dict) -> dict:
+        """PATCH /repos/{owner}/{repo} -- e.g. name, visibility, archived."""
+        resp = await self._request("PATCH", f"/repos/{owner}/{repo}", json=fields)
+        if resp.status_code == 403:
+            raise PermissionError("Token lacks permission to administer this repository.")
+        resp.raise_for_status()
+        return resp.json()
+
+    async def delete_repo(self, owner: str, repo: str) -> None:
+        """DELETE /repos/{owner}/{repo} -- requires the delete_repo scope."""
+        resp = await self._request("DELETE", f"/repos/{owner}/{repo}")
+        if resp.status_code == 403:
+            raise PermissionError("Token lacks permission to delete this repository.")
+        resp.raise_for_status()
 
-    async def list_repos(self, prefix: str | None = None) -> list[dict]:
-        """List repos for the authenticated user, optionally filtered by name prefix."""
-        repos: list[dict] = []
+    async def list_collaborators(self, owner: str, repo: str) -> list[dict]:
+        """GET /repos/{owner}/{repo}/collaborators -- includes ``role_name``."""
+        return await self._paginate(f"/repos/{owner}/{repo}/collaborators")
+
+    async def is_collaborator(self, owner: str, repo: str, login: str) -> bool:
+        """GET /repos/{owner}/{repo}/collaborators/{login} -- 204 yes, 404 no."""
+        resp = await self._request(
+            "GET", f"/repos/{owner}/{repo}/collaborators/{login}"
+        )
+        if resp.status_code == 404:
+            return False
+        resp.raise_for_status()
+        return True
+
+    async def add_collaborator(
+        self, owner: str, repo: str, login: str, permission: str
+    ) -> bool:
+        """Invite or update a collaborator. Returns True if an invitation was sent."""
+        resp = await self._request(
+            "PUT",
+            f"/repos/{owner}/{repo}/collaborators/{login}",
+            json={"permission": permission},
+        )
+        resp.raise_for_status()
+        return resp.status_code == 201
+
+    async def remove_collaborator(self, owner: str, repo: str, login: str) -> None:
+        """DELETE /repos/{owner}/{repo}/collaborators/{login}."""
+        resp = await self._request(
+            "DELETE", f"/repos/{owner}/{repo}/collaborators/{login}"
+        )
+        resp.raise_for_status()
+
+    async def list_assignees(self, owner: str, repo: str) -> list[dict]:
+        """GET /repos/{owner}/{repo}/assignees -- users who can be assigned issues."""
+        return await self._paginate(f"/repos/{owner}/{repo}/assignees")
+
+    async def list_orgs(self) -> list[dict]:
+        """List organizations the authenticated user belongs to (GET /user/orgs)."""
+        return await self._paginate("/user/orgs")
+
+    async def search_repos(self, query: str) -> list[dict] | None:
+        """GET /search/repositories for every result page.
+
+        Returns None when the results can't be trusted to be complete: the
+        query was rejected or too long, the search rate limit was hit, or
+        GitHub flagged ``incomplete_results``.
+        """
+        if len(query) > SEARCH_QUERY_LIMIT:
+            return None
+        items: list[dict] = []
         page = 1
-        async with httpx.AsyncClient() as client:
-            while True:
-                resp = await client.get(
-                    f"{self.BASE_URL}/user/repos",
-                    headers=self._headers,
-                    params={
-                        "per_page": 100,
-                        "page": page,
-                        "sort": "updated",
-                        "direction": "desc",
-                    },
+        while True:
+            resp = await self._request(
+                "GET",
+                "/search/repositories",
+                params={"q": query, "per_page": 100, "page": page},
+            )
+            if resp.status_code in (403, 422, 429, 503):
+                logger.info(
+                    "Repo search unavailable (%s); listing instead", resp.status_code
                 )
-                resp.raise_for_status()
-                batch = resp.json()
-                if not batch:
-                    break
-                for r in batch:
-                    if prefix is None or r["name"].startswith(prefix):
-                        repos.append(r)
-                if len(batch) < 100:
-                    break
-                page += 1
-        return repos
+                return None
+            resp.raise_for_status()
+            data = resp.json()
+            if data.get("incomplete_results"):
+                return None
+            items.extend(data["items"])
+            if len(data["items"]) < 100 or len(items) >= min(data["total_count"], 1000):
+                return items
+            page += 1
+
+    async def _search_accessible_repos(
+        self,
+        text: str,
+        affiliation: str | None,
+        org: str | None,
+    ) -> list[dict] | None:
+        """Search within the user's own and organization repos.
+
+        Collaborator repos on other personal accounts can't be expressed as
+        search qualifiers, so an explicit collaborator-only affiliation is
+        left to the listing fallback.
+        """
+        if org is not None:
+            scopes = [f"org:{org}"]
+        else:
+            wanted = set((affiliation or "owner,organization_member").split(","))
+            scopes = []
+            if "owner" in wanted:
+                scopes.append(f"user:{(await self.get_authenticated_user())['login']}")
+            if "organization_member" in wanted:
+                scopes.extend(f"org:{o['login']}" for o in await self.list_orgs())
+            if not scopes:
+                return None
+        return await self.search_repos(f"{text} {' '.join(scopes)}")
+
+    async def list_repos(
+        self,
+        prefix: str | None = None,
+        affiliation: str | None = None,
+        org: str | None = None,
+        query: str | None = None,
+    ) -> list[dict]:
+        """List repos the authenticated user can access, optionally filtered.
+
+        Filtered requests go through the search API instead of paging every
+        repo, falling back to a full listing when search can't answer.
+
+        Args:
+            prefix: Only repos whose name starts with this.
+            affiliation: Comma-separated subset of ``owner``, ``collaborator``,
+                ``organization_member``. GitHub defaults to all three.
+            org: List an organization's repos instead of the user's.
+            query: Free text matched against names, descriptions and topics.
+        """
+        repos: list[dict] | None = None
+        if prefix or query:
+            # Search matches whole words; trailing separators would match nothing.
+            name_words = (prefix or "").rstrip("-_.")
+            text = f"{query} {name_words}" if query else f"{name_words} in:name"
+            repos = await self._search_accessible_repos(text.strip(), affiliation, org)
+            # Partial words (e.g. "attr") find nothing via search but do by prefix.
+            if repos is not None and not repos and prefix and not query:
+                repos = None
+
+        if repos is None:
+            if org is not None:
+                path = f"/orgs/{org}/repos"
+                params: dict[str, str | int] = {"sort": "updated", "direction": "desc"}
+            else:
+                path = "/user/repos"
+                params = {"sort": "updated", "direction": "desc"}
+                if affiliation is not None:
+                    params["affiliation"] = affiliation
+            repos = await self._paginate(path, params)
+            if query:
+                needle = query.lower()
+                repos = [
+                    r
+                    for r in repos
+                    if needle in r["name"].lower()
+                    or needle in (r.get("description") or "").lower()
+                ]
+
+        if prefix is None:
+            return repos
+        return [r for r in repos if r["name"].startswith(prefix)]
+
+    async def list_issues(self, owner: str, repo: str) -> list[dict]:
+        """List all issues, open and closed (excludes pull requests)."""
+        items = await self._paginate(
+            f"/repos/{owner}/{repo}/issues",
+            {"state": "all", "sort": "created", "direction": "asc"},
+        )
+        return [i for i in items if "pull_request" not in i]
+
+    async def list_issue_comments(self, owner: str, repo: str, number: int) -> list[dict]:
+        """GET /repos/{owner}/{repo}/issues/{number}/comments (all pages)."""
+        return await self._paginate(f"/repos/{owner}/{repo}/issues/{number}/comments")
+
+    async def list_labels(self, owner: str, repo: str) -> list[dict]:
+        """GET /repos/{owner}/{repo}/labels (all pages)."""
+        return await self._paginate(f"/repos/{owner}/{repo}/labels")
+
+    async def list_milestones(self, owner: str, repo: str) -> list[dict]:
+        """GET /repos/{owner}/{repo}/milestones, open and closed."""
+        return await self._paginate(
+            f"/repos/{owner}/{repo}/milestones", {"state": "all"}
+        )
+
+    async def get_issue(self, owner: str, repo: str, number: int) -> dict:
+        """GET /repos/{owner}/{repo}/issues/{number}."""
+        resp = await self._request("GET", f"/repos/{owner}/{repo}/issues/{number}")
+        resp.raise_for_status()
+        return resp.json()
+
+    async def create_issue(self, owner: str, repo: str, fields: dict) -> dict:
+        """POST /repos/{owner}/{repo}/issues -- unknown label names are created."""
+        resp = await self._request("POST", f"/repos/{owner}/{repo}/issues", json=fields)
+        resp.raise_for_status()
+        return resp.json()
+
+    async def update_issue(self, owner: str, repo: str, number: int, fields: dict) -> dict:
+        """PATCH /repos/{owner}/{repo}/issues/{number}."""
+        resp = await self._request(
+            "PATCH", f"/repos/{owner}/{repo}/issues/{number}", json=fields
+        )
+        resp.raise_for_status()
+        return resp.json()
+
+    async def create_issue_comment(
+        self, owner: str, repo: str, number: int, body: str
+    ) -> dict:
+        """POST /repos/{owner}/{repo}/issues/{number}/comments."""
+        resp = await self._request(
+            "POST",
+            f"/repos/{owner}/{repo}/issues/{number}/comments",
+            json={"body": body},
+        )
+        resp.raise_for_status()
+        return resp.json()
+
+    async def create_pull_request(self, owner: str, repo: str, fields: dict) -> dict:
+        """POST /repos/{owner}/{repo}/pulls."""
+        resp = await self._request("POST", f"/repos/{owner}/{repo}/pulls", json=fields)
+        resp.raise_for_status()
+        return resp.json()
+
+    async def get_pull_request(self, owner: str, repo: str, number: int) -> dict:
+        """GET /repos/{owner}/{repo}/pulls/{number}."""
+        resp = await self._request("GET", f"/repos/{owner}/{repo}/pulls/{number}")
+        resp.raise_for_status()
+        return resp.json()
+
+    async def find_pull_request(self, owner: str, repo: str, head: str) -> dict | None:
+        """The open pull request from *head* (``owner:branch``), if there is one."""
+        resp = await self._request(
+            "GET",
+            f"/repos/{owner}/{repo}/pulls",
+            params={"head": head, "state": "open"},
+        )
+        resp.raise_for_status()
+        pulls = resp.json()
+        return pulls[0] if pulls else None
+
+    async def enable_pages(self, owner: str, repo: str, branch: str) -> str | None:
+        """Serve *branch* with GitHub Pages and return the site URL.
+
+        Returns None when Pages is unavailable, e.g. private repos on free
+        plans or tokens without the Pages permission.
+        """
+        resp = await self._request(
+            "POST",
+            f"/repos/{owner}/{repo}/pages",
+            json={"source": {"branch": branch, "path": "/"}},
+        )
+        if resp.status_code == 409:
+            resp = await self._request("GET", f"/repos/{owner}/{repo}/pages")
+        if resp.status_code in (403, 404, 422):
+            return None
+        resp.raise_for_status()
+        return resp.json().get("html_url")
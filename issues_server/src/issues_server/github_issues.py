@@ -0,0 +1,534 @@
+"""Move issues between a project store and real GitHub Issues."""
+
+from __future__ import annotations
+
+import asyncio
+import logging
+from collections.abc import Awaitable, Callable
+from datetime import datetime, timezone
+
+from pydantic import BaseModel
+
+from .core.models import (
+    Comment,
+    ExternalRef,
+    Issue,
+    IssueFilters,
+    IssueMirror,
+    Label,
+    MirrorConfig,
+    Milestone,
+    SimpleUser,
+)
+from .core.storage import ProjectStorage
+from .github_client import GitHubClient
+
+logger = logging.getLogger(__name__)
+
+# GitHub asks integrations to space out content-creating requests; bursts
+# trip the secondary rate limit even with quota remaining.
+WRITE_INTERVAL = 1.0
+
+
+class ImportResult(BaseModel):
+    """Counts of records created by an import."""
+
+    issues: int = 0
+    comments: int = 0
+    labels: int = 0
+    milestones: int = 0
+    skipped_issues: int = 0
+
+
+class ExportResult(BaseModel):
+    """Counts of records created on GitHub by an export."""
+
+    issues: int = 0
+    comments: int = 0
+    skipped_issues: int = 0
+
+
+class MirrorSyncResult(BaseModel):
+    """Local issue numbers touched by a mirror sync pass."""
+
+    pushed: list[int] = []
+    pulled: list[int] = []
+    conflicts: list[int] = []
+    comments_pushed: int = 0
+    comments_pulled: int = 0
+
+    @property
+    def changed(self) -> bool:
+        return bool(
+            self.pushed or self.pulled or self.comments_pushed or self.comments_pulled
+        )
+
+
+def _import_map_name(owner: str, repo: str) -> str:
+    return f"github-import-{owner}-{repo}"
+
+
+def _export_map_name(owner: str, repo: str) -> str:
+    return f"github-export-{owner}-{repo}"
+
+
+def _is_from(ref: ExternalRef | None, owner: str, repo: str) -> bool:
+    return ref is not None and (ref.owner, ref.repo) == (owner, repo)
+
+
+def _user(data: dict | None) -> SimpleUser:
+    if data is None:
+        return SimpleUser(login="ghost", id=0, avatar_url="", user_type="User")
+    return SimpleUser.model_validate(data)
+
+
+def _import_labels(storage: ProjectStorage, remote: list[dict]) -> tuple[dict[str, Label], int]:
+    """Merge remote labels into labels.json by name. Returns (by_name, created)."""
+    labels = storage.read_labels()
+    by_name = {label.name: label for label in labels}
+    next_id = max((label.id for label in labels), default=0) + 1
+    created = 0
+    for data in remote:
+        if data["name"] in by_name:
+            continue
+        label = Label(
+            id=next_id,
+            name=data["name"],
+            color=data["color"],
+            description=data.get("description"),
+        )
+        labels.append(label)
+        by_name[label.name] = label
+        next_id += 1
+        created += 1
+    if created:
+        storage.write_labels(labels)
+    return by_name, created
+
+
+def _import_milestones(
+    storage: ProjectStorage, remote: list[dict]
+) -> tuple[dict[str, Milestone], int]:
+    """Merge remote milestones into milestones.json by title. Returns (by_title, created)."""
+    milestones = storage.read_milestones()
+    by_title = {m.title: m for m in milestones}
+    next_number = max((m.number for m in milestones), default=0) + 1
+    created = 0
+    for data in remote:
+        if data["title"] in by_title:
+            continue
+        milestone = Milestone(
+            id=next_number,
+            number=next_number,
+            title=data["title"],
+            description=data.get("description"),
+            state=data["state"],
+            due_on=data.get("due_on"),
+            created_at=data["created_at"],
+            updated_at=data["updated_at"],
+            closed_at=data.get("closed_at"),
+        )
+        milestones.append(milestone)
+        by_title[milestone.title] = milestone
+        next_number += 1
+        created += 1
+    if created:
+        storage.write_milestones(milestones)
+    return by_title, created
+
+
+async def import_github_issues(
+    storage: ProjectStorage,
+    client: GitHubClient,
+    source_owner: str,
+    source_repo: str,
+) -> ImportResult:
+    """Copy issues, comments, labels and milestones from a GitHub repo.
+
+    Local numbers are assigned fresh; the source of each issue and comment is
+    kept in its ``source`` field and in an ID map so re-running an import
+    only picks up records added since the last run. The caller commits.
+    """
+    result = ImportResult()
+
+    labels, result.labels = _import_labels(
+        storage, await client.list_labels(source_owner, source_repo)
+    )
+    milestones, result.milestones = _import_milestones(
+        storage, await client.list_milestones(source_owner, source_repo)
+    )
+
+    map_name = _import_map_name(source_owner, source_repo)
+    id_map = storage.read_id_map(map_name)
+    issue_map = id_map.setdefault("issues", {})
+    comment_map = id_map.setdefault("comments", {})
+
+    for data in await client.list_issues(source_owner, source_repo):
+        source_number = str(data["number"])
+        if source_number in issue_map:
+            result.skipped_issues += 1
+            number = issue_map[source_number]
+        else:
+            number = storage.next_issue_id()
+            milestone = data.get("milestone")
+            issue = Issue(
+                id=number,
+                number=number,
+                title=data["title"],
+                body=data.get("body"),
+                state=data["state"],
+                state_reason=data.get("state_reason"),
+                labels=[labels[lbl["name"]] for lbl in data["labels"] if lbl["name"] in labels],
+                assignees=[_user(a) for a in data.get("assignees") or []],
+                created_at=data["created_at"],
+                updated_at=data["updated_at"],
+                closed_at=data.get("closed_at"),
+                author_association=data["author_association"],
+                user=_user(data.get("user")),
+                milestone=milestones.get(milestone["title"]) if milestone else None,
+                source=ExternalRef(
+                    owner=source_owner,
+                    repo=source_repo,
+                    id=data["id"],
+                    number=data["number"],
+                    url=data["html_url"],
+                ),
+            )
+            storage.write_issue(issue)
+            issue_map[source_number] = number
+            result.issues += 1
+
+        if not data["comments"] or storage.read_issue(number) is None:
+            continue
+        added = 0
+        for c in await client.list_issue_comments(source_owner, source_repo, data["number"]):
+            if str(c["id"]) in comment_map:
+                continue
+            comment = Comment(
+                id=storage.next_comment_id(),
+                body=c["body"],
+                user=_user(c.get("user")),
+                created_at=c["created_at"],
+                updated_at=c["updated_at"],
+                author_association=c["author_association"],
+                source=ExternalRef(
+                    owner=source_owner,
+                    repo=source_repo,
+                    id=c["id"],
+                    url=c["html_url"],
+                ),
+            )
+            storage.write_comment(number, comment)
+            comment_map[str(c["id"])] = comment.id
+            added += 1
+        if added:
+            issue = storage.read_issue(number)
+            issue.comments += added
+            storage.write_issue(issue)
+            result.comments += added
+
+    storage.write_id_map(map_name, id_map)
+    return result
+
+
+async def export_to_github_issues(
+    storage: ProjectStorage,
+    client: GitHubClient,
+    target_owner: str,
+    target_repo: str,
+    filters: IssueFilters,
+) -> ExportResult:
+    """Create GitHub issues and comments for local issues matching *filters*.
+
+    Created issues are appended to each issue's ``exported_to`` and recorded
+    in an ID map, so exporting again only adds what is new. Records that were
+    imported from the target repo are never sent back. The caller commits.
+    """
+    result = ExportResult()
+
+    map_name = _export_map_name(target_owner, target_repo)
+    id_map = storage.read_id_map(map_name)
+    issue_map = id_map.setdefault("issues", {})
+    comment_map = id_map.setdefault("comments", {})
+
+    for issue in storage.all_issues(filters):
+        if _is_from(issue.source, target_owner, target_repo):
+            result.skipped_issues += 1
+            continue
+
+        remote_number = issue_map.get(str(issue.number))
+        if remote_number is None:
+            created = await client.create_issue(
+                target_owner,
+                target_repo,
+                {
+                    "title": issue.title,
+                    "body": issue.body or "",
+                    "labels": [label.name for label in issue.labels],
+                },
+            )
+            await asyncio.sleep(WRITE_INTERVAL)
+            remote_number = created["number"]
+            if issue.state == "closed":
+                await client.update_issue(
+                    target_owner,
+                    target_repo,
+                    remote_number,
+                    {"state": "closed", "state_reason": issue.state_reason or "completed"},
+                )
+                await asyncio.sleep(WRITE_INTERVAL)
+            issue.exported_to.append(
+                ExternalRef(
+                    owner=target_owner,
+                    repo=target_repo,
+                    id=created["id"],
+                    number=remote_number,
+                    url=created["html_url"],
+                )
+            )
+            storage.write_issue(issue)
+            issue_map[str(issue.number)] = remote_number
+            result.issues += 1
+        else:
+            result.skipped_issues += 1
+
+        for comment in storage.all_comments(issue.number):
+            if str(comment.id) in comment_map or _is_from(
+                comment.source, target_owner, target_repo
+            ):
+                continue
+            created = await client.create_issue_comment(
+                target_owner, target_repo, remote_number, comment.body
+            )
+            await asyncio.sleep(WRITE_INTERVAL)
+            comment_map[str(comment.id)] = created["id"]
+            result.comments += 1
+
+        # Persist after every issue so a failure part-way keeps what was created.
+        storage.write_id_map(map_name, id_map)
+
+    return result
+
+
+# ---------------------------------------------------------------------------
+# Mirroring
+#
+# Each mirrored issue has a ``mirror/{number}.json`` link in the store. A sync
+# compares both sides with the timestamps recorded at the previous sync and
+# copies title, body, state and labels from whichever side changed; when both
+# did, the project's conflict policy picks. New comments flow both ways, but
+# comment edits and deletions are not propagated.
+# ---------------------------------------------------------------------------
+
+
+def _remote_fields(issue: Issue) -> dict:
+    fields: dict = {
+        "title": issue.title,
+        "body": issue.body or "",
+        "state": issue.state,
+        "labels": [label.name for label in issue.labels],
+    }
+    if issue.state == "closed":
+        fields["state_reason"] = issue.state_reason or "completed"
+    return fields
+
+
+def _apply_remote(storage: ProjectStorage, issue: Issue, remote: dict) -> None:
+    labels, _ = _import_labels(storage, remote["labels"])
+    issue.title = remote["title"]
+    issue.body = remote.get("body")
+    issue.state = remote["state"]
+    issue.state_reason = remote.get("state_reason")
+    closed_at = remote.get("closed_at")
+    issue.closed_at = datetime.fromisoformat(closed_at) if closed_at else None
+    issue.labels = [labels[lbl["name"]] for lbl in remote["labels"]]
+    issue.updated_at = datetime.now(timezone.utc)
+
+
+def _known_comments(
+    storage: ProjectStorage, issue_number: int, config: MirrorConfig
+) -> dict[str, int]:
+    """Local comments that already exist on the mirror repo, from import or export."""
+    exported = storage.read_id_map(_export_map_name(config.owner, config.repo)).get(
+        "comments", {}
+    )
+    known: dict[str, int] = {}
+    for comment in storage.all_comments(issue_number):
+        if _is_from(comment.source, config.owner, config.repo):
+            known[str(comment.id)] = comment.source.id  # type: ignore[union-attr]
+        elif str(comment.id) in exported:
+            known[str(comment.id)] = exported[str(comment.id)]
+    return known
+
+
+async def link_issue_mirror(
+    storage: ProjectStorage,
+    client: GitHubClient,
+    config: MirrorConfig,
+    issue: Issue,
+    remote_number: int | None = None,
+) -> IssueMirror:
+    """Start mirroring *issue*, creating its GitHub counterpart if needed.
+
+    Without *remote_number*, an issue previously imported from or exported
+    to the mirror repo is linked to that GitHub issue. Both sides are treated
+    as in sync at link time. The caller commits.
+    """
+    if remote_number is None:
+        refs = [issue.source, *issue.exported_to]
+        remote_number = next(
+            (r.number for r in refs if _is_from(r, config.owner, config.repo)), None
+        )
+
+    if remote_number is not None:
+        remote = await client.get_issue(config.owner, config.repo, remote_number)
+    else:
+        remote = await client.create_issue(
+            config.owner, config.repo, _remote_fields(issue)
+        )
+        await asyncio.sleep(WRITE_INTERVAL)
+        if issue.state == "closed":
+            remote = await client.update_issue(
+                config.owner, config.repo, remote["number"], _remote_fields(issue)
+            )
+            await asyncio.sleep(WRITE_INTERVAL)
+
+    mirror = IssueMirror(
+        owner=config.owner,
+        repo=config.repo,
+        number=remote["number"],
+        id=remote["id"],
+        url=remote["html_url"],
+        local_updated_at=issue.updated_at,
+        remote_updated_at=datetime.fromisoformat(remote["updated_at"]),
+        comments=_known_comments(storage, issue.number, config),
+    )
+    storage.write_mirror(issue.number, mirror)
+    return mirror
+
+
+async def _sync_issue_mirror(
+    storage: ProjectStorage,
+    client: GitHubClient,
+    config: MirrorConfig,
+    number: int,
+    mirror: IssueMirror,
+    result: MirrorSyncResult,
+) -> None:
+    issue = storage.read_issue(number)
+    if issue is None:
+        storage.delete_mirror(number)
+        return
+
+    remote = await client.get_issue(mirror.owner, mirror.repo, mirror.number)
+    remote_updated_at = datetime.fromisoformat(remote["updated_at"])
+    local_changed = issue.updated_at > mirror.local_updated_at
+    remote_changed = remote_updated_at > mirror.remote_updated_at
+
+    push = local_changed
+    if local_changed and remote_changed:
+        result.conflicts.append(number)
+        if config.conflict_policy == "remote" or (
+            config.conflict_policy == "newest" and remote_updated_at > issue.updated_at
+        ):
+            push = False
+
+    wrote_remote = False
+    wrote_local = False
+    if push:
+        await client.update_issue(
+            mirror.owner, mirror.repo, mirror.number, _remote_fields(issue)
+        )
+        await asyncio.sleep(WRITE_INTERVAL)
+        result.pushed.append(number)
+        wrote_remote = True
+    elif remote_changed:
+        _apply_remote(storage, issue, remote)
+        result.pulled.append(number)
+        wrote_local = True
+
+    known_remote = set(mirror.comments.values())
+    for c in await client.list_issue_comments(mirror.owner, mirror.repo, mirror.number):
+        if c["id"] in known_remote:
+            continue
+        comment = Comment(
+            id=storage.next_comment_id(),
+            body=c["body"],
+            user=_user(c.get("user")),
+            created_at=c["created_at"],
+            updated_at=c["updated_at"],
+            author_association=c["author_association"],
+            source=ExternalRef(
+                owner=mirror.owner, repo=mirror.repo, id=c["id"], url=c["html_url"]
+            ),
+        )
+        storage.write_comment(number, comment)
+        mirror.comments[str(comment.id)] = c["id"]
+        issue.comments += 1
+        result.comments_pulled += 1
+        wrote_local = True
+
+    for comment in storage.all_comments(number):
+        if str(comment.id) in mirror.comments:
+            continue
+        created = await client.create_issue_comment(
+            mirror.owner, mirror.repo, mirror.number, comment.body
+        )
+        await asyncio.sleep(WRITE_INTERVAL)
+        mirror.comments[str(comment.id)] = created["id"]
+        result.comments_pushed += 1
+        wrote_remote = True
+
+    if wrote_local:
+        storage.write_issue(issue)
+    if wrote_remote:
+        # Our own writes bump GitHub's updated_at; record it so they don't
+        # read as remote changes next time.
+        remote = await client.get_issue(mirror.owner, mirror.repo, mirror.number)
+    mirror.local_updated_at = issue.updated_at
+    mirror.remote_updated_at = datetime.fromisoformat(remote["updated_at"])
+    storage.write_mirror(number, mirror)
+
+
+async def sync_issue_mirrors(
+    storage: ProjectStorage, client: GitHubClient, config: MirrorConfig
+) -> MirrorSyncResult:
+    """Run one sync pass over every issue mirrored with *config*'s repo.
+
+    The caller commits.
+    """
+    result = MirrorSyncResult()
+    for number in storage.list_mirrored_issues():
+        mirror = storage.read_mirror(number)
+        if mirror is None or (mirror.owner, mirror.repo) != (config.owner, config.repo):
+            continue
+        await _sync_issue_mirror(storage, client, config, number, mirror, result)
+    return result
+
+
+class MirrorScheduler:
+    """Run mirror syncs on an interval and serialize them per project."""
+
+    def __init__(self) -> None:
+        self._locks: dict[str, asyncio.Lock] = {}
+        self._task: asyncio.Task | None = None  # type: ignore[type-arg]
+
+    def lock(self, project: str) -> asyncio.Lock:
+        return self._locks.setdefault(project, asyncio.Lock())
+
+    def start(self, interval: float, run: Callable[[], Awaitable[None]]) -> None:
+        self.stop()
+        self._task = asyncio.create_task(self._loop(interval, run))
+
+    def stop(self) -> None:
+        if self._task is not None:
+            self._task.cancel()
+            self._task = None
+
+    async def _loop(self, interval: float, run: Callable[[], Awaitable[None]]) -> None:
+        while True:
+            await asyncio.sleep(interval)
+            try:
+                await run()
+            except Exception:
+                logger.exception("Scheduled mirror sync failed")
@@ -0,0 +1,180 @@
+"""Render issues as Markdown, CSV, or JSON files for reports and other tools."""
+
+from __future__ import annotations
+
+import csv
+import io
+import json
+import re
+import shutil
+from pathlib import Path
+
+from .core.models import Comment, Issue, IssueExportFormat
+
+# Markdown links and images whose target has no URL scheme, i.e. a file path.
+_LOCAL_LINK = re.compile(r"(!?\[[^\]]*\]\()(?![a-zA-Z][a-zA-Z0-9+.-]*:|#)([^)\s]+)(\))")
+
+CSV_COLUMNS = [
+    "number",
+    "title",
+    "state",
+    "labels",
+    "assignees",
+    "milestone",
+    "author",
+    "created_at",
+    "updated_at",
+    "closed_at",
+    "comments",
+    "body",
+]
+
+
+def _comment_header(comment: Comment) -> str:
+    return f"{comment.user.login} on {comment.created_at.date().isoformat()}"
+
+
+def _to_json(
+    project: str, issues: list[Issue], comments: dict[int, list[Comment]] | None
+) -> str:
+    data: dict[str, object] = {
+        "project": project,
+        "issues": [issue.model_dump(mode="json", by_alias=True) for issue in issues],
+    }
+    if comments is not None:
+        data["comments"] = {
+            str(number): [c.model_dump(mode="json", by_alias=True) for c in thread]
+            for number, thread in comments.items()
+        }
+    return json.dumps(data, indent=2) + "\n"
+
+
+def _to_csv(issues: list[Issue], comments: dict[int, list[Comment]] | None) -> str:
+    out = io.StringIO()
+    writer = csv.writer(out, lineterminator="\n")
+    writer.writerow(CSV_COLUMNS + (["discussion"] if comments is not None else []))
+    for issue in issues:
+        row = [
+            issue.number,
+            issue.title,
+            issue.state,
+            ", ".join(label.name for label in issue.labels),
+            ", ".join(user.login for user in issue.assignees),
+            issue.milestone.title if issue.milestone else "",
+            issue.user.login,
+            issue.created_at.isoformat(),
+            issue.updated_at.isoformat(),
+            issue.closed_at.isoformat() if issue.closed_at else "",
+            issue.comments,
+            issue.body or "",
+        ]
+        if comments is not None:
+            row.append(
+                "\n\n".join(
+                    f"{_comment_header(c)}: {c.body}"
+                    for c in comments.get(issue.number, [])
+                )
+            )
+        writer.writerow(row)
+    return out.getvalue()
+
+
+def _details(issue: Issue) -> list[str]:
+    details = [
+        f"State: {issue.state}",
+        f"Author: {issue.user.login}",
+        f"Created: {issue.created_at.date().isoformat()}",
+    ]
+    if issue.labels:
+        details.append("Labels: " + ", ".join(label.name for label in issue.labels))
+    if issue.assignees:
+        details.append("Assignees: " + ", ".join(u.login for u in issue.assignees))
+    if issue.milestone:
+        details.append(f"Milestone: {issue.milestone.title}")
+    return [f"- {detail}" for detail in details]
+
+
+def _to_markdown(
+    project: str, issues: list[Issue], comments: dict[int, list[Comment]] | None
+) -> str:
+    lines = [f"# {project}", ""]
+    for issue in issues:
+        lines += [f"## #{issue.number} {issue.title}", ""]
+        lines += _details(issue) + [""]
+        if issue.body:
+            lines += [issue.body.strip(), ""]
+        thread = (comments or {}).get(issue.number, [])
+        if thread:
+            lines += ["### Comments", ""]
+            for comment in thread:
+                lines += [f"**{_comment_header(comment)}**", "", comment.body.strip(), ""]
+    return "\n".join(lines)
+
+
+def render_issues(
+    project: str,
+    issues: list[Issue],
+    fmt: IssueExportFormat,
+    comments: dict[int, list[Comment]] | None = None,
+) -> str:
+    """*issues* in *fmt*, with each issue's comments when *comments* is given."""
+    if fmt == "json":
+        return _to_json(project, issues, comments)
+    if fmt == "csv":
+        return _to_csv(issues, comments)
+    return _to_markdown(project, issues, comments)
+
+
+def render_issue_markdown(project: str, issue: Issue, comments: list[Comment]) -> str:
+    """One issue and its whole thread as a document that stands on its own."""
+    lines = [f"# {issue.title} (#{issue.number})", "", f"- Project: {project}"]
+    lines += _details(issue)
+    if issue.closed_at:
+        lines.append(f"- Closed: {issue.closed_at.date().isoformat()}")
+    if issue.branch:
+        lines.append(f"- Branch: {issue.branch}")
+    lines += [f"- Pull request: {pr.url} ({pr.state})" for pr in issue.linked_prs]
+    lines.append("")
+    if issue.body:
+        lines += [issue.body.strip(), ""]
+    if comments:
+        lines += ["## Comments", ""]
+        for comment in comments:
+            lines += [f"### {_comment_header(comment)}", "", comment.body.strip(), ""]
+    return "\n".join(lines)
+
+
+def copy_attachments(markdown: str, bases: list[Path], folder: Path) -> tuple[str, int]:
+    """Copy local files linked from *markdown* into *folder* and relink them.
+
+    Links are looked up under each of *bases* in turn. Anything outside
+    them is left alone, since issue text may come from other people and the
+    export is meant to be shared. Returns the rewritten text and the number
+    of files copied.
+    """
+    copied: dict[Path, str] = {}
+    roots = [base.resolve() for base in bases]
+
+    def relink(match: re.Match[str]) -> str:
+        candidates = [(root / match.group(2)).resolve() for root in roots]
+        source = next(
+            (
+                c
+                for c, root in zip(candidates, roots)
+                if c.is_relative_to(root) and c.is_file()
+            ),
+            None,
+        )
+        if source is None:
+            return match.group(0)
+        if source not in copied:
+            name, n = source.name, 1
+            while name in copied.values():
+                n += 1
+                name = f"{n}-{source.name}"
+            folder.mkdir(parents=True, exist_ok=True)
+            shutil.copy2(source, folder / name)
+            copied[source] = name
+        return f"{match.group(1)}{folder.name}/{copied[source]}{match.group(3)}"
+
+    return _LOCAL_LINK.sub(relink, markdown), len(copied)
@@ -0,0 +1,176 @@
+"""Create issues in bulk from spreadsheets and Jira CSV exports."""
+
+from __future__ import annotations
+
+import csv
+import io
+import re
+from datetime import datetime, timezone
+
+from .core.models import (
+    Comment,
+    ImportColumnMapping,
+    Issue,
+    IssueImportFormat,
+    SimpleUser,
+)
+from .core.storage import ProjectStorage
+from .github_issues import ImportResult, _import_labels, _import_milestones
+
+# Labels created for names the project does not have yet.
+IMPORTED_LABEL_COLOR = "ededed"
+
+JIRA_MAPPING = ImportColumnMapping(
+    title="Summary",
+    body="Description",
+    state="Status",
+    labels="Labels",
+    assignees="Assignee",
+    milestone="Sprint",
+    author="Reporter",
+    created_at="Created",
+    closed_at="Resolved",
+    comments="Comment",
+)
+
+_CLOSED_STATES = {"closed", "done", "resolved", "complete", "completed", "won't do"}
+_DATE_FORMATS = ["%d/%b/%y %I:%M %p", "%d/%b/%Y %I:%M %p", "%Y-%m-%d %H:%M"]
+
+
+class _Row:
+    """A CSV row whose values are looked up by header, repeats included."""
+
+    def __init__(self, header: list[str], values: list[str]) -> None:
+        self._cells: dict[str, list[str]] = {}
+        for column, value in zip(header, values):
+            if value.strip():
+                self._cells.setdefault(column.strip(), []).append(value.strip())
+
+    def all(self, column: str | None) -> list[str]:
+        return self._cells.get(column, []) if column else []
+
+    def first(self, column: str | None) -> str | None:
+        values = self.all(column)
+        return values[0] if values else None
+
+
+def _parse_date(value: str | None) -> datetime | None:
+    if not value:
+        return None
+    try:
+        parsed = datetime.fromisoformat(value)
+    except ValueError:
+        for fmt in _DATE_FORMATS:
+            try:
+                parsed = datetime.strptime(value, fmt)
+                break
+            except ValueError:
+                continue
+        else:
+            return None
+    return parsed if parsed.tzinfo else parsed.replace(tzinfo=timezone.utc)
+
+
+def _user(name: str | None) -> SimpleUser:
+    login = re.sub(r"\s+", "-", name.strip()) if name else "local-user"
+    return SimpleUser(login=login, id=0, avatar_url="", user_type="User")
+
+
+def _split(values: list[str]) -> list[str]:
+    """Names from cells that each hold one name or a comma-separated list."""
+    names: list[str] = []
+    for value in values:
+        names += [n.strip() for n in value.split(",") if n.strip()]
+    return list(dict.fromkeys(names))
+
+
+def _comment(value: str, fmt: IssueImportFormat, fallback: datetime) -> Comment:
+    """A comment cell; Jira writes them as ``date;author;body``."""
+    author: str | None = None
+    created = fallback
+    body = value
+    if fmt == "jira":
+        parts = value.split(";", 2)
+        if len(parts) == 3 and _parse_date(parts[0]) is not None:
+            created = _parse_date(parts[0]) or fallback
+            author, body = parts[1], parts[2]
+    return Comment(
+        id=0, body=body, user=_user(author), created_at=created, updated_at=created
+    )
+
+
+def import_issues(
+    storage: ProjectStorage,
+    content: str,
+    fmt: IssueImportFormat = "csv",
+    mapping: ImportColumnMapping | None = None,
+) -> ImportResult:
+    """Create an issue, with its labels, milestone and comments, per CSV row.
+
+    Labels and milestones that do not exist yet are created. Rows without a
+    title are counted as skipped. The caller commits.
+
+    Raises:
+        ValueError: The file has no header row or no column for *title*.
+    """
+    mapping = mapping or (JIRA_MAPPING if fmt == "jira" else ImportColumnMapping())
+    reader = csv.reader(io.StringIO(content))
+    header = [column.strip() for column in next(reader, [])]
+    if mapping.title not in header:
+        raise ValueError(f"No '{mapping.title}' column for issue titles")
+    rows = [_Row(header, values) for values in reader]
+
+    now = datetime.now(timezone.utc)
+    result = ImportResult()
+    labels, result.labels = _import_labels(
+        storage,
+        [
+            {"name": name, "color": IMPORTED_LABEL_COLOR}
+            for row in rows
+            for name in _split(row.all(mapping.labels))
+        ],
+    )
+    milestones, result.milestones = _import_milestones(
+        storage,
+        [
+            {"title": title, "state": "open", "created_at": now, "updated_at": now}
+            for row in rows
+            if (title := row.first(mapping.milestone))
+        ],
+    )
+
+    for row in rows:
+        title = row.first(mapping.title)
+        if title is None:
+            result.skipped_issues += 1
+            continue
+        created = _parse_date(row.first(mapping.created_at)) or now
+        status = (row.first(mapping.state) or "").lower()
+        closed_at = _parse_date(row.first(mapping.closed_at))
+        is_closed = status in _CLOSED_STATES or (not status and closed_at is not None)
+        milestone = row.first(mapping.milestone)
+        number = storage.next_issue_id()
+        issue = Issue(
+            id=number,
+            number=number,
+            title=title,
+            body=row.first(mapping.body),
+            state="closed" if is_closed else "open",
+            state_reason="completed" if is_closed else None,
+            labels=[labels[n] for n in _split(row.all(mapping.labels)) if n in labels],
+            assignees=[_user(n) for n in _split(row.all(mapping.assignees))],
+            created_at=created,
+            updated_at=closed_at or created,
+            closed_at=(closed_at or now) if is_closed else None,
+            user=_user(row.first(mapping.author)),
+            milestone=milestones.get(milestone) if milestone else None,
+        )
+        thread = [_comment(value, fmt, created) for value in row.all(mapping.comments)]
+        for comment in thread:
+            comment.id = storage.next_comment_id()
+            storage.write_comment(number, comment)
+        issue.comments = len(thread)
+        storage.write_issue(issue)
+        result.issues += 1
+        result.comments += len(thread)
+    return result
@@ -0,0 +1,41 @@
+"""OS keyring access for secrets.
+
+Falls back gracefully when no keyring backend is available (e.g. headless
+servers or containers), in which case callers keep the secret on disk.
+"""
+
+import logging
+
+import keyring
+from keyring.errors import KeyringError
+
+logger = logging.getLogger(__name__)
+
+SERVICE_NAME = "attractor"
+
+
+def set_secret(key: str, value: str) -> bool:
+    """Store *value* under *key*. Returns False if the keyring is unusable."""
+    try:
+        keyring.set_password(SERVICE_NAME, key, value)
+    except KeyringError as exc:
+        logger.warning("Keyring unavailable, keeping secret on disk: %s", exc)
+        return False
+    return True
+
+
+def get_secret(key: str) -> str | None:
+    """Return the secret stored under *key*, or None if missing."""
+    try:
+        return keyring.get_password(SERVICE_NAME, key)
+    except KeyringError as exc:
+        logger.warning("Failed to read secret from keyring: %s", exc)
+        return None
+
+
+def delete_secret(key: str) -> None:
+    """Remove the secret stored under *key*, ignoring missing entries."""
+    try:
+        keyring.delete_password(SERVICE_NAME, key)
+    except KeyringError:
+        pass
@@ -3,35 +3,115 @@
 Run with: uv run fastapi dev src/issues_server/main.py
 """
 
-from collections.abc import AsyncGenerator
+import logging
+from collections.abc import AsyncGenerator, Awaitable, Callable
 from contextlib import asynccontextmanager
 
-from fastapi import FastAPI, WebSocket, WebSocketDisconnect
+from fastapi import FastAPI, Request, WebSocket, WebSocketDisconnect
 from fastapi.middleware.cors import CORSMiddleware
+from fastapi.responses import JSONResponse, Response
 from fastapi.staticfiles import StaticFiles
-
-from .deps import get_settings, get_ws_manager
+from starlette.exceptions import HTTPException as StarletteHTTPException
+
+from .comment_commands import run_new_commands
+from .core.errors import AppError, http_error_detail
+from .core.logs import configure_logging, span
+from .core.network import configure_network
+from .deps import (
+    get_mirror_scheduler,
+    get_notification_scheduler,
+    get_notifications,
+    get_pull_request_scheduler,
+    get_settings,
+    get_store_sync_scheduler,
+    get_update_scheduler,
+    get_ws_manager,
+)
+from .frontend import notify_available_update
 from .routes import (
+    activity,
     amplifier,
+    assignees,
+    autocomplete,
+    avatars,
+    backup,
     comments,
     config,
+    doctor,
     filesystem,
     github_auth,
+    github_compat,
+    github_issues,
     issues,
     labels,
+    logs,
+    milestones,
+    network,
+    notifications,
+    open_projects,
+    openers,
+    project_repo,
+    project_settings,
     projects,
+    providers,
+    quick_switcher,
     store,
+    tray,
+    updates,
+    workspaces,
 )
 
+logger = logging.getLogger(__name__)
+
 
 @asynccontextmanager
 async def lifespan(app: FastAPI) -> AsyncGenerator[None]:
     settings = get_settings()
+    configure_logging(settings)
+    configure_network(settings)
     projects_dir = settings.data_dir / "projects"
     projects_dir.mkdir(parents=True, exist_ok=True)
     stores_dir = settings.data_dir / "stores"
     stores_dir.mkdir(parents=True, exist_ok=True)
+    github_auth.migrate_tokens_to_keyring(settings)
+    mirror_scheduler = get_mirror_scheduler()
+    mirror_scheduler.start(
+        settings.mirror_interval,
+        lambda: github_issues.sync_all_mirrors(
+            settings, get_ws_manager(), mirror_scheduler
+        ),
+    )
+    pull_request_scheduler = get_pull_request_scheduler()
+    pull_request_scheduler.start(
+        settings.pull_request_interval,
+        lambda: github_issues.refresh_all_linked_prs(settings, get_ws_manager()),
+    )
+    notification_scheduler = get_notification_scheduler()
+
+    async def scan_stores() -> None:
+        await notifications.broadcast_new_notifications(
+            settings, get_notifications(), get_ws_manager()
+        )
+        await run_new_commands(settings, get_ws_manager())
+
+    notification_scheduler.start(settings.notification_interval, scan_stores)
+    store.start_auto_sync(
+        settings, config._read_config(settings.data_dir).settings.auto_sync_interval
+    )
+    update_scheduler = get_update_scheduler()
+    if settings.production:
+        update_scheduler.start(
+            settings.update_check_interval,
+            lambda: notify_available_update(
+                settings, get_ws_manager(), update_scheduler
+            ),
+        )
     yield
+    mirror_scheduler.stop()
+    pull_request_scheduler.stop()
+    notification_scheduler.stop()
+    get_store_sync_scheduler().stop()
+    update_scheduler.stop()
 
 
 app = FastAPI(title="Attractor Issues Server", lifespan=lifespan)
@@ -45,17 +125,76 @@ if not get_settings().production:
         allow_headers=["*"],
     )
 
+
+@app.middleware("http")
+async def log_api_requests(
+    request: Request, call_next: Callable[[Request], Awaitable[Response]]
+) -> Response:
+    if not request.url.path.startswith("/api/"):
+        return await call_next(request)
+    with span(logger, f"{request.method} {request.url.path}"):
+        response = await call_next(request)
+    if response.status_code >= 500:
+        logger.error(
+            "%s %s returned %d",
+            request.method,
+            request.url.path,
+            response.status_code,
+        )
+    return response
+
+
+@app.exception_handler(AppError)
+async def app_error_handler(request: Request, exc: AppError) -> JSONResponse:
+    return JSONResponse(status_code=exc.status_code, content={"detail": exc.detail()})
+
+
+@app.exception_handler(StarletteHTTPException)
+async def http_error_handler(
+    request: Request, exc: StarletteHTTPException
+) -> JSONResponse:
+    return JSONResponse(
+        status_code=exc.status_code,
+        content={"detail": http_error_detail(exc.status_code, exc.detail)},
+        headers=exc.headers,
+    )
+
+
 # --- API routes (all under /api) -------------------------------------------
 
 app.include_router(projects.router, prefix="/api")
+app.include_router(project_settings.router, prefix="/api")
+app.include_router(project_repo.router, prefix="/api")
+app.include_router(backup.router, prefix="/api")
+app.include_router(open_projects.router, prefix="/api")
+app.include_router(workspaces.router, prefix="/api")
+app.include_router(quick_switcher.router, prefix="/api")
+app.include_router(notifications.router, prefix="/api")
+app.include_router(tray.router, prefix="/api")
 app.include_router(issues.router, prefix="/api")
+app.include_router(assignees.router, prefix="/api")
+app.include_router(autocomplete.router, prefix="/api")
 app.include_router(comments.router, prefix="/api")
 app.include_router(labels.router, prefix="/api")
+app.include_router(milestones.router, prefix="/api")
 app.include_router(amplifier.router, prefix="/api")
 app.include_router(github_auth.router, prefix="/api")
+app.include_router(avatars.router, prefix="/api")
 app.include_router(store.router, prefix="/api")
+app.include_router(providers.router, prefix="/api")
+app.include_router(github_issues.router, prefix="/api")
 app.include_router(config.router, prefix="/api")
 app.include_router(filesystem.router, prefix="/api")
+app.include_router(activity.router, prefix="/api")
+app.include_router(network.router, prefix="/api")
+app.include_router(doctor.router, prefix="/api")
+app.include_router(logs.router, prefix="/api")
+app.include_router(openers.router, prefix="/api")
+app.include_router(updates.router, prefix="/api")
+
+# GitHub-compatible issue routes sit at the root, where GitHub clients expect them.
+if get_settings().github_compat_api:
+    app.include_router(github_compat.router)
 
 
 @app.get("/api/health")
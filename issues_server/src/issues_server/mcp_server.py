@@ -0,0 +1,250 @@
+"""Model Context Protocol server exposing the issue tracker as agent tools.
+
+Started with ``issues-server --mcp``. Speaks newline-delimited JSON-RPC 2.0
+over stdin/stdout, so stdout carries protocol messages only; logs go to the
+log file. Tools call the same route handlers as the app, so project policies
+apply and every change is committed and pushed to the store.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import json
+import logging
+import sys
+from collections.abc import Awaitable, Callable
+from typing import Any
+
+from fastapi import HTTPException
+from pydantic import ValidationError
+
+from .core.config import Settings
+from .core.errors import AppError
+from .core.models import (
+    CreateCommentRequest,
+    CreateIssueRequest,
+    IssueFilters,
+    UpdateIssueRequest,
+)
+from .deps import get_ws_manager
+from .routes import comments, issues
+from .routes.projects import get_project_storage, list_projects
+
+logger = logging.getLogger(__name__)
+
+PROTOCOL_VERSION = "2025-06-18"
+
+_PROJECT = {"type": "string", "description": "Project name"}
+_NUMBER = {"type": "integer", "description": "Issue number"}
+_STRINGS = {"type": "array", "items": {"type": "string"}}
+
+TOOLS: list[dict[str, Any]] = [
+    {
+        "name": "list_issues",
+        "description": "List issues in a project, newest first.",
+        "inputSchema": {
+            "type": "object",
+            "properties": {
+                "project": _PROJECT,
+                "state": {"type": "string", "enum": ["open", "closed", "all"]},
+                "reason": {
+                    "type": "string",
+                    "enum": ["completed", "not_planned", "reopened"],
+                },
+                "labels": {"type": "string", "description": "Comma-separated labels"},
+                "assignee": {"type": "string"},
+                "mentioned": {"type": "string"},
+                "page": {"type": "integer", "minimum": 1},
+                "per_page": {"type": "integer", "minimum": 1, "maximum": 100},
+            },
+        },
+    },
+    {
+        "name": "create_issue",
+        "description": "Create an issue.",
+        "inputSchema": {
+            "type": "object",
+            "properties": {
+                "project": _PROJECT,
+                "title": {"type": "string"},
+                "body": {"type": "string"},
+                "labels": _STRINGS,
+                "assignees": _STRINGS,
+            },
+            "required": ["title"],
+        },
+    },
+    {
+        "name": "comment",
+        "description": "Add a comment to an issue.",
+        "inputSchema": {
+            "type": "object",
+            "properties": {
+                "project": _PROJECT,
+                "number": _NUMBER,
+                "body": {"type": "string"},
+            },
+            "required": ["number", "body"],
+        },
+    },
+    {
+        "name": "update_issue",
+        "description": "Change an issue's title, body, state, labels, or assignees.",
+        "inputSchema": {
+            "type": "object",
+            "properties": {
+                "project": _PROJECT,
+                "number": _NUMBER,
+                "title": {"type": "string"},
+                "body": {"type": "string"},
+                "state": {"type": "string", "enum": ["open", "closed"]},
+                "state_reason": {
+                    "type": "string",
+                    "enum": ["completed", "not_planned", "reopened"],
+                },
+                "labels": _STRINGS,
+                "assignees": _STRINGS,
+            },
+            "required": ["number"],
+        },
+    },
+]
+
+
+class ToolError(Exception):
+    """Reported to the client as a tool result with ``isError`` set."""
+
+
+class McpServer:
+    def __init__(self, settings: Settings, project: str | None = None) -> None:
+        self.settings = settings
+        self.project = project  # used when a tool call names no project
+        self._tools: dict[str, Callable[[dict[str, Any]], Awaitable[object]]] = {
+            "list_issues": self._list_issues,
+            "create_issue": self._create_issue,
+            "comment": self._comment,
+            "update_issue": self._update_issue,
+        }
+
+    def _project_name(self, args: dict[str, Any]) -> str:
+        name = args.pop("project", None) or self.project
+        if name is None:
+            projects = [p.name for p in list_projects(self.settings)]
+            if len(projects) != 1:
+                raise ToolError(
+                    f"Name a project; available projects: {', '.join(projects)}"
+                )
+            name = projects[0]
+        return name
+
+    async def _list_issues(self, args: dict[str, Any]) -> object:
+        name = self._project_name(args)
+        storage = get_project_storage(name, self.settings)
+        return storage.list_issues(IssueFilters(**args))
+
+    async def _create_issue(self, args: dict[str, Any]) -> object:
+        name = self._project_name(args)
+        storage = get_project_storage(name, self.settings)
+        return await issues.create_issue(
+            name, CreateIssueRequest(**args), self.settings, storage, get_ws_manager()
+        )
+
+    async def _comment(self, args: dict[str, Any]) -> object:
+        name = self._project_name(args)
+        storage = get_project_storage(name, self.settings)
+        number = args.pop("number")
+        return await comments.create_comment(
+            name, number, CreateCommentRequest(**args), storage, get_ws_manager()
+        )
+
+    async def _update_issue(self, args: dict[str, Any]) -> object:
+        name = self._project_name(args)
+        storage = get_project_storage(name, self.settings)
+        number = args.pop("number")
+        return await issues.update_issue(
+            name,
+            number,
+            UpdateIssueRequest(**args),
+            self.settings,
+            storage,
+            get_ws_manager(),
+        )
+
+    async def call_tool(self, name: str, args: dict[str, Any]) -> dict[str, Any]:
+        try:
+            result = await self._tools[name](dict(args))
+        except KeyError as exc:
+            return _tool_result(f"Missing argument: {exc}", error=True)
+        except (ToolError, ValidationError) as exc:
+            return _tool_result(str(exc), error=True)
+        except HTTPException as exc:
+            return _tool_result(str(exc.detail), error=True)
+        except AppError as exc:
+            return _tool_result(exc.message, error=True)
+        return _tool_result(_to_json(result))
+
+    async def handle(self, message: dict[str, Any]) -> dict[str, Any] | None:
+        """The response to one JSON-RPC message, or None for notifications."""
+        method = message.get("method")
+        if "id" not in message:
+            return None
+        params = message.get("params") or {}
+        try:
+            if method == "initialize":
+                result: object = {
+                    "protocolVersion": params.get("protocolVersion", PROTOCOL_VERSION),
+                    "capabilities": {"tools": {}},
+                    "serverInfo": {"name": "attractor-issues", "version": "0.1.0"},
+                }
+            elif method == "ping":
+                result = {}
+            elif method == "tools/list":
+                result = {"tools": TOOLS}
+            elif method == "tools/call":
+                if params.get("name") not in self._tools:
+                    return _error(message["id"], -32602, "Unknown tool")
+                result = await self.call_tool(
+                    params["name"], params.get("arguments") or {}
+                )
+            else:
+                return _error(message["id"], -32601, f"Method not found: {method}")
+        except Exception as exc:
+            logger.exception("MCP request %s failed", method)
+            return _error(message["id"], -32603, str(exc))
+        return {"jsonrpc": "2.0", "id": message["id"], "result": result}
+
+
+def _to_json(value: object) -> str:
+    if hasattr(value, "model_dump"):
+        value = value.model_dump(mode="json", by_alias=True)
+    return json.dumps(value, indent=2)
+
+
+def _tool_result(text: str, error: bool = False) -> dict[str, Any]:
+    return {"content": [{"type": "text", "text": text}], "isError": error}
+
+
+def _error(request_id: object, code: int, message: str) -> dict[str, Any]:
+    error = {"code": code, "message": message}
+    return {"jsonrpc": "2.0", "id": request_id, "error": error}
+
+
+async def _serve(server: McpServer) -> None:
+    loop = asyncio.get_running_loop()
+    while line := await loop.run_in_executor(None, sys.stdin.readline):
+        if not line.strip():
+            continue
+        try:
+            message = json.loads(line)
+        except json.JSONDecodeError:
+            response: dict[str, Any] | None = _error(None, -32700, "Parse error")
+        else:
+            response = await server.handle(message)
+        if response is not None:
+            sys.stdout.write(json.dumps(response) + "\n")
+            sys.stdout.flush()
+
+
+def run_stdio(settings: Settings, project: str | None = None) -> None:
+    """Serve MCP on stdin/stdout until the client closes stdin."""
+    asyncio.run(_serve(McpServer(settings, project)))
@@ -0,0 +1,310 @@
+"""In-app notifications with local read/unread state.
+
+Amplifier completions and sync conflicts are recorded as they happen.
+Assignments, mentions and sync changes are derived by scanning each store's
+history since the last scan: commits authored elsewhere, plus GitHub
+imports, are checked for issues newly assigned to the user and for
+``@login`` mentions. Open milestones due within a day are announced once
+per due date.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import json
+import logging
+import re
+import threading
+import uuid
+from collections.abc import Awaitable, Callable
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+
+from .core.models import (
+    Comment,
+    Issue,
+    Notification,
+    NotificationKind,
+    NotificationState,
+    StoreCommit,
+)
+from .core.storage import ProjectStorage, parse_mentions, write_text_atomic
+from .ws import WebSocketManager
+
+logger = logging.getLogger(__name__)
+
+MAX_NOTIFICATIONS = 200
+MILESTONE_NOTICE = timedelta(days=1)
+
+# Commits we author ourselves that carry changes made by other people.
+_IMPORT_EVENTS = {"github.import", "file.import", "mirror.sync"}
+
+_ISSUE_PATH = re.compile(r"^issues/(\d+)\.json$")
+_COMMENT_PATH = re.compile(r"^comments/(\d+)/\d+\.json$")
+
+
+def _mentions(text: str | None) -> set[str]:
+    return {login.lower() for login in parse_mentions(text)}
+
+
+def new_notification(
+    kind: NotificationKind,
+    project: str,
+    title: str,
+    issue_number: int | None = None,
+) -> Notification:
+    return Notification(
+        id=uuid.uuid4().hex,
+        kind=kind,
+        project=project,
+        issue_number=issue_number,
+        title=title,
+        created_at=datetime.now(timezone.utc),
+    )
+
+
+async def notify(
+    center: NotificationCenter, ws: WebSocketManager, notification: Notification
+) -> None:
+    """Record *notification* and push it to clients as ``notification:created``."""
+    center.add([notification])
+    await ws.broadcast("notification:created", notification.model_dump(mode="json"))
+
+
+class NotificationCenter:
+    """Reads and writes ``notifications.json``; safe to share across threads."""
+
+    def __init__(self, data_dir: Path) -> None:
+        self.path = data_dir / "notifications.json"
+        self._lock = threading.Lock()
+
+    def _read(self) -> NotificationState:
+        if not self.path.exists():
+            return NotificationState()
+        return NotificationState.model_validate_json(self.path.read_text())
+
+    def _write(self, state: NotificationState) -> None:
+        state.notifications = state.notifications[:MAX_NOTIFICATIONS]
+        write_text_atomic(
+            self.path, json.dumps(state.model_dump(mode="json"), indent=2) + "\n"
+        )
+
+    def add(self, notifications: list[Notification]) -> None:
+        if not notifications:
+            return
+        with self._lock:
+            state = self._read()
+            state.notifications = list(reversed(notifications)) + state.notifications
+            self._write(state)
+
+    def list(self) -> list[Notification]:
+        """Notifications, newest first."""
+        with self._lock:
+            return self._read().notifications
+
+    def mark_read(self, notification_id: str, read: bool = True) -> Notification | None:
+        with self._lock:
+            state = self._read()
+            match = next(
+                (n for n in state.notifications if n.id == notification_id), None
+            )
+            if match is not None and match.read != read:
+                match.read = read
+                self._write(state)
+            return match
+
+    def mark_all_read(self) -> int:
+        with self._lock:
+            state = self._read()
+            unread = [n for n in state.notifications if not n.read]
+            for n in unread:
+                n.read = True
+            if unread:
+                self._write(state)
+            return len(unread)
+
+    def rename_project(self, old_name: str, new_name: str) -> None:
+        with self._lock:
+            state = self._read()
+            for n in state.notifications:
+                if n.project == old_name:
+                    n.project = new_name
+            if old_name in state.cursors:
+                state.cursors[new_name] = state.cursors.pop(old_name)
+            prefix = f"{old_name}:"
+            state.notified_milestones = [
+                f"{new_name}:{k[len(prefix):]}" if k.startswith(prefix) else k
+                for k in state.notified_milestones
+            ]
+            self._write(state)
+
+    def remove_project(self, name: str) -> None:
+        with self._lock:
+            state = self._read()
+            state.notifications = [n for n in state.notifications if n.project != name]
+            state.cursors.pop(name, None)
+            state.notified_milestones = [
+                k for k in state.notified_milestones if not k.startswith(f"{name}:")
+            ]
+            self._write(state)
+
+    def scan(
+        self,
+        project: str,
+        storage: ProjectStorage,
+        logins: set[str],
+        emails: set[str],
+    ) -> list[Notification]:
+        """Record notifications for store commits made since the last scan.
+
+        The first scan of a project only sets its cursor, so existing history
+        does not flood the list.
+        """
+        head = storage.head()
+        if head is None:
+            return []
+        own_email = storage.author_email()
+        emails = emails | ({own_email} if own_email else set())
+        logins = {login.lower() for login in logins}
+
+        def is_own(commit: StoreCommit) -> bool:
+            # Commits authored as the bot are told apart by their actor trailer.
+            if commit.actor is not None:
+                return commit.actor.lower() in logins or commit.actor == storage.actor
+            return commit.author_email in emails
+
+        with self._lock:
+            state = self._read()
+            cursor = state.cursors.get(project)
+            if cursor == head:
+                return []
+            commits = storage.commits_since(cursor) if cursor else None
+            state.cursors[project] = head
+
+            found: list[Notification] = []
+            foreign = [c for c in commits or [] if not is_own(c)]
+            for commit in commits or []:
+                if commit in foreign or commit.event in _IMPORT_EVENTS:
+                    found.extend(self._from_commit(project, storage, commit, logins))
+            if foreign:
+                count = len(foreign)
+                found.append(
+                    new_notification(
+                        "sync",
+                        project,
+                        f"{count} new change{'s' if count != 1 else ''} synced",
+                    )
+                )
+
+            state.notifications = list(reversed(found)) + state.notifications
+            self._write(state)
+            return found
+
+    def check_milestones(
+        self, project: str, storage: ProjectStorage
+    ) -> list[Notification]:
+        """Announce open milestones that are due within :data:`MILESTONE_NOTICE`."""
+        with self._lock:
+            state = self._read()
+            found: list[Notification] = []
+            for milestone in storage.list_milestones(due_within=MILESTONE_NOTICE):
+                due = milestone.due_on
+                assert due is not None
+                key = f"{project}:{milestone.number}:{due.isoformat()}"
+                if key in state.notified_milestones:
+                    continue
+                state.notified_milestones.append(key)
+                verb = "was" if milestone.is_overdue else "is"
+                found.append(
+                    new_notification(
+                        "milestone",
+                        project,
+                        f"Milestone {milestone.title} {verb} due {due:%Y-%m-%d %H:%M}",
+                    )
+                )
+            if found:
+                state.notifications = list(reversed(found)) + state.notifications
+                state.notified_milestones = state.notified_milestones[-MAX_NOTIFICATIONS:]
+                self._write(state)
+            return found
+
+    @staticmethod
+    def _from_commit(
+        project: str,
+        storage: ProjectStorage,
+        commit: StoreCommit,
+        logins: set[str],
+    ) -> list[Notification]:
+        found: list[Notification] = []
+        for path in commit.files:
+            if _ISSUE_PATH.match(path):
+                after = storage.read_json_at(commit.sha, path)
+                if after is None:
+                    continue
+                issue = Issue.model_validate(after)
+                before_data = storage.read_json_at(f"{commit.sha}^", path)
+                before = Issue.model_validate(before_data) if before_data else None
+                assigned = {a.login.lower() for a in issue.assignees}
+                if before is not None:
+                    assigned -= {a.login.lower() for a in before.assignees}
+                if assigned & logins:
+                    found.append(
+                        new_notification(
+                            "assignment",
+                            project,
+                            f"Assigned to you: #{issue.number} {issue.title}",
+                            issue.number,
+                        )
+                    )
+                mentioned = _mentions(issue.body)
+                if before is not None:
+                    mentioned -= _mentions(before.body)
+                if mentioned & logins:
+                    found.append(
+                        new_notification(
+                            "mention",
+                            project,
+                            f"Mentioned in #{issue.number} {issue.title}",
+                            issue.number,
+                        )
+                    )
+            elif match := _COMMENT_PATH.match(path):
+                number = int(match.group(1))
+                after = storage.read_json_at(commit.sha, path)
+                if after is None or storage.read_json_at(f"{commit.sha}^", path):
+                    continue
+                comment = Comment.model_validate(after)
+                if _mentions(comment.body) & logins:
+                    found.append(
+                        new_notification(
+                            "mention",
+                            project,
+                            f"{comment.user.login} mentioned you on #{number}",
+                            number,
+                        )
+                    )
+        return found
+
+
+class NotificationScheduler:
+    """Scan stores on an interval so notifications arrive without a request."""
+
+    def __init__(self) -> None:
+        self._task: asyncio.Task | None = None  # type: ignore[type-arg]
+
+    def start(self, interval: float, run: Callable[[], Awaitable[None]]) -> None:
+        self.stop()
+        self._task = asyncio.create_task(self._loop(interval, run))
+
+    def stop(self) -> None:
+        if self._task is not None:
+            self._task.cancel()
+            self._task = None
+
+    async def _loop(self, interval: float, run: Callable[[], Awaitable[None]]) -> None:
+        while True:
+            await asyncio.sleep(interval)
+            try:
+                await run()
+            except Exception:
+                logger.exception("Scheduled notification scan failed")
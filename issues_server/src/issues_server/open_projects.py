@@ -0,0 +1,51 @@
+"""Registry of the projects the app currently has open.
+
+Opening a project (``GET /projects/{name}``) registers it; closing releases
+its per-project resources, such as running Amplifier sessions. Several
+projects can be open at once, each addressed by name.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+from datetime import datetime, timezone
+
+
+@dataclass
+class OpenProject:
+    name: str
+    store_path: str
+    opened_at: datetime
+    last_accessed: datetime
+
+
+class OpenProjectRegistry:
+    def __init__(self) -> None:
+        self._projects: dict[str, OpenProject] = {}
+
+    def open(self, name: str, store_path: str) -> OpenProject:
+        """Register *name*, or refresh its last-access time if already open."""
+        now = datetime.now(timezone.utc)
+        project = self._projects.get(name)
+        if project is None:
+            project = OpenProject(name, store_path, opened_at=now, last_accessed=now)
+            self._projects[name] = project
+        else:
+            project.store_path = store_path
+            project.last_accessed = now
+        return project
+
+    def close(self, name: str) -> OpenProject | None:
+        return self._projects.pop(name, None)
+
+    def rename(self, old_name: str, new_name: str) -> None:
+        project = self._projects.pop(old_name, None)
+        if project is not None:
+            project.name = new_name
+            self._projects[new_name] = project
+
+    def list(self) -> list[OpenProject]:
+        """Open projects, most recently accessed first."""
+        return sorted(
+            self._projects.values(), key=lambda p: p.last_accessed, reverse=True
+        )
@@ -0,0 +1,195 @@
+"""Open paths in the user's editor, terminal or file manager on the server's machine.
+
+Each program is a command template with a ``{path}`` placeholder, plus
+``{line}`` for editors. Without a configured template the first known
+program found for the platform is used.
+"""
+
+from __future__ import annotations
+
+import os
+import shlex
+import shutil
+import subprocess
+import sys
+from pathlib import Path
+
+from .core.errors import AppError
+
+# Checked in order when no editor is configured.
+EDITOR_PRESETS: dict[str, str] = {
+    "code": "code --goto {path}:{line}",
+    "cursor": "cursor --goto {path}:{line}",
+    "zed": "zed {path}:{line}",
+    "subl": "subl {path}:{line}",
+    "idea": "idea --line {line} {path}",
+    "pycharm": "pycharm --line {line} {path}",
+}
+
+
+# Linux terminals in order of preference; $TERMINAL is tried first.
+TERMINAL_PRESETS: dict[str, str] = {
+    "x-terminal-emulator": "x-terminal-emulator",
+    "gnome-terminal": "gnome-terminal --working-directory={path}",
+    "konsole": "konsole --workdir {path}",
+    "xfce4-terminal": "xfce4-terminal --working-directory={path}",
+    "kitty": "kitty --directory {path}",
+    "alacritty": "alacritty --working-directory {path}",
+    "wezterm": "wezterm start --cwd {path}",
+    "xterm": "xterm",
+}
+
+
+class OpenerNotFoundError(AppError):
+    """No configured or detectable program to open the path with."""
+
+    status_code = 404
+    code = "OPENER_NOT_FOUND"
+
+
+def detect_editor() -> str | None:
+    """The template of the first preset editor on PATH."""
+    return next(
+        (template for name, template in EDITOR_PRESETS.items() if shutil.which(name)),
+        None,
+    )
+
+
+def detect_terminal() -> str | None:
+    """The template of the platform's terminal, or None if none is found."""
+    if sys.platform == "darwin":
+        if Path("/Applications/iTerm.app").exists():
+            return "open -a iTerm {path}"
+        return "open -a Terminal {path}"
+    if sys.platform == "win32":
+        if shutil.which("wt"):
+            return "wt -d {path}"
+        return "cmd /c start cmd /k cd /d {path}"
+    candidates = dict(TERMINAL_PRESETS)
+    if env := os.environ.get("TERMINAL"):
+        candidates = {shlex.split(env)[0]: env, **candidates}
+    return next(
+        (template for name, template in candidates.items() if shutil.which(name)),
+        None,
+    )
+
+
+def detect_file_manager() -> str:
+    """The platform's command for showing a directory."""
+    if sys.platform == "win32":
+        return "explorer {path}"
+    if sys.platform == "darwin":
+        return "open {path}"
+    return "xdg-open {path}"
+
+
+def _reveal_argv(paths: list[Path]) -> list[str]:
+    if sys.platform == "win32":
+        # Explorer can select only one item.
+        return ["explorer", f"/select,{paths[0]}"]
+    if sys.platform == "darwin":
+        return ["open", "-R", *map(str, paths)]
+    if shutil.which("dbus-send"):
+        uris = ",".join(path.as_uri() for path in paths)
+        return [
+            "dbus-send",
+            "--session",
+            "--dest=org.freedesktop.FileManager1",
+            "--type=method_call",
+            "/org/freedesktop/FileManager1",
+            "org.freedesktop.FileManager1.ShowItems",
+            f"array:string:{uris}",
+            "string:",
+        ]
+    return ["xdg-open", str(paths[0].parent)]
+
+
+def _expand(template: str, path: Path, line: int | None = None) -> list[str]:
+    return [
+        arg.replace("{path}", str(path)).replace("{line}", str(line or 1))
+        for arg in shlex.split(template)
+    ]
+
+
+def _launch(argv: list[str], cwd: Path | None = None) -> None:
+    """Start *argv* detached, so it outlives the request and the server."""
+    executable = shutil.which(argv[0])
+    if executable is None:
+        raise OpenerNotFoundError(f"'{argv[0]}' was not found on PATH", command=argv[0])
+    # Windows only runs .cmd shims such as code.cmd when given their full path.
+    detach: dict[str, object] = (
+        {
+            "creationflags": subprocess.DETACHED_PROCESS
+            | subprocess.CREATE_NEW_PROCESS_GROUP
+        }
+        if sys.platform == "win32"
+        else {"start_new_session": True}
+    )
+    subprocess.Popen(
+        [executable, *argv[1:]],
+        cwd=cwd,
+        stdin=subprocess.DEVNULL,
+        stdout=subprocess.DEVNULL,
+        stderr=subprocess.DEVNULL,
+        **detach,
+    )
+
+
+def open_in_editor(
+    path: Path, line: int | None = None, command: str | None = None
+) -> str:
+    """Open *path*, at *line* when given, and return the program used.
+
+    Raises:
+        OpenerNotFoundError: No editor is configured or found, or the
+            configured one is not on PATH.
+    """
+    template = command or detect_editor()
+    if template is None:
+        raise OpenerNotFoundError(
+            "No editor found. Install one of "
+            f"{', '.join(EDITOR_PRESETS)} or set an editor command in settings."
+        )
+    argv = _expand(template, path, line)
+    _launch(argv)
+    return argv[0]
+
+
+def open_in_terminal(path: Path, command: str | None = None) -> str:
+    """Open a terminal in *path* (a file's directory) and return the program used.
+
+    The terminal also starts with *path* as its working directory, so
+    templates without ``{path}`` work too.
+
+    Raises:
+        OpenerNotFoundError: No terminal is configured or found, or the
+            configured one is not on PATH.
+    """
+    directory = path if path.is_dir() else path.parent
+    template = command or detect_terminal()
+    if template is None:
+        raise OpenerNotFoundError(
+            "No terminal found. Set $TERMINAL or a terminal command in settings."
+        )
+    argv = _expand(template, directory)
+    _launch(argv, cwd=directory)
+    return argv[0]
+
+
+def open_in_file_manager(path: Path) -> str:
+    """Show *path* (a file's directory) in the file manager; return the program used."""
+    directory = path if path.is_dir() else path.parent
+    argv = _expand(detect_file_manager(), directory)
+    _launch(argv)
+    return argv[0]
+
+
+def reveal_in_file_manager(paths: list[Path]) -> str:
+    """Show the file manager with *paths* selected, where the platform allows.
+
+    Linux file managers that do not implement the FileManager1 D-Bus
+    interface get the first path's directory instead.
+    """
+    argv = _reveal_argv(paths)
+    _launch(argv)
+    return argv[0]
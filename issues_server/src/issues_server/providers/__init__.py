@@ -0,0 +1,25 @@
+"""Git hosting backends a store can live on."""
+
+from .azure_devops import AzureDevOpsProvider
+from .base import GitProvider, RemoteRepo
+from .gitea import GiteaProvider
+from .github import GitHubProvider
+from .gitlab import GitLabProvider
+
+PROVIDERS: dict[str, type[GitProvider]] = {
+    GitHubProvider.kind: GitHubProvider,
+    GitLabProvider.kind: GitLabProvider,
+    GiteaProvider.kind: GiteaProvider,
+    AzureDevOpsProvider.kind: AzureDevOpsProvider,
+}
+
+
+def get_provider(kind: str, token: str, base_url: str | None = None) -> GitProvider:
+    """Instantiate the provider registered as *kind*.
+
+    Raises KeyError if unknown and ValueError if *base_url* is unusable.
+    """
+    return PROVIDERS[kind](token, base_url)
+
+
+__all__ = ["PROVIDERS", "GitProvider", "RemoteRepo", "get_provider"]
@@ -0,0 +1,107 @@
+"""Azure DevOps Repos via the REST API, authenticated with a PAT.
+
+``base_url`` is the organization URL (``https://dev.azure.com/{org}``) and
+``owner`` is the Azure DevOps project that contains the repo.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import base64
+from urllib.parse import quote, urlsplit
+
+import httpx
+
+from ..core.network import http_timeout
+from .base import GitProvider, RemoteRepo
+
+API_VERSION = "7.1"
+# Agile; every organization has it and the app only uses the repo.
+PROCESS_TEMPLATE_ID = "adcc42ab-9882-485e-a3ed-7678f01f66bc"
+PROJECT_CREATE_POLLS = 30
+
+
+class AzureDevOpsProvider(GitProvider):
+    kind = "azure_devops"
+    default_base_url = "https://dev.azure.com"
+
+    def __init__(self, token: str, base_url: str | None = None) -> None:
+        super().__init__(token, base_url)
+        if not urlsplit(self.base_url).path.strip("/"):
+            raise ValueError(
+                "Azure DevOps needs the organization URL, e.g. https://dev.azure.com/{org}"
+            )
+
+    async def _request(
+        self, method: str, url: str, **kwargs: object
+    ) -> httpx.Response:
+        if not url.startswith("https://"):
+            url = f"{self.base_url}{url}"
+        auth = base64.b64encode(f":{self.token}".encode()).decode()
+        async with httpx.AsyncClient(timeout=http_timeout()) as client:
+            return await client.request(
+                method,
+                url,
+                headers={"Authorization": f"Basic {auth}"},
+                params={"api-version": API_VERSION},
+                **kwargs,
+            )
+
+    async def get_authenticated_user(self) -> str:
+        resp = await self._request("GET", "/_apis/connectionData")
+        resp.raise_for_status()
+        user = resp.json()["authenticatedUser"]
+        account = user.get("properties", {}).get("Account", {}).get("$value")
+        return account or user["providerDisplayName"]
+
+    async def repo_exists(self, owner: str, repo: str) -> bool:
+        resp = await self._request(
+            "GET",
+            f"/{quote(owner)}/_apis/git/repositories/{quote(repo)}",
+        )
+        return resp.status_code == 200
+
+    async def create_repo(
+        self, name: str, private: bool = True, description: str = ""
+    ) -> RemoteRepo:
+        """Create a project named *name*; Azure DevOps gives it a repo of the same name."""
+        resp = await self._request(
+            "POST",
+            "/_apis/projects",
+            json={
+                "name": name,
+                "description": description,
+                "visibility": "private" if private else "public",
+                "capabilities": {
+                    "versioncontrol": {"sourceControlType": "Git"},
+                    "processTemplate": {"templateTypeId": PROCESS_TEMPLATE_ID},
+                },
+            },
+        )
+        if resp.status_code in (401, 403):
+            raise PermissionError("Token lacks permission to create projects.")
+        resp.raise_for_status()
+
+        # Project creation is an async operation; the repo exists once it succeeds.
+        operation_url = resp.json()["url"]
+        for _ in range(PROJECT_CREATE_POLLS):
+            op = await self._request("GET", operation_url)
+            op.raise_for_status()
+            status = op.json()["status"]
+            if status == "succeeded":
+                break
+            if status in ("failed", "cancelled"):
+                raise RuntimeError(op.json().get("resultMessage") or status)
+            await asyncio.sleep(1.0)
+        else:
+            raise RuntimeError(f"Timed out waiting for project '{name}' to be created")
+
+        return RemoteRepo(owner=name, name=name, web_url=self.web_url(name, name))
+
+    def clone_url(self, owner: str, repo: str) -> str:
+        return self._credential_url(
+            "pat", self.token, f"{quote(owner)}/_git/{quote(repo)}"
+        )
+
+    def web_url(self, owner: str, repo: str) -> str:
+        return f"{self.base_url}/{quote(owner)}/_git/{quote(repo)}"
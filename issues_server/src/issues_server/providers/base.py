@@ -0,0 +1,65 @@
+"""Interface every git hosting backend implements."""
+
+from __future__ import annotations
+
+from abc import ABC, abstractmethod
+from urllib.parse import quote, urlsplit
+
+from pydantic import BaseModel
+
+
+class RemoteRepo(BaseModel):
+    """A repository on a git host, as returned by a provider."""
+
+    owner: str
+    name: str
+    web_url: str
+
+
+class GitProvider(ABC):
+    """Minimal repo operations needed to back a store with a hosted remote.
+
+    ``owner`` is whatever namespace the host uses in repo paths: a user,
+    organization, group, or Azure DevOps project.
+    """
+
+    kind: str
+    default_base_url: str
+
+    def __init__(self, token: str, base_url: str | None = None) -> None:
+        self.token = token
+        self.base_url = (base_url or self.default_base_url).rstrip("/")
+
+    @property
+    def host(self) -> str:
+        return urlsplit(self.base_url).netloc
+
+    @abstractmethod
+    async def get_authenticated_user(self) -> str:
+        """Return the login the token belongs to. Raises on invalid tokens."""
+
+    @abstractmethod
+    async def repo_exists(self, owner: str, repo: str) -> bool:
+        """Whether the token can see *owner*/*repo*."""
+
+    @abstractmethod
+    async def create_repo(
+        self, name: str, private: bool = True, description: str = ""
+    ) -> RemoteRepo:
+        """Create an empty repo in the token owner's namespace.
+
+        Raises PermissionError when the token may not create repos.
+        """
+
+    @abstractmethod
+    def clone_url(self, owner: str, repo: str) -> str:
+        """HTTPS clone URL with the token embedded for push and pull."""
+
+    def web_url(self, owner: str, repo: str) -> str:
+        return f"{self.base_url}/{owner}/{repo}"
+
+    def _credential_url(self, username: str, password: str, path: str) -> str:
+        # Keep any sub-path so instances served under e.g. /gitea still work.
+        parts = urlsplit(self.base_url)
+        userinfo = f"{quote(username, safe='')}:{quote(password, safe='')}"
+        return f"{parts.scheme}://{userinfo}@{parts.netloc}{parts.path}/{path}"
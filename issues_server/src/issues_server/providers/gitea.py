@@ -0,0 +1,53 @@
+"""Gitea and Forgejo (self-hosted or public instances) via the REST v1 API."""
+
+from __future__ import annotations
+
+import httpx
+
+from ..core.network import http_timeout
+from .base import GitProvider, RemoteRepo
+
+
+class GiteaProvider(GitProvider):
+    kind = "gitea"
+    default_base_url = "https://gitea.com"
+
+    async def _request(
+        self, method: str, path: str, **kwargs: object
+    ) -> httpx.Response:
+        async with httpx.AsyncClient(timeout=http_timeout()) as client:
+            return await client.request(
+                method,
+                f"{self.base_url}/api/v1{path}",
+                headers={"Authorization": f"token {self.token}"},
+                **kwargs,
+            )
+
+    async def get_authenticated_user(self) -> str:
+        resp = await self._request("GET", "/user")
+        resp.raise_for_status()
+        return resp.json()["login"]
+
+    async def repo_exists(self, owner: str, repo: str) -> bool:
+        resp = await self._request("GET", f"/repos/{owner}/{repo}")
+        return resp.status_code == 200
+
+    async def create_repo(
+        self, name: str, private: bool = True, description: str = ""
+    ) -> RemoteRepo:
+        resp = await self._request(
+            "POST",
+            "/user/repos",
+            json={"name": name, "private": private, "description": description},
+        )
+        if resp.status_code == 403:
+            raise PermissionError("Token lacks permission to create repositories.")
+        resp.raise_for_status()
+        data = resp.json()
+        return RemoteRepo(
+            owner=data["owner"]["login"], name=data["name"], web_url=data["html_url"]
+        )
+
+    def clone_url(self, owner: str, repo: str) -> str:
+        # Gitea treats the username as a token when the password is x-oauth-basic.
+        return self._credential_url(self.token, "x-oauth-basic", f"{owner}/{repo}.git")
@@ -0,0 +1,61 @@
+"""GitLab (gitlab.com or self-managed) via the REST v4 API."""
+
+from __future__ import annotations
+
+from urllib.parse import quote
+
+import httpx
+
+from ..core.network import http_timeout
+from .base import GitProvider, RemoteRepo
+
+
+class GitLabProvider(GitProvider):
+    kind = "gitlab"
+    default_base_url = "https://gitlab.com"
+
+    async def _request(
+        self, method: str, path: str, **kwargs: object
+    ) -> httpx.Response:
+        async with httpx.AsyncClient(timeout=http_timeout()) as client:
+            return await client.request(
+                method,
+                f"{self.base_url}/api/v4{path}",
+                headers={"PRIVATE-TOKEN": self.token},
+                **kwargs,
+            )
+
+    async def get_authenticated_user(self) -> str:
+        resp = await self._request("GET", "/user")
+        resp.raise_for_status()
+        return resp.json()["username"]
+
+    async def repo_exists(self, owner: str, repo: str) -> bool:
+        project_id = quote(f"{owner}/{repo}", safe="")
+        resp = await self._request("GET", f"/projects/{project_id}")
+        return resp.status_code == 200
+
+    async def create_repo(
+        self, name: str, private: bool = True, description: str = ""
+    ) -> RemoteRepo:
+        resp = await self._request(
+            "POST",
+            "/projects",
+            json={
+                "name": name,
+                "visibility": "private" if private else "public",
+                "description": description,
+            },
+        )
+        if resp.status_code == 403:
+            raise PermissionError("Token lacks permission to create projects.")
+        resp.raise_for_status()
+        data = resp.json()
+        return RemoteRepo(
+            owner=data["namespace"]["full_path"],
+            name=data["path"],
+            web_url=data["web_url"],
+        )
+
+    def clone_url(self, owner: str, repo: str) -> str:
+        return self._credential_url("oauth2", self.token, f"{owner}/{repo}.git")
@@ -0,0 +1,110 @@
+"""Pull requests linked to issues, and the periodic refresh of their state."""
+
+from __future__ import annotations
+
+import asyncio
+import logging
+import re
+from collections.abc import Awaitable, Callable
+from datetime import datetime, timezone
+
+import httpx
+
+from .core.models import Issue, IssueFilters, LinkedPullRequest, PullRequestState
+from .core.storage import ProjectStorage
+from .github_client import GitHubClient
+
+logger = logging.getLogger(__name__)
+
+_PULL_URL = re.compile(r"^https://github\.com/([^/]+)/([^/]+)/pull/(\d+)/?$")
+
+
+def parse_pull_request_url(url: str) -> tuple[str, str, int] | None:
+    """(owner, repo, number) from a github.com pull request URL."""
+    match = _PULL_URL.match(url.strip())
+    if match is None:
+        return None
+    return match.group(1), match.group(2), int(match.group(3))
+
+
+def pull_request_state(pr: dict) -> PullRequestState:
+    if pr.get("merged_at"):
+        return "merged"
+    return "closed" if pr["state"] == "closed" else "open"
+
+
+def linked_pull_request(owner: str, repo: str, pr: dict) -> LinkedPullRequest:
+    return LinkedPullRequest(
+        owner=owner,
+        repo=repo,
+        number=pr["number"],
+        url=pr["html_url"],
+        title=pr.get("title"),
+        state=pull_request_state(pr),
+    )
+
+
+def add_linked_pr(issue: Issue, linked: LinkedPullRequest) -> None:
+    """Add *linked* to the issue, replacing an earlier link to the same PR."""
+    issue.linked_prs = [
+        pr
+        for pr in issue.linked_prs
+        if (pr.owner, pr.repo, pr.number) != (linked.owner, linked.repo, linked.number)
+    ] + [linked]
+
+
+async def refresh_linked_prs(
+    storage: ProjectStorage, client: GitHubClient
+) -> list[Issue]:
+    """Update the state of every linked PR that is not merged; return changed issues.
+
+    Closed PRs are still checked because they can be reopened. PRs that can
+    no longer be fetched keep their last known state.
+    """
+    changed: list[Issue] = []
+    for issue in storage.all_issues(IssueFilters(state="all")):
+        dirty = False
+        for linked in issue.linked_prs:
+            if linked.state == "merged":
+                continue
+            try:
+                pr = await client.get_pull_request(
+                    linked.owner, linked.repo, linked.number
+                )
+            except httpx.HTTPError:
+                logger.warning("Could not refresh %s", linked.url)
+                continue
+            state = pull_request_state(pr)
+            if state != linked.state or pr.get("title") != linked.title:
+                linked.state = state
+                linked.title = pr.get("title")
+                dirty = True
+        if dirty:
+            issue.updated_at = datetime.now(timezone.utc)
+            storage.write_issue(issue)
+            changed.append(issue)
+    return changed
+
+
+class PullRequestScheduler:
+    """Refresh linked pull requests on an interval."""
+
+    def __init__(self) -> None:
+        self._task: asyncio.Task | None = None  # type: ignore[type-arg]
+
+    def start(self, interval: float, run: Callable[[], Awaitable[None]]) -> None:
+        self.stop()
+        self._task = asyncio.create_task(self._loop(interval, run))
+
+    def stop(self) -> None:
+        if self._task is not None:
+            self._task.cancel()
+            self._task = None
+
+    async def _loop(self, interval: float, run: Callable[[], Awaitable[None]]) -> None:
+        while True:
+            await asyncio.sleep(interval)
+            try:
+                await run()
+            except Exception:
+                logger.exception("Scheduled pull request refresh failed")
@@ -0,0 +1,22 @@
+"""Project activity feed derived from store history."""
+
+from datetime import datetime
+
+from fastapi import APIRouter, Depends
+
+from ..core.models import ActivityEvent, ListResponse
+from ..core.storage import ProjectStorage
+from .projects import get_project_storage
+
+router = APIRouter(tags=["activity"])
+
+
+@router.get("/projects/{name}/activity")
+def get_activity_feed(
+    since: datetime | None = None,
+    page: int = 1,
+    per_page: int = 30,
+    storage: ProjectStorage = Depends(get_project_storage),
+) -> ListResponse[ActivityEvent]:
+    """List recent store changes (issues, comments, labels), newest first."""
+    return storage.list_activity(since, page, per_page)
@@ -1,43 +1,286 @@
 """Amplifier session endpoints for the issues server API."""
 
-from fastapi import APIRouter, Depends, HTTPException, Response
+from pathlib import Path, PurePosixPath
 
-from ..amplifier import AmplifierManager
-from ..config import Settings
-from ..deps import get_amplifier_manager, get_settings, get_ws_manager
-from ..models import AmplifierSessionInfo
-from ..storage import ProjectStorage
+from fastapi import APIRouter, Depends, HTTPException, Query, Response
+from pydantic import BaseModel
+
+from ..amplifier import PLAN_MAX_ISSUES, AmplifierManager
+from ..amplifier_settings import (
+    AmplifierSettingsFile,
+    get_amplifier_settings,
+    reset_amplifier_settings,
+    update_amplifier_settings,
+)
+from ..amplifier_profiles import (
+    keyring_key,
+    read_profiles,
+    resolve_profile,
+    write_profiles,
+)
+from ..core.config import Settings
+from ..core.logs import amplifier_log_dir
+from ..core.models import (
+    AmplifierProfile,
+    AmplifierProvider,
+    AmplifierSessionInfo,
+    AmplifierSessionLogs,
+    Issue,
+    IssueFilters,
+    IssuePolishSuggestion,
+    LabelSuggestion,
+    MilestonePlan,
+    PlanMilestoneRequest,
+    ProjectConfig,
+    RunAmplifierRequest,
+)
+from ..core.storage import ProjectStorage
+from ..deps import (
+    get_amplifier_manager,
+    get_notifications,
+    get_settings,
+    get_ws_manager,
+)
+from ..keyring_store import delete_secret
+from ..notifications import NotificationCenter
 from ..ws import WebSocketManager
-from .projects import get_project_storage, load_project_config
+from .config import _read_config
+from .projects import get_project_storage, load_project_config, save_project_config
 
 router = APIRouter(tags=["amplifier"])
 
 
+class AmplifierProfileInfo(BaseModel):
+    name: str
+    provider: AmplifierProvider
+    model: str
+    base_url: str | None
+    has_api_key: bool
+
+
+class AmplifierProfilesResponse(BaseModel):
+    profiles: list[AmplifierProfileInfo]
+    default: str | None
+
+
+class SaveAmplifierProfileRequest(BaseModel):
+    provider: AmplifierProvider
+    model: str
+    base_url: str | None = None
+    # Omitted keeps the stored key; an empty string removes it.
+    api_key: str | None = None
+
+
+class SelectAmplifierProfileRequest(BaseModel):
+    profile: str | None = None
+
+
+class UpdateAmplifierSettingsRequest(BaseModel):
+    # YAML mapping merged into settings.local.yaml; null values remove keys.
+    patch: str
+
+
+class ProjectAmplifierProfile(BaseModel):
+    # Chosen for the project; ``effective`` is what sessions use, after defaults.
+    profile: str | None
+    effective: str | None
+
+
+def _scope_paths(config: ProjectConfig, paths: list[str]) -> list[str]:
+    """Normalize repo-relative *paths*, refusing any that leave the project repo."""
+    root = Path(config.project_path) if config.project_path else None
+    scoped: list[str] = []
+    for raw in paths:
+        path = PurePosixPath(raw.strip().replace("\\", "/"))
+        if path.is_absolute() or ".." in path.parts:
+            raise HTTPException(
+                status_code=422,
+                detail=f"'{raw}' must be a path inside the project repository",
+            )
+        if str(path) == ".":
+            continue
+        if root is not None and not (root / path).exists():
+            raise HTTPException(
+                status_code=422, detail=f"'{raw}' does not exist in {root}"
+            )
+        if str(path) not in scoped:
+            scoped.append(str(path))
+    return scoped
+
+
+async def start_session(
+    config: ProjectConfig,
+    issue: Issue,
+    storage: ProjectStorage,
+    settings: Settings,
+    ws_manager: WebSocketManager,
+    notifications: NotificationCenter,
+    amplifier_manager: AmplifierManager,
+    paths: list[str] | None = None,
+) -> None:
+    """Start an Amplifier session on *issue* with the project's settings and profile.
+
+    Raises:
+        HTTPException: 403 when Amplifier is turned off for the project, 409
+            when the issue already has a session or too many are running.
+    """
+    project_settings = storage.read_settings()
+    if not project_settings.amplifier_enabled:
+        raise HTTPException(
+            status_code=403, detail="Amplifier is turned off in this project's settings"
+        )
+
+    try:
+        await amplifier_manager.run(
+            config.name,
+            issue.number,
+            issue,
+            storage,
+            ws_manager,
+            project_dir=settings.data_dir / "projects" / config.name,
+            notifications=notifications,
+            max_sessions=_read_config(settings.data_dir).settings.max_amplifier_sessions,
+            instructions=project_settings.amplifier_instructions,
+            log_dir=amplifier_log_dir(settings.data_dir),
+            profile=resolve_profile(settings, config),
+            paths=paths,
+        )
+    except ValueError as exc:
+        raise HTTPException(status_code=409, detail=str(exc)) from exc
+
+
 @router.post("/projects/{name}/issues/{number}/amplifier", status_code=202)
 async def start_amplifier_session(
     name: str,
     number: int,
+    body: RunAmplifierRequest | None = None,
     storage: ProjectStorage = Depends(get_project_storage),
     amplifier_manager: AmplifierManager = Depends(get_amplifier_manager),
     ws_manager: WebSocketManager = Depends(get_ws_manager),
     settings: Settings = Depends(get_settings),
+    notifications: NotificationCenter = Depends(get_notifications),
 ) -> dict[str, str]:
-    """Start an Amplifier session for an issue."""
+    """Start an Amplifier session for an issue, optionally confined to some paths."""
     issue = storage.read_issue(number)
     if issue is None:
         raise HTTPException(status_code=404, detail=f"Issue #{number} not found")
 
     config = load_project_config(name, settings)
-    project_dir = settings.data_dir / "projects" / config.name
+    await start_session(
+        config,
+        issue,
+        storage,
+        settings,
+        ws_manager,
+        notifications,
+        amplifier_manager,
+        paths=_scope_paths(config, body.paths) if body else None,
+    )
 
-    try:
-        await amplifier_manager.run(
-            name, number, issue, storage, ws_manager, project_dir=project_dir
+    return {"status": "started"}
+
+
+@router.post("/projects/{name}/issues/{number}/amplifier/polish")
+async def polish_issue(
+    name: str,
+    number: int,
+    storage: ProjectStorage = Depends(get_project_storage),
+    amplifier_manager: AmplifierManager = Depends(get_amplifier_manager),
+    settings: Settings = Depends(get_settings),
+) -> IssuePolishSuggestion:
+    """Suggest a clearer title and structured description for an issue.
+
+    Waits for Amplifier's answer; apply it with ``PATCH .../issues/{number}``.
+    """
+    issue = storage.read_issue(number)
+    if issue is None:
+        raise HTTPException(status_code=404, detail=f"Issue #{number} not found")
+
+    project_settings = storage.read_settings()
+    if not project_settings.amplifier_enabled:
+        raise HTTPException(
+            status_code=403, detail="Amplifier is turned off in this project's settings"
         )
-    except ValueError as exc:
-        raise HTTPException(status_code=409, detail=str(exc)) from exc
 
-    return {"status": "started"}
+    config = load_project_config(name, settings)
+    return await amplifier_manager.polish(
+        issue,
+        settings.data_dir / "projects" / config.name,
+        project_settings.amplifier_instructions,
+        resolve_profile(settings, config),
+    )
+
+
+@router.post("/projects/{name}/issues/{number}/amplifier/labels")
+async def suggest_issue_labels(
+    name: str,
+    number: int,
+    storage: ProjectStorage = Depends(get_project_storage),
+    amplifier_manager: AmplifierManager = Depends(get_amplifier_manager),
+    settings: Settings = Depends(get_settings),
+) -> list[LabelSuggestion]:
+    """Rank the project's labels that fit an issue and it doesn't have yet."""
+    issue = storage.read_issue(number)
+    if issue is None:
+        raise HTTPException(status_code=404, detail=f"Issue #{number} not found")
+
+    project_settings = storage.read_settings()
+    if not project_settings.amplifier_enabled:
+        raise HTTPException(
+            status_code=403, detail="Amplifier is turned off in this project's settings"
+        )
+
+    config = load_project_config(name, settings)
+    return await amplifier_manager.suggest_labels(
+        issue,
+        storage.read_labels(),
+        settings.data_dir / "projects" / config.name,
+        project_settings.amplifier_instructions,
+        resolve_profile(settings, config),
+    )
+
+
+@router.post("/projects/{name}/milestones/{number}/amplifier/plan")
+async def plan_milestone(
+    name: str,
+    number: int,
+    body: PlanMilestoneRequest,
+    storage: ProjectStorage = Depends(get_project_storage),
+    amplifier_manager: AmplifierManager = Depends(get_amplifier_manager),
+    settings: Settings = Depends(get_settings),
+) -> MilestonePlan:
+    """Propose open issues for a milestone, with estimates and rationale.
+
+    Candidates are the most recently updated open issues that have no
+    milestone or already have this one.
+    """
+    milestone = next((m for m in storage.read_milestones() if m.number == number), None)
+    if milestone is None:
+        raise HTTPException(status_code=404, detail=f"Milestone #{number} not found")
+
+    project_settings = storage.read_settings()
+    if not project_settings.amplifier_enabled:
+        raise HTTPException(
+            status_code=403, detail="Amplifier is turned off in this project's settings"
+        )
+
+    candidates = [
+        issue
+        for issue in storage.all_issues(IssueFilters(state="open", sort="updated"))
+        if issue.milestone is None or issue.milestone.number == number
+    ][:PLAN_MAX_ISSUES]
+    if not candidates:
+        return MilestonePlan(milestone=number, summary="No open issues to plan")
+
+    config = load_project_config(name, settings)
+    return await amplifier_manager.plan_milestone(
+        milestone,
+        candidates,
+        settings.data_dir / "projects" / config.name,
+        body.constraints,
+        project_settings.amplifier_instructions,
+        resolve_profile(settings, config),
+    )
 
 
 @router.get("/projects/{name}/issues/{number}/amplifier")
@@ -56,6 +299,23 @@ async def get_amplifier_status(
     return info
 
 
+@router.get("/projects/{name}/issues/{number}/amplifier/logs")
+async def get_amplifier_logs(
+    name: str,
+    number: int,
+    tail: int = Query(default=200, ge=1, le=10000),
+    amplifier_manager: AmplifierManager = Depends(get_amplifier_manager),
+) -> AmplifierSessionLogs:
+    """The last *tail* lines of the issue's Amplifier session stderr."""
+    logs = amplifier_manager.get_session_logs(name, number, tail)
+    if logs is None:
+        raise HTTPException(
+            status_code=404,
+            detail=f"No Amplifier session log for issue #{number}",
+        )
+    return logs
+
+
 @router.delete("/projects/{name}/issues/{number}/amplifier", status_code=204)
 async def cancel_amplifier_session(
     name: str,
@@ -74,7 +334,180 @@ async def cancel_amplifier_session(
 
 @router.get("/amplifier/sessions")
 async def list_amplifier_sessions(
+    project: str | None = None,
     amplifier_manager: AmplifierManager = Depends(get_amplifier_manager),
 ) -> list[AmplifierSessionInfo]:
-    """List all tracked Amplifier sessions."""
-    return amplifier_manager.list_sessions()
+    """List tracked Amplifier sessions, across all projects unless *project* is given."""
+    return amplifier_manager.list_sessions(project)
+
+
+@router.delete("/amplifier/sessions")
+async def cancel_all_amplifier_sessions(
+    amplifier_manager: AmplifierManager = Depends(get_amplifier_manager),
+) -> dict[str, int]:
+    """Cancel every running Amplifier session, across all projects."""
+    return {"cancelled": amplifier_manager.cancel_all()}
+
+
+# ---------------------------------------------------------------------------
+# Provider profiles
+# ---------------------------------------------------------------------------
+
+
+def _profile_info(profile: AmplifierProfile) -> AmplifierProfileInfo:
+    return AmplifierProfileInfo(
+        name=profile.name,
+        provider=profile.provider,
+        model=profile.model,
+        base_url=profile.base_url,
+        has_api_key=bool(profile.api_key),
+    )
+
+
+@router.get("/amplifier/profiles")
+def list_amplifier_profiles(
+    settings: Settings = Depends(get_settings),
+) -> AmplifierProfilesResponse:
+    """List provider profiles (without keys) and the default one."""
+    profiles = read_profiles(settings)
+    return AmplifierProfilesResponse(
+        profiles=[_profile_info(p) for p in profiles.profiles],
+        default=profiles.default,
+    )
+
+
+@router.put("/amplifier/profiles/{profile_name}")
+def save_amplifier_profile(
+    profile_name: str,
+    req: SaveAmplifierProfileRequest,
+    settings: Settings = Depends(get_settings),
+) -> AmplifierProfileInfo:
+    """Create or replace a provider profile. The first one becomes the default."""
+    if req.provider == "azure" and not req.base_url:
+        raise HTTPException(
+            status_code=422, detail="Azure profiles need the resource endpoint URL"
+        )
+    profiles = read_profiles(settings)
+    existing = profiles.find(profile_name)
+    api_key = req.api_key
+    if api_key is None and existing is not None:
+        api_key = existing.api_key
+    if existing is not None:
+        if existing.api_key_ref is not None:
+            delete_secret(existing.api_key_ref)
+        profiles.profiles.remove(existing)
+    profile = AmplifierProfile(
+        name=profile_name,
+        provider=req.provider,
+        model=req.model,
+        base_url=req.base_url or None,
+        api_key=api_key or None,
+    )
+    profiles.profiles.append(profile)
+    if profiles.default is None:
+        profiles.default = profile_name
+    write_profiles(settings, profiles)
+    return _profile_info(profile)
+
+
+@router.delete("/amplifier/profiles/{profile_name}", status_code=204)
+def delete_amplifier_profile(
+    profile_name: str, settings: Settings = Depends(get_settings)
+) -> None:
+    """Forget a profile and its key. Projects using it fall back to the default."""
+    profiles = read_profiles(settings)
+    profile = profiles.find(profile_name)
+    if profile is None:
+        raise HTTPException(
+            status_code=404, detail=f"Amplifier profile '{profile_name}' not found"
+        )
+    delete_secret(keyring_key(profile))
+    profiles.profiles.remove(profile)
+    if profiles.default == profile_name:
+        profiles.default = None
+    write_profiles(settings, profiles)
+
+
+@router.put("/amplifier/default-profile")
+def set_default_amplifier_profile(
+    req: SelectAmplifierProfileRequest, settings: Settings = Depends(get_settings)
+) -> AmplifierProfilesResponse:
+    """Use a profile for projects that have not chosen one; null for Anthropic."""
+    profiles = read_profiles(settings)
+    if req.profile is not None and profiles.find(req.profile) is None:
+        raise HTTPException(
+            status_code=404, detail=f"Amplifier profile '{req.profile}' not found"
+        )
+    profiles.default = req.profile
+    write_profiles(settings, profiles)
+    return list_amplifier_profiles(settings)
+
+
+@router.get("/projects/{name}/amplifier/profile")
+def get_project_amplifier_profile(
+    name: str, settings: Settings = Depends(get_settings)
+) -> ProjectAmplifierProfile:
+    """The profile this project's sessions use on this machine."""
+    config = load_project_config(name, settings)
+    effective = resolve_profile(settings, config)
+    return ProjectAmplifierProfile(
+        profile=config.amplifier_profile,
+        effective=effective.name if effective else None,
+    )
+
+
+@router.put("/projects/{name}/amplifier/profile")
+def set_project_amplifier_profile(
+    name: str,
+    req: SelectAmplifierProfileRequest,
+    settings: Settings = Depends(get_settings),
+) -> ProjectAmplifierProfile:
+    """Pick this project's profile on this machine; null follows the default."""
+    if req.profile is not None and read_profiles(settings).find(req.profile) is None:
+        raise HTTPException(
+            status_code=404, detail=f"Amplifier profile '{req.profile}' not found"
+        )
+    config = load_project_config(name, settings)
+    config.amplifier_profile = req.profile
+    save_project_config(config, settings)
+    return get_project_amplifier_profile(name, settings)
+
+
+# ---------------------------------------------------------------------------
+# settings.local.yaml
+# ---------------------------------------------------------------------------
+
+
+@router.get("/projects/{name}/amplifier/settings")
+def get_project_amplifier_settings(
+    name: str, settings: Settings = Depends(get_settings)
+) -> AmplifierSettingsFile:
+    """The project's Amplifier settings file and the providers it configures."""
+    config = load_project_config(name, settings)
+    return get_amplifier_settings(
+        settings.data_dir / "projects" / config.name, resolve_profile(settings, config)
+    )
+
+
+@router.patch("/projects/{name}/amplifier/settings")
+def update_project_amplifier_settings(
+    name: str,
+    req: UpdateAmplifierSettingsRequest,
+    settings: Settings = Depends(get_settings),
+) -> AmplifierSettingsFile:
+    """Merge a YAML patch into the settings; the file then stops following profiles."""
+    config = load_project_config(name, settings)
+    return update_amplifier_settings(
+        settings.data_dir / "projects" / config.name,
+        req.patch,
+        resolve_profile(settings, config),
+    )
+
+
+@router.delete("/projects/{name}/amplifier/settings", status_code=204)
+def reset_project_amplifier_settings(
+    name: str, settings: Settings = Depends(get_settings)
+) -> None:
+    """Drop local edits; the next session writes the file from the profile again."""
+    config = load_project_config(name, settings)
+    reset_amplifier_settings(settings.data_dir / "projects" / config.name)
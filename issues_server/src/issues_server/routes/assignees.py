@@ -0,0 +1,123 @@
+"""Assignable users for a project, backed by the store repo's collaborators."""
+
+import logging
+
+import httpx
+from fastapi import APIRouter, Depends
+
+from ..core.config import Settings
+from ..core.models import LOCAL_USER, ProjectConfig, SimpleUser
+from ..deps import get_settings
+from ..github_client import GitHubClient
+from .github_auth import get_github_account, get_github_token
+from .projects import load_project_config
+
+logger = logging.getLogger(__name__)
+
+router = APIRouter(tags=["assignees"])
+
+# GitHub users by lowercase login; ids and avatars don't change often enough to expire.
+_users: dict[str, SimpleUser] = {}
+
+
+async def list_assignable_users(
+    config: ProjectConfig, settings: Settings
+) -> list[SimpleUser]:
+    """Users who can be assigned issues on the project's GitHub store repo.
+
+    Local-only stores, missing tokens, and GitHub errors all yield an empty
+    list so callers can fall back to plain logins.
+    """
+    github = config.store.github
+    token = get_github_token(settings, config.github_account)
+    if github is None or token is None:
+        return []
+    try:
+        users = await GitHubClient(token).list_assignees(github.owner, github.repo)
+    except httpx.HTTPError as exc:
+        logger.warning(
+            "Could not list assignees for %s/%s: %s", github.owner, github.repo, exc
+        )
+        return []
+    resolved = [SimpleUser.model_validate(u) for u in users]
+    _users.update((u.login.lower(), u) for u in resolved)
+    return resolved
+
+
+async def resolve_user(login: str, token: str | None) -> SimpleUser:
+    """The GitHub user behind *login*, or a stub when it can't be looked up."""
+    cached = _users.get(login.lower())
+    if cached is not None:
+        return cached
+    stub = SimpleUser(login=login, id=0, avatar_url="", user_type="User")
+    if token is None:
+        return stub
+    try:
+        data = await GitHubClient(token).get_user(login)
+    except httpx.HTTPError as exc:
+        logger.info("Could not look up GitHub user %s: %s", login, exc)
+        return stub
+    if data is None:
+        return stub
+    user = SimpleUser.model_validate(data)
+    _users[login.lower()] = user
+    return user
+
+
+async def current_user(config: ProjectConfig, settings: Settings) -> SimpleUser:
+    """The project's GitHub account as a user, or the local user for local-only stores."""
+    account = get_github_account(settings, config.github_account)
+    if config.store.github is None or account is None:
+        return LOCAL_USER
+    return await resolve_user(account.user, account.token)
+
+
+async def resolve_assignees(
+    config: ProjectConfig, settings: Settings, logins: list[str]
+) -> list[SimpleUser]:
+    """Map logins to real GitHub users, keeping unknown logins as stubs."""
+    if not logins:
+        return []
+    if any(login.lower() not in _users for login in logins):
+        await list_assignable_users(config, settings)
+    token = (
+        get_github_token(settings, config.github_account) if config.store.github else None
+    )
+    return [await resolve_user(login, token) for login in logins]
+
+
+async def author_association(config: ProjectConfig, settings: Settings) -> str:
+    """How the project's GitHub account relates to the store repo, GitHub-style.
+
+    Local-only stores belong to whoever runs the app.
+    """
+    github = config.store.github
+    if github is None:
+        return "OWNER"
+    account = get_github_account(settings, config.github_account)
+    if account is None:
+        return "NONE"
+    if account.user.lower() == github.owner.lower():
+        return "OWNER"
+    try:
+        member = await GitHubClient(account.token).is_collaborator(
+            github.owner, github.repo, account.user
+        )
+    except httpx.HTTPError as exc:
+        logger.info("Could not check collaborator status, assuming one: %s", exc)
+        # Writing to the store at all takes push access.
+        return "COLLABORATOR"
+    return "COLLABORATOR" if member else "NONE"
+
+
+@router.get("/projects/{name}/assignees")
+async def get_assignable_users(
+    name: str,
+    q: str | None = None,
+    settings: Settings = Depends(get_settings),
+) -> list[SimpleUser]:
+    """List users who can be assigned issues, optionally filtered by login prefix."""
+    users = await list_assignable_users(load_project_config(name, settings), settings)
+    if q:
+        users = [u for u in users if u.login.lower().startswith(q.lower())]
+    return users
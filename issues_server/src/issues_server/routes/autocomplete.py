@@ -0,0 +1,110 @@
+"""Suggestions for the editor's ``#``, ``@``, label and milestone pickers."""
+
+from __future__ import annotations
+
+from typing import Literal
+
+from fastapi import APIRouter, Depends, Query
+from pydantic import BaseModel
+
+from ..core.config import Settings
+from ..core.models import LOCAL_USER, IssueFilters
+from ..core.storage import ProjectStorage
+from ..deps import get_settings
+from .assignees import list_assignable_users
+from .projects import get_project_storage, load_project_config
+
+router = APIRouter(tags=["autocomplete"])
+
+AutocompleteKind = Literal["labels", "milestones", "users", "issues"]
+
+
+class AutocompleteItem(BaseModel):
+    """One suggestion; ``value`` is what gets inserted, ``label`` what is shown."""
+
+    value: str
+    label: str
+    color: str | None = None
+    avatar_url: str | None = None
+
+
+# Store-derived suggestions per (store, kind). Every store write is a commit,
+# so they stay valid until HEAD moves.
+_cache: dict[tuple[str, str], tuple[str | None, list[AutocompleteItem]]] = {}
+
+
+def _store_items(storage: ProjectStorage, kind: str) -> list[AutocompleteItem]:
+    key = (str(storage.path), kind)
+    head = storage.head()
+    cached = _cache.get(key)
+    if cached is not None and cached[0] == head:
+        return cached[1]
+
+    items: list[AutocompleteItem]
+    if kind == "labels":
+        items = [
+            AutocompleteItem(value=label.name, label=label.name, color=label.color)
+            for label in storage.read_labels()
+        ]
+    elif kind == "milestones":
+        items = [
+            AutocompleteItem(value=m.title, label=m.title)
+            for m in storage.read_milestones()
+            if m.state == "open"
+        ]
+    elif kind == "issues":
+        items = [
+            AutocompleteItem(value=str(issue.number), label=issue.title)
+            for issue in storage.all_issues(IssueFilters(state="open"))
+        ]
+    else:
+        seen: dict[str, AutocompleteItem] = {}
+        for issue in storage.all_issues(IssueFilters(state="all")):
+            for user in [issue.user, *issue.assignees]:
+                if user.login == LOCAL_USER.login:
+                    continue
+                seen.setdefault(
+                    user.login.lower(),
+                    AutocompleteItem(
+                        value=user.login,
+                        label=user.login,
+                        avatar_url=user.avatar_url or None,
+                    ),
+                )
+        items = list(seen.values())
+    _cache[key] = (head, items)
+    return items
+
+
+def _matches(item: AutocompleteItem, kind: str, prefix: str) -> bool:
+    if kind == "issues":
+        # "#12" narrows by number; anything else searches titles.
+        return item.value.startswith(prefix) or prefix in item.label.lower()
+    return item.value.lower().startswith(prefix)
+
+
+@router.get("/projects/{name}/autocomplete")
+async def get_autocomplete_data(
+    name: str,
+    kind: AutocompleteKind,
+    prefix: str = "",
+    limit: int = Query(default=10, ge=1, le=50),
+    settings: Settings = Depends(get_settings),
+    storage: ProjectStorage = Depends(get_project_storage),
+) -> list[AutocompleteItem]:
+    """Labels, open milestones, users or open issues starting with *prefix*.
+
+    Users are the store repo's assignable collaborators plus everyone who
+    opened or was assigned an issue in the store.
+    """
+    items = _store_items(storage, kind)
+    if kind == "users":
+        config = load_project_config(name, settings)
+        known = {item.value.lower() for item in items}
+        items = [
+            AutocompleteItem(value=u.login, label=u.login, avatar_url=u.avatar_url)
+            for u in await list_assignable_users(config, settings)
+            if u.login.lower() not in known
+        ] + items
+    needle = prefix.lower().lstrip("#@")
+    return [item for item in items if _matches(item, kind, needle)][:limit]
@@ -0,0 +1,25 @@
+"""Locally cached GitHub avatars."""
+
+from fastapi import APIRouter, Depends, HTTPException
+from fastapi.responses import FileResponse
+
+from ..avatars import get_user_avatar, is_valid_login
+from ..core.config import Settings
+from ..deps import get_settings
+
+router = APIRouter(prefix="/avatars", tags=["avatars"])
+
+
+@router.get("/{login}")
+async def get_avatar(login: str, settings: Settings = Depends(get_settings)) -> FileResponse:
+    """Serve a user's avatar from the local cache, fetching it on first use."""
+    if not is_valid_login(login):
+        raise HTTPException(status_code=400, detail=f"Invalid GitHub login '{login}'")
+    avatar = await get_user_avatar(settings.data_dir / "avatars", login)
+    if avatar is None:
+        raise HTTPException(status_code=404, detail=f"No avatar available for '{login}'")
+    return FileResponse(
+        avatar.path,
+        media_type=avatar.content_type,
+        headers={"Cache-Control": "max-age=3600"},
+    )
@@ -0,0 +1,82 @@
+"""Back up a project to a single archive and restore it as a new local store."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+from fastapi import APIRouter, Depends, HTTPException
+from pydantic import BaseModel
+
+from ..backup import backup_project, read_backup_config, restore_project
+from ..core.config import Settings
+from ..core.models import StoreConfig
+from ..deps import get_settings
+from .projects import (
+    ProjectInfo,
+    _build_project_info,
+    load_project_config,
+    save_project_config,
+)
+from .store import _create_and_push_remote
+
+router = APIRouter(prefix="/projects", tags=["backup"])
+
+
+class BackupProjectRequest(BaseModel):
+    path: str
+
+
+class BackupProjectResponse(BaseModel):
+    path: str
+
+
+class RestoreProjectRequest(BaseModel):
+    archive: str
+    name: str | None = None
+    repo_name: str | None = None
+    private: bool = True
+
+
+@router.post("/{name}/backup")
+def backup(
+    name: str, req: BackupProjectRequest, settings: Settings = Depends(get_settings)
+) -> BackupProjectResponse:
+    """Write the project's config and full store history to one archive."""
+    config = load_project_config(name, settings)
+    destination = Path(req.path).expanduser().resolve()
+    return BackupProjectResponse(path=str(backup_project(config, destination)))
+
+
+@router.post("/restore", status_code=201)
+async def restore(
+    req: RestoreProjectRequest, settings: Settings = Depends(get_settings)
+) -> ProjectInfo:
+    """Recreate a project from a backup under ``data/stores/``.
+
+    With *repo_name*, a new GitHub repo is created and the restored store is
+    pushed to it; otherwise the store stays local.
+    """
+    archive = Path(req.archive).expanduser().resolve()
+    if not archive.is_file():
+        raise HTTPException(status_code=404, detail=f"{archive} not found")
+    name = req.name or read_backup_config(archive).name
+    # The name may come from a shared archive, so it must not escape data_dir.
+    if not name or any(s in name for s in ("/", "\\", "..")) or Path(name).name != name:
+        raise HTTPException(status_code=422, detail=f"Invalid project name '{name}'")
+    project_dir = settings.data_dir / "projects" / name
+    store_dir = settings.data_dir / "stores" / name
+    if project_dir.exists() or store_dir.exists():
+        raise HTTPException(status_code=409, detail=f"Project '{name}' already exists")
+
+    config = restore_project(archive, store_dir)
+    config.name = name
+    config.store = StoreConfig(path=str(store_dir.resolve()))
+    if config.project_path and not Path(config.project_path).is_dir():
+        config.project_path = None
+    save_project_config(config, settings)
+
+    if req.repo_name:
+        await _create_and_push_remote(
+            config, settings, req.repo_name, req.private, f"Issues store for {name}"
+        )
+    return _build_project_info(config, project_dir, store_dir)
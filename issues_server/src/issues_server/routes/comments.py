@@ -4,21 +4,30 @@ from datetime import datetime, timezone
 
 from fastapi import APIRouter, Depends, HTTPException, Response
 
-from ..deps import get_ws_manager
-from ..models import (
+from ..comment_commands import broadcast_reply, run_commands
+from ..core.config import Settings
+from ..core.models import (
     Comment,
+    CommentCommandsConfig,
     CreateCommentRequest,
     ListResponse,
-    SimpleUser,
     UpdateCommentRequest,
 )
-from ..storage import ProjectStorage
+from ..core.storage import ProjectStorage
+from ..deps import get_settings, get_ws_manager
 from ..ws import WebSocketManager
-from .projects import get_project_storage
+from .assignees import author_association, current_user
+from .projects import get_project_storage, load_project_config, save_project_config
 
 router = APIRouter(tags=["comments"])
 
 
+def _check_issue_unlocked(storage: ProjectStorage, issue_number: int) -> None:
+    issue = storage.read_issue(issue_number)
+    if issue is not None:
+        storage.check_unlocked(issue)
+
+
 # ---------------------------------------------------------------------------
 # Issue-scoped comment endpoints
 # ---------------------------------------------------------------------------
@@ -46,13 +55,19 @@ async def create_comment(
     request: CreateCommentRequest,
     storage: ProjectStorage = Depends(get_project_storage),
     ws_manager: WebSocketManager = Depends(get_ws_manager),
+    settings: Settings = Depends(get_settings),
 ) -> Comment:
     """Create a comment on an issue."""
+    config = load_project_config(name, settings)
+    # Both come from the project's GitHub account, so they describe one person.
+    author = await current_user(config, settings)
+    association = await author_association(config, settings)
     storage.sync()
 
     issue = storage.read_issue(number)
     if issue is None:
         raise HTTPException(status_code=404, detail=f"Issue #{number} not found")
+    storage.check_unlocked(issue)
 
     comment_id = storage.next_comment_id()
     now = datetime.now(timezone.utc)
@@ -60,23 +75,24 @@ async def create_comment(
     comment = Comment(
         id=comment_id,
         body=request.body,
-        user=SimpleUser(
-            login="local-user",
-            id=1,
-            avatar_url="",
-            user_type="User",
-        ),
+        user=author,
         created_at=now,
         updated_at=now,
-        author_association="OWNER",
+        author_association=association,
     )
 
     storage.write_comment(number, comment)
 
     issue.comments += 1
     storage.write_issue(issue)
+    # Committed with the comment, so a synced copy is never acted on again.
+    reply = await run_commands(config, storage, issue, comment, settings, ws_manager)
 
-    storage.commit(f"Add comment #{comment_id} on issue #{number}")
+    storage.commit(
+        f"Add comment #{comment_id} on issue #{number}",
+        event="comment.create",
+        issue=number,
+    )
     storage.push()
 
     await ws_manager.broadcast(
@@ -87,10 +103,34 @@ async def create_comment(
             "comment": comment.model_dump(mode="json"),
         },
     )
+    if reply is not None:
+        await broadcast_reply(ws_manager, name, issue, reply)
 
     return comment
 
 
+@router.get("/projects/{name}/comment-commands")
+def get_comment_commands(
+    name: str, settings: Settings = Depends(get_settings)
+) -> CommentCommandsConfig:
+    """Whether commands in this account's synced comments run on this machine."""
+    config = load_project_config(name, settings)
+    return CommentCommandsConfig(synced=config.synced_comment_commands)
+
+
+@router.put("/projects/{name}/comment-commands")
+def set_comment_commands(
+    name: str,
+    req: CommentCommandsConfig,
+    settings: Settings = Depends(get_settings),
+) -> CommentCommandsConfig:
+    """Turn running commands from synced comments on or off for this machine."""
+    config = load_project_config(name, settings)
+    config.synced_comment_commands = req.synced
+    save_project_config(config, settings)
+    return req
+
+
 # ---------------------------------------------------------------------------
 # Project-scoped comment endpoints (scan all issues)
 # ---------------------------------------------------------------------------
@@ -124,12 +164,15 @@ async def update_comment(
         raise HTTPException(status_code=404, detail=f"Comment #{comment_id} not found")
 
     issue_number, comment = result
+    _check_issue_unlocked(storage, issue_number)
 
     comment.body = request.body
     comment.updated_at = datetime.now(timezone.utc)
 
     storage.write_comment(issue_number, comment)
-    storage.commit(f"Update comment #{comment_id}")
+    storage.commit(
+        f"Update comment #{comment_id}", event="comment.update", issue=issue_number
+    )
     storage.push()
 
     return comment
@@ -148,6 +191,7 @@ async def delete_comment(
         raise HTTPException(status_code=404, detail=f"Comment #{comment_id} not found")
 
     issue_number, _comment = result
+    _check_issue_unlocked(storage, issue_number)
 
     storage.delete_comment(issue_number, comment_id)
 
@@ -156,7 +200,9 @@ async def delete_comment(
         issue.comments = max(0, issue.comments - 1)
         storage.write_issue(issue)
 
-    storage.commit(f"Delete comment #{comment_id}")
+    storage.commit(
+        f"Delete comment #{comment_id}", event="comment.delete", issue=issue_number
+    )
     storage.push()
 
     return Response(status_code=204)
@@ -6,14 +6,26 @@ import json
 from pathlib import Path
 from typing import Any
 
-from fastapi import APIRouter, Depends
-
-from ..config import Settings
-from ..deps import get_settings
-from ..models import AppConfig
+from fastapi import APIRouter, Depends, HTTPException
+from pydantic import BaseModel, ValidationError
+
+from ..core.config import Settings
+from ..core.models import (
+    AppConfig,
+    AppSettings,
+    RecentIssue,
+    SettingsExport,
+    UpdateAppSettingsRequest,
+)
+from ..core.storage import write_text_atomic
+from ..deps import get_settings, get_store_sync_scheduler
 
 router = APIRouter(prefix="/config", tags=["config"])
 
+
+class SettingsFileRequest(BaseModel):
+    path: str
+
 _DEFAULTS = AppConfig()
 
 
@@ -31,8 +43,7 @@ def _read_config(data_dir: Path) -> AppConfig:
 
 def _write_config(data_dir: Path, config: AppConfig) -> None:
     path = _config_path(data_dir)
-    path.parent.mkdir(parents=True, exist_ok=True)
-    path.write_text(json.dumps(config.model_dump(mode="json"), indent=2) + "\n")
+    write_text_atomic(path, json.dumps(config.model_dump(mode="json"), indent=2) + "\n")
 
 
 def _update_recent_projects(data_dir: Path, project_name: str) -> None:
@@ -45,20 +56,146 @@ def _update_recent_projects(data_dir: Path, project_name: str) -> None:
     _write_config(data_dir, config)
 
 
+def _update_recent_issues(data_dir: Path, project_name: str, number: int) -> None:
+    """Push an issue to the front of the recently-viewed list."""
+    config = _read_config(data_dir)
+    viewed = RecentIssue(project=project_name, number=number)
+    if config.recent_issues[:1] == [viewed]:
+        return
+    config.recent_issues = [viewed] + [i for i in config.recent_issues if i != viewed]
+    config.recent_issues = config.recent_issues[:50]
+    _write_config(data_dir, config)
+
+
+def _remove_recent_project(data_dir: Path, project_name: str) -> None:
+    """Drop *project_name* from the recent-projects and recent-issues lists."""
+    config = _read_config(data_dir)
+    issues = [i for i in config.recent_issues if i.project != project_name]
+    if project_name in config.recent_projects or issues != config.recent_issues:
+        if project_name in config.recent_projects:
+            config.recent_projects.remove(project_name)
+        config.recent_issues = issues
+        _write_config(data_dir, config)
+
+
+def _rename_recent_project(data_dir: Path, old_name: str, new_name: str) -> None:
+    """Replace *old_name* with *new_name* in the recent lists, keeping positions."""
+    config = _read_config(data_dir)
+    changed = False
+    if old_name in config.recent_projects:
+        index = config.recent_projects.index(old_name)
+        config.recent_projects[index] = new_name
+        changed = True
+    for issue in config.recent_issues:
+        if issue.project == old_name:
+            issue.project = new_name
+            changed = True
+    if changed:
+        _write_config(data_dir, config)
+
+
 @router.get("")
 def get_config(settings: Settings = Depends(get_settings)) -> AppConfig:
     """Return the current app configuration (defaults if file missing)."""
     return _read_config(settings.data_dir)
 
 
+def _validation_error(exc: ValidationError) -> HTTPException:
+    error = exc.errors()[0]
+    field = ".".join(str(part) for part in error["loc"])
+    return HTTPException(status_code=422, detail=f"{field}: {error['msg']}")
+
+
+def _apply_settings(settings: Settings, app_settings: AppSettings) -> None:
+    """Restart automatic store syncs if their interval changed."""
+    from .store import start_auto_sync
+
+    if get_store_sync_scheduler().interval != app_settings.auto_sync_interval:
+        start_auto_sync(settings, app_settings.auto_sync_interval)
+
+
 @router.patch("")
-def patch_config(
+async def patch_config(
     body: dict[str, Any], settings: Settings = Depends(get_settings)
 ) -> AppConfig:
     """Partially update app configuration fields."""
+    unknown = sorted(set(body) - set(AppConfig.model_fields))
+    if unknown:
+        raise HTTPException(
+            status_code=422, detail=f"Unknown config fields: {', '.join(unknown)}"
+        )
     config = _read_config(settings.data_dir)
     updated = config.model_dump()
     updated.update(body)
-    config = AppConfig(**updated)
+    try:
+        config = AppConfig(**updated)
+    except ValidationError as exc:
+        raise _validation_error(exc) from exc
+    _write_config(settings.data_dir, config)
+    _apply_settings(settings, config.settings)
+    return config
+
+
+@router.get("/settings")
+def get_app_settings(settings: Settings = Depends(get_settings)) -> AppSettings:
+    return _read_config(settings.data_dir).settings
+
+
+@router.patch("/settings")
+async def update_app_settings(
+    body: UpdateAppSettingsRequest, settings: Settings = Depends(get_settings)
+) -> AppSettings:
+    """Update the given settings; the rest keep their values."""
+    config = _read_config(settings.data_dir)
+    updated = config.settings.model_dump()
+    updated.update(body.model_dump(exclude_unset=True))
+    try:
+        config.settings = AppSettings.model_validate(updated)
+    except ValidationError as exc:
+        raise _validation_error(exc) from exc
+    _write_config(settings.data_dir, config)
+    _apply_settings(settings, config.settings)
+    return config.settings
+
+
+@router.post("/export")
+def export_settings(
+    req: SettingsFileRequest, settings: Settings = Depends(get_settings)
+) -> SettingsExport:
+    """Write the app preferences, without secrets, to *path* as JSON."""
+    config = _read_config(settings.data_dir)
+    exported = SettingsExport(
+        settings=config.settings,
+        desktop_notifications=config.desktop_notifications,
+    )
+    target = Path(req.path).expanduser()
+    try:
+        target.parent.mkdir(parents=True, exist_ok=True)
+        target.write_text(json.dumps(exported.model_dump(mode="json"), indent=2) + "\n")
+    except OSError as exc:
+        raise HTTPException(
+            status_code=400, detail=f"Cannot write {target}: {exc.strerror}"
+        ) from exc
+    return exported
+
+
+@router.post("/import")
+async def import_settings(
+    req: SettingsFileRequest, settings: Settings = Depends(get_settings)
+) -> AppConfig:
+    """Apply preferences from a file written by ``/config/export``."""
+    source = Path(req.path).expanduser()
+    try:
+        imported = SettingsExport.model_validate_json(source.read_text())
+    except OSError as exc:
+        raise HTTPException(
+            status_code=400, detail=f"Cannot read {source}: {exc.strerror}"
+        ) from exc
+    except ValidationError as exc:
+        raise _validation_error(exc) from exc
+    config = _read_config(settings.data_dir)
+    config.settings = imported.settings
+    config.desktop_notifications = imported.desktop_notifications
     _write_config(settings.data_dir, config)
+    _apply_settings(settings, config.settings)
     return config
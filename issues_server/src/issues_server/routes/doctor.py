@@ -0,0 +1,160 @@
+"""Health check covering git, Amplifier, the GitHub token, disk space and stores."""
+
+from __future__ import annotations
+
+import json
+import shutil
+import subprocess
+import tempfile
+from pathlib import Path
+
+from fastapi import APIRouter, Depends
+from pydantic import BaseModel
+
+from ..core.config import Settings
+from ..core.models import ProjectConfig
+from ..deps import get_settings
+from .github_auth import CapabilityCheck, check_token_capabilities, get_github_token
+from .projects import load_project_config
+
+router = APIRouter(prefix="/doctor", tags=["doctor"])
+
+MIN_FREE_BYTES = 1 << 30
+
+
+class DoctorReport(BaseModel):
+    ok: bool
+    checks: list[CapabilityCheck]
+
+
+def _check_git(settings: Settings) -> CapabilityCheck:
+    if shutil.which("git") is None:
+        return CapabilityCheck(
+            name="git",
+            ok=False,
+            detail="git was not found on PATH",
+            fix="Install git and make sure it is on PATH.",
+        )
+    stores_dir = settings.data_dir / "stores"
+    try:
+        stores_dir.mkdir(parents=True, exist_ok=True)
+        with tempfile.TemporaryDirectory(dir=stores_dir) as tmp:
+            subprocess.run(
+                ["git", "init", "--quiet", tmp],
+                capture_output=True,
+                text=True,
+                check=True,
+            )
+    except (OSError, subprocess.CalledProcessError) as exc:
+        detail = getattr(exc, "stderr", None) or str(exc)
+        return CapabilityCheck(
+            name="git",
+            ok=False,
+            detail=f"Cannot create a repository in {stores_dir}: {detail.strip()}",
+            fix=f"Make {stores_dir} writable, or point ATTRACTOR_DATA_DIR elsewhere.",
+        )
+    return CapabilityCheck(
+        name="git", ok=True, detail=f"Can create repositories in {stores_dir}"
+    )
+
+
+def _check_amplifier() -> CapabilityCheck:
+    path = shutil.which("amplifier")
+    if path is None:
+        return CapabilityCheck(
+            name="amplifier",
+            ok=False,
+            detail="The amplifier CLI was not found on PATH",
+            fix=(
+                "Run `uv tool install git+https://github.com/microsoft/amplifier` "
+                "and make sure `amplifier` is on PATH."
+            ),
+        )
+    return CapabilityCheck(name="amplifier", ok=True, detail=f"Found {path}")
+
+
+def _check_disk(settings: Settings) -> CapabilityCheck:
+    path = settings.data_dir
+    while not path.exists() and path != path.parent:
+        path = path.parent
+    free = shutil.disk_usage(path).free
+    free_text = f"{free / (1 << 30):.1f} GiB free in {settings.data_dir}"
+    if free < MIN_FREE_BYTES:
+        return CapabilityCheck(
+            name="disk",
+            ok=False,
+            detail=free_text,
+            fix="Free up disk space; clones and stores need room to grow.",
+        )
+    return CapabilityCheck(name="disk", ok=True, detail=free_text)
+
+
+def _check_store(config: ProjectConfig) -> CapabilityCheck:
+    store_path = Path(config.store.path)
+    manifest_path = store_path / ".attractor-store.json"
+    fix = "Reconnect the project's store from its settings, or re-create the project."
+    if not store_path.exists():
+        return CapabilityCheck(
+            name="store",
+            ok=False,
+            detail=f"Store directory not found at {store_path}",
+            fix=fix,
+        )
+    try:
+        store_id = json.loads(manifest_path.read_text()).get("store_id")
+    except (OSError, ValueError):
+        return CapabilityCheck(
+            name="store",
+            ok=False,
+            detail=f"Store manifest missing or unreadable at {manifest_path}",
+            fix=fix,
+        )
+    if store_id != config.store_id:
+        return CapabilityCheck(
+            name="store",
+            ok=False,
+            detail="Store ID does not match the project; it may have been reassigned",
+            fix=fix,
+        )
+    return CapabilityCheck(name="store", ok=True, detail=f"Store ID {store_id} matches")
+
+
+async def _check_token(
+    settings: Settings, config: ProjectConfig | None
+) -> list[CapabilityCheck]:
+    account = config.github_account if config else None
+    if get_github_token(settings, account) is None:
+        return [
+            CapabilityCheck(
+                name="user",
+                ok=False,
+                detail="No GitHub token configured",
+                fix="Sign in under Settings > GitHub Authentication.",
+            )
+        ]
+    github = config.store.github if config else None
+    capabilities = await check_token_capabilities(
+        account=account,
+        owner=github.owner if github else None,
+        repo=github.repo if github else None,
+        settings=settings,
+    )
+    return capabilities.checks
+
+
+@router.get("")
+async def run_doctor(
+    project: str | None = None,
+    settings: Settings = Depends(get_settings),
+) -> DoctorReport:
+    """Run every check and return a checklist with a fix for each failure.
+
+    With *project*, the token is checked against that project's account and
+    store repository, and the store's ID is compared with the project's.
+    """
+    config = load_project_config(project, settings) if project else None
+    checks = [_check_git(settings), _check_amplifier(), _check_disk(settings)]
+    checks.extend(await _check_token(settings, config))
+    if config is not None:
+        checks.append(_check_store(config))
+    return DoctorReport(ok=all(c.ok for c in checks), checks=checks)
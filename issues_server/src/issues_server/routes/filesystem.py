@@ -7,9 +7,9 @@ from pathlib import Path
 
 from fastapi import APIRouter, Depends, Query
 
-from ..config import Settings
+from ..core.config import Settings
+from ..core.models import PathValidationResponse
 from ..deps import get_settings
-from ..models import PathValidationResponse
 
 router = APIRouter(prefix="/filesystem", tags=["filesystem"])
 
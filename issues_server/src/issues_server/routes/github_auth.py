@@ -2,13 +2,37 @@
 
 import json
 from datetime import datetime, timezone
+from pathlib import Path
+from typing import Literal
 
 from fastapi import APIRouter, Depends, HTTPException, Query
 from pydantic import BaseModel
 
-from issues_server.config import Settings
-from issues_server.deps import get_settings
-from issues_server.github_client import GitHubClient
+from issues_server.core.config import Settings
+from issues_server.core.models import CommitIdentity, GitHubAccount, GitHubAccounts
+from issues_server.core.storage import write_text_atomic
+from issues_server.deps import get_device_login_manager, get_settings, get_ws_manager
+from issues_server.device_login import DeviceLoginManager
+from issues_server.git_credentials import clear_cache, credential_fill
+from issues_server.github_client import (
+    GitHubClient,
+    SamlSsoRequiredError,
+    clear_response_cache,
+)
+from issues_server.keyring_store import delete_secret, get_secret, set_secret
+from issues_server.routes.config import _read_config
+from issues_server.routes.projects import (
+    list_projects,
+    load_project_config,
+    remove_local_project,
+)
+from issues_server.store_discovery import (
+    SUGGESTED_NAMES,
+    DiscoveredStore,
+    discover_stores,
+    free_store_repo_names,
+)
+from issues_server.ws import WebSocketManager
 
 router = APIRouter(prefix="/github", tags=["github"])
 
@@ -25,34 +49,148 @@ REQUIRED_PERMISSIONS = [
 ]
 
 
-def _token_path(settings: Settings):
-    return settings.data_dir / "github-token.json"
+def _accounts_path(settings: Settings) -> Path:
+    return settings.data_dir / "github-accounts.json"
 
 
-def _read_token(settings: Settings) -> dict | None:
-    path = _token_path(settings)
-    if not path.exists():
-        return None
-    return json.loads(path.read_text())
+def _legacy_token_path(settings: Settings) -> Path:
+    return settings.data_dir / "github-token.json"
 
 
-def _write_token(settings: Settings, data: dict) -> None:
-    path = _token_path(settings)
-    path.write_text(json.dumps(data, indent=2) + "\n")
+def _keyring_key(user: str) -> str:
+    return f"github:{user}"
 
 
-def _delete_token(settings: Settings) -> None:
-    path = _token_path(settings)
+def _read_accounts(settings: Settings) -> GitHubAccounts:
+    path = _accounts_path(settings)
     if path.exists():
-        path.unlink()
+        accounts = GitHubAccounts.model_validate_json(path.read_text())
+        for account in accounts.accounts:
+            if account.source == "credential_helper":
+                account.token = credential_fill()
+            elif account.token_ref is not None:
+                account.token = get_secret(account.token_ref)
+        return accounts
+
+    # Single-token installs predate accounts; adopt that token as the default.
+    legacy = _legacy_token_path(settings)
+    if not legacy.exists():
+        return GitHubAccounts()
+    data = json.loads(legacy.read_text())
+    accounts = GitHubAccounts(
+        default=data["user"],
+        accounts=[GitHubAccount.model_validate(data)],
+    )
+    _write_accounts(settings, accounts)
+    legacy.unlink()
+    return accounts
+
+
+def _write_accounts(settings: Settings, accounts: GitHubAccounts) -> None:
+    """Persist *accounts*, moving plaintext tokens into the keyring when possible."""
+    persisted = accounts.model_copy(deep=True)
+    for account in persisted.accounts:
+        if account.source == "credential_helper":
+            account.token = None
+            continue
+        if account.token is None or not settings.use_keyring:
+            continue
+        key = _keyring_key(account.user)
+        if set_secret(key, account.token):
+            account.token = None
+            account.token_ref = key
+    path = _accounts_path(settings)
+    write_text_atomic(
+        path, json.dumps(persisted.model_dump(mode="json"), indent=2) + "\n"
+    )
+
+
+def _remove_account(settings: Settings, user: str) -> bool:
+    """Drop *user* from the stored accounts. Returns False if unknown."""
+    accounts = _read_accounts(settings)
+    account = accounts.find(user)
+    if account is None:
+        return False
+    if account.token_ref is not None:
+        delete_secret(account.token_ref)
+    accounts.accounts = [a for a in accounts.accounts if a.user != user]
+    if accounts.default == user:
+        accounts.default = accounts.accounts[0].user if accounts.accounts else None
+    _write_accounts(settings, accounts)
+    return True
+
+
+def migrate_tokens_to_keyring(settings: Settings) -> None:
+    """Move any plaintext tokens on disk into the OS keyring."""
+    accounts = _read_accounts(settings)
+    if settings.use_keyring and accounts.accounts:
+        _write_accounts(settings, accounts)
+
+
+def get_github_account(
+    settings: Settings, user: str | None = None
+) -> GitHubAccount | None:
+    """Return the named account, or the default account when *user* is None."""
+    accounts = _read_accounts(settings)
+    login = user or accounts.default
+    if login is None:
+        return None
+    return accounts.find(login)
 
 
-def get_github_token(settings: Settings) -> str | None:
-    """Get the stored GitHub PAT, or None if not configured."""
-    data = _read_token(settings)
-    if data is None:
+def get_github_token(settings: Settings, user: str | None = None) -> str | None:
+    """Get the PAT for *user* (default account if None), or None if not configured."""
+    account = get_github_account(settings, user)
+    if account is None:
         return None
-    return data.get("token")
+    return account.token
+
+
+BOT_AUTHOR = ("attractor-app[bot]", "attractor-app[bot]@users.noreply.github.com")
+
+
+def commit_author(
+    settings: Settings,
+    user: str | None = None,
+    override: CommitIdentity | None = None,
+) -> tuple[str, str] | None:
+    """(name, email) to author store commits as, or None to use git's own config.
+
+    Each part comes from the project's *override*, then the app settings,
+    then the GitHub account's login and commit email.
+    """
+    app = _read_config(settings.data_dir).settings
+    account = get_github_account(settings, user)
+    name = (override and override.name) or app.commit_name
+    email = (override and override.email) or app.commit_email
+    if account is not None:
+        name = name or account.user
+        email = email or account.commit_email
+    if not name or not email:
+        return None
+    return name, email
+
+
+def store_author(
+    settings: Settings,
+    user: str | None = None,
+    override: CommitIdentity | None = None,
+) -> tuple[tuple[str, str] | None, str | None]:
+    """Author for store commits, and the person behind them when that is the bot."""
+    author = commit_author(settings, user, override)
+    if not _read_config(settings.data_dir).settings.commit_as_bot:
+        return author, None
+    return BOT_AUTHOR, author[0] if author else None
+
+
+def _require_account_token(settings: Settings, account: str | None) -> str:
+    token = get_github_token(settings, account)
+    if token is None:
+        raise HTTPException(
+            status_code=401,
+            detail="GitHub token not configured. Set a token first via POST /api/github/token.",
+        )
+    return token
 
 
 class GitHubStatusResponse(BaseModel):
@@ -75,51 +213,285 @@ class PatUrlResponse(BaseModel):
     required_permissions: list[str]
 
 
+class DeviceLoginResponse(BaseModel):
+    status: str
+    user_code: str
+    verification_uri: str
+    expires_at: str
+    user: str | None = None
+    error: str | None = None
+
+
 @router.get("/status")
 async def github_status(
     settings: Settings = Depends(get_settings),
 ) -> GitHubStatusResponse:
-    """Check if a GitHub PAT is configured and valid."""
-    data = _read_token(settings)
-    if data is None:
+    """Check if a default GitHub account is configured."""
+    account = get_github_account(settings)
+    if account is None:
         return GitHubStatusResponse(configured=False)
     return GitHubStatusResponse(
         configured=True,
-        user=data.get("user"),
-        validated_at=data.get("validated_at"),
+        user=account.user,
+        validated_at=account.validated_at,
     )
 
 
+async def _validate_and_store_token(
+    settings: Settings,
+    token: str,
+    make_default: bool = True,
+    source: Literal["token", "credential_helper"] = "token",
+) -> SetTokenResponse:
+    """Validate *token* against the GitHub API and store it as an account.
+
+    An existing account for the same login is replaced.
+    """
+    client = GitHubClient(token)
+    user_info = await client.get_authenticated_user()
+
+    validated_at = datetime.now(timezone.utc).isoformat()
+    accounts = _read_accounts(settings)
+    previous = accounts.find(user_info["login"])
+    account = GitHubAccount(
+        user=user_info["login"],
+        token=token,
+        source=source,
+        validated_at=validated_at,
+        email=previous.email if previous else None,
+    )
+    accounts.accounts = [a for a in accounts.accounts if a.user != account.user]
+    accounts.accounts.append(account)
+    if make_default or accounts.default is None:
+        accounts.default = account.user
+    _write_accounts(settings, accounts)
+    return SetTokenResponse(user=account.user, validated_at=validated_at)
+
+
 @router.post("/token")
 async def set_token(
     req: SetTokenRequest, settings: Settings = Depends(get_settings)
 ) -> SetTokenResponse:
-    """Set or update the GitHub PAT. Validates via GitHub API."""
-    client = GitHubClient(req.token)
+    """Set or update the default GitHub account's PAT. Validates via GitHub API."""
     try:
-        user_info = await client.get_authenticated_user()
+        return await _validate_and_store_token(settings, req.token)
     except Exception as exc:
         raise HTTPException(
             status_code=401,
             detail=f"Token validation failed: {exc}",
         ) from exc
 
-    validated_at = datetime.now(timezone.utc).isoformat()
-    _write_token(
-        settings,
-        {
-            "token": req.token,
-            "user": user_info["login"],
-            "validated_at": validated_at,
-        },
-    )
-    return SetTokenResponse(user=user_info["login"], validated_at=validated_at)
-
 
 @router.delete("/token", status_code=204)
 async def remove_token(settings: Settings = Depends(get_settings)) -> None:
-    """Remove the stored GitHub PAT."""
-    _delete_token(settings)
+    """Remove the default GitHub account."""
+    account = get_github_account(settings)
+    if account is not None:
+        _remove_account(settings, account.user)
+
+
+class LogoutRequest(BaseModel):
+    wipe_clones: bool = False
+
+
+class LogoutResponse(BaseModel):
+    removed_accounts: list[str]
+    removed_projects: list[str] = []
+
+
+@router.post("/logout")
+async def logout(
+    req: LogoutRequest | None = None,
+    settings: Settings = Depends(get_settings),
+    device_login: DeviceLoginManager = Depends(get_device_login_manager),
+) -> LogoutResponse:
+    """Forget every GitHub account and anything cached for it.
+
+    Tokens are deleted from the keyring, the accounts file is removed, and
+    cached credentials and API responses are dropped. With ``wipe_clones``,
+    projects whose store is on GitHub are also removed locally; their data
+    stays on GitHub.
+    """
+    accounts = _read_accounts(settings)
+    for account in accounts.accounts:
+        if account.token_ref is not None:
+            delete_secret(account.token_ref)
+    _accounts_path(settings).unlink(missing_ok=True)
+    _legacy_token_path(settings).unlink(missing_ok=True)
+    clear_cache()
+    clear_response_cache()
+    device_login.cancel()
+
+    removed_projects: list[str] = []
+    if req is not None and req.wipe_clones:
+        for project in list_projects(settings):
+            config = load_project_config(project.name, settings)
+            if config.store.github is not None:
+                remove_local_project(config, settings)
+                removed_projects.append(config.name)
+    return LogoutResponse(
+        removed_accounts=[a.user for a in accounts.accounts],
+        removed_projects=removed_projects,
+    )
+
+
+# ---------------------------------------------------------------------------
+# Accounts
+# ---------------------------------------------------------------------------
+
+
+class GitHubAccountInfo(BaseModel):
+    user: str
+    validated_at: str
+    is_default: bool
+    source: str
+    commit_email: str
+
+
+class GitHubEmail(BaseModel):
+    email: str
+    primary: bool
+    verified: bool
+
+
+class AccountEmailsResponse(BaseModel):
+    available: bool  # False when the token can't read email addresses
+    emails: list[GitHubEmail]
+    selected: str | None = None
+
+
+class SetCommitEmailRequest(BaseModel):
+    email: str | None = None
+
+
+class AddAccountRequest(BaseModel):
+    token: str
+    make_default: bool = False
+
+
+@router.get("/accounts")
+async def list_accounts(
+    settings: Settings = Depends(get_settings),
+) -> list[GitHubAccountInfo]:
+    """List configured GitHub accounts (tokens are never returned)."""
+    accounts = _read_accounts(settings)
+    return [
+        GitHubAccountInfo(
+            user=a.user,
+            validated_at=a.validated_at,
+            is_default=a.user == accounts.default,
+            source=a.source,
+            commit_email=a.commit_email,
+        )
+        for a in accounts.accounts
+    ]
+
+
+@router.post("/accounts", status_code=201)
+async def add_account(
+    req: AddAccountRequest, settings: Settings = Depends(get_settings)
+) -> SetTokenResponse:
+    """Add a GitHub account from a PAT. Validates via GitHub API."""
+    try:
+        return await _validate_and_store_token(settings, req.token, req.make_default)
+    except Exception as exc:
+        raise HTTPException(
+            status_code=401,
+            detail=f"Token validation failed: {exc}",
+        ) from exc
+
+
+class CredentialHelperRequest(BaseModel):
+    make_default: bool = True
+
+
+@router.post("/accounts/credential-helper", status_code=201)
+async def add_credential_helper_account(
+    req: CredentialHelperRequest, settings: Settings = Depends(get_settings)
+) -> SetTokenResponse:
+    """Add the account git's credential helper (gh, osxkeychain, GCM) signs in as."""
+    clear_cache()
+    token = credential_fill()
+    if token is None:
+        raise HTTPException(
+            status_code=404,
+            detail="No github.com credentials found in git's credential helpers.",
+        )
+    try:
+        return await _validate_and_store_token(
+            settings, token, req.make_default, source="credential_helper"
+        )
+    except Exception as exc:
+        raise HTTPException(
+            status_code=401,
+            detail=f"Credential helper token was rejected by GitHub: {exc}",
+        ) from exc
+
+
+@router.delete("/accounts/{user}", status_code=204)
+async def remove_account(user: str, settings: Settings = Depends(get_settings)) -> None:
+    """Remove a GitHub account."""
+    if not _remove_account(settings, user):
+        raise HTTPException(status_code=404, detail=f"Account '{user}' not found")
+
+
+@router.put("/accounts/{user}/default", status_code=204)
+async def set_default_account(
+    user: str, settings: Settings = Depends(get_settings)
+) -> None:
+    """Make *user* the account used by projects without an explicit binding."""
+    accounts = _read_accounts(settings)
+    if accounts.find(user) is None:
+        raise HTTPException(status_code=404, detail=f"Account '{user}' not found")
+    accounts.default = user
+    _write_accounts(settings, accounts)
+
+
+async def _verified_emails(account: GitHubAccount) -> list[GitHubEmail] | None:
+    if account.token is None:
+        return None
+    emails = await GitHubClient(account.token).list_emails()
+    if emails is None:
+        return None
+    return [GitHubEmail.model_validate(e) for e in emails if e["verified"]]
+
+
+@router.get("/accounts/{user}/emails")
+async def list_account_emails(
+    user: str, settings: Settings = Depends(get_settings)
+) -> AccountEmailsResponse:
+    """List *user*'s verified emails that store commits can be authored with."""
+    account = get_github_account(settings, user)
+    if account is None:
+        raise HTTPException(status_code=404, detail=f"Account '{user}' not found")
+    emails = await _verified_emails(account)
+    return AccountEmailsResponse(
+        available=emails is not None, emails=emails or [], selected=account.email
+    )
+
+
+@router.put("/accounts/{user}/email", status_code=204)
+async def set_commit_email(
+    user: str, req: SetCommitEmailRequest, settings: Settings = Depends(get_settings)
+) -> None:
+    """Author *user*'s store commits with a verified email (None for noreply)."""
+    accounts = _read_accounts(settings)
+    account = accounts.find(user)
+    if account is None:
+        raise HTTPException(status_code=404, detail=f"Account '{user}' not found")
+    if req.email is not None:
+        emails = await _verified_emails(account)
+        if emails is None:
+            raise HTTPException(
+                status_code=403,
+                detail="Token cannot read email addresses. Grant the user:email scope.",
+            )
+        if req.email not in {e.email for e in emails}:
+            raise HTTPException(
+                status_code=400, detail=f"'{req.email}' is not a verified email"
+            )
+    account.email = req.email
+    _write_accounts(settings, accounts)
 
 
 @router.get("/pat-url")
@@ -131,6 +503,225 @@ async def get_pat_url() -> PatUrlResponse:
     )
 
 
+def _device_login_response(manager: DeviceLoginManager) -> DeviceLoginResponse:
+    login = manager.current
+    if login is None:
+        raise HTTPException(status_code=404, detail="No device login in progress")
+    return DeviceLoginResponse(
+        status=login.status,
+        user_code=login.user_code,
+        verification_uri=login.verification_uri,
+        expires_at=login.expires_at.isoformat(),
+        user=login.user,
+        error=login.error,
+    )
+
+
+@router.post("/device-login", status_code=202)
+async def start_device_login(
+    settings: Settings = Depends(get_settings),
+    manager: DeviceLoginManager = Depends(get_device_login_manager),
+    ws_manager: WebSocketManager = Depends(get_ws_manager),
+) -> DeviceLoginResponse:
+    """Begin GitHub's device authorization flow.
+
+    The user enters the returned code at the verification URL. The token is
+    stored automatically once approved, and ``github:login_completed`` is
+    broadcast over the WebSocket.
+    """
+    if settings.github_client_id is None:
+        raise HTTPException(
+            status_code=400,
+            detail="Device login requires ATTRACTOR_GITHUB_CLIENT_ID to be set.",
+        )
+
+    async def on_token(token: str) -> str:
+        result = await _validate_and_store_token(settings, token)
+        return result.user
+
+    try:
+        await manager.start(settings.github_client_id, on_token, ws_manager)
+    except Exception as exc:
+        raise HTTPException(
+            status_code=502,
+            detail=f"Failed to start device login: {exc}",
+        ) from exc
+    return _device_login_response(manager)
+
+
+@router.get("/device-login")
+async def get_device_login(
+    manager: DeviceLoginManager = Depends(get_device_login_manager),
+) -> DeviceLoginResponse:
+    """Return the state of the current device login flow."""
+    return _device_login_response(manager)
+
+
+@router.delete("/device-login", status_code=204)
+async def cancel_device_login(
+    manager: DeviceLoginManager = Depends(get_device_login_manager),
+) -> None:
+    """Cancel a pending device login flow."""
+    if not manager.cancel():
+        raise HTTPException(status_code=404, detail="No pending device login")
+
+
+# ---------------------------------------------------------------------------
+# Capabilities
+# ---------------------------------------------------------------------------
+
+
+class CapabilityCheck(BaseModel):
+    name: str
+    ok: bool
+    detail: str
+    fix: str | None = None
+
+
+class TokenCapabilitiesResponse(BaseModel):
+    user: str | None = None
+    token_type: Literal["classic", "fine-grained"] | None = None
+    scopes: list[str] | None = None
+    checks: list[CapabilityCheck]
+    missing: list[str]
+
+
+@router.get("/capabilities")
+async def check_token_capabilities(
+    account: str | None = Query(default=None),
+    owner: str | None = Query(default=None),
+    repo: str | None = Query(default=None),
+    settings: Settings = Depends(get_settings),
+) -> TokenCapabilitiesResponse:
+    """Probe the token for the permissions the app needs.
+
+    Pass *owner*/*repo* to also check read/write access on a specific repo
+    (typically a project's backing store).
+    """
+    client = GitHubClient(_require_account_token(settings, account))
+    checks: list[CapabilityCheck] = []
+
+    try:
+        user_info = await client.get_authenticated_user()
+        scopes = await client.get_token_scopes()
+    except Exception as exc:
+        checks.append(
+            CapabilityCheck(
+                name="user",
+                ok=False,
+                detail=f"Token was rejected: {exc}",
+                fix="Create a new token and paste it into Settings > GitHub Authentication.",
+            )
+        )
+        return TokenCapabilitiesResponse(checks=checks, missing=["user"])
+
+    checks.append(
+        CapabilityCheck(name="user", ok=True, detail=f"Authenticated as {user_info['login']}")
+    )
+
+    if await client.can_create_repos():
+        checks.append(
+            CapabilityCheck(name="repo_create", ok=True, detail="Can create repositories")
+        )
+    else:
+        checks.append(
+            CapabilityCheck(
+                name="repo_create",
+                ok=False,
+                detail="Cannot create repositories",
+                fix=(
+                    f"Grant 'Administration: read & write' at {PAT_CREATE_URL}, "
+                    "or create store repos manually and connect to them."
+                ),
+            )
+        )
+
+    if owner and repo:
+        sso_url: str | None = None
+        try:
+            permissions = (await client.get_repo(owner, repo)).get("permissions", {})
+        except SamlSsoRequiredError as exc:
+            permissions = {}
+            sso_url = exc.authorization_url
+        except Exception:
+            permissions = {}
+        full_name = f"{owner}/{repo}"
+        if sso_url is not None:
+            checks.append(
+                CapabilityCheck(
+                    name="repo_read",
+                    ok=False,
+                    detail=f"{full_name} requires SAML SSO authorization",
+                    fix=f"Authorize the token for the organization at {sso_url}",
+                )
+            )
+        elif permissions.get("pull"):
+            checks.append(
+                CapabilityCheck(name="repo_read", ok=True, detail=f"Can read {full_name}")
+            )
+        else:
+            checks.append(
+                CapabilityCheck(
+                    name="repo_read",
+                    ok=False,
+                    detail=f"Cannot read {full_name}",
+                    fix=f"Add {full_name} to the token's repository access.",
+                )
+            )
+        if permissions.get("push"):
+            checks.append(
+                CapabilityCheck(name="repo_write", ok=True, detail=f"Can push to {full_name}")
+            )
+        else:
+            checks.append(
+                CapabilityCheck(
+                    name="repo_write",
+                    ok=False,
+                    detail=f"Cannot push to {full_name}",
+                    fix="Grant 'Contents: read & write' for this repository.",
+                )
+            )
+
+    return TokenCapabilitiesResponse(
+        user=user_info["login"],
+        token_type="fine-grained" if scopes is None else "classic",
+        scopes=scopes,
+        checks=checks,
+        missing=[c.name for c in checks if not c.ok],
+    )
+
+
+# ---------------------------------------------------------------------------
+# Rate limits
+# ---------------------------------------------------------------------------
+
+
+class RateLimitBucket(BaseModel):
+    limit: int
+    remaining: int
+    used: int
+    reset: int
+
+
+class RateLimitResponse(BaseModel):
+    core: RateLimitBucket
+    search: RateLimitBucket
+
+
+@router.get("/rate-limit")
+async def get_rate_limit_status(
+    account: str | None = Query(default=None),
+    settings: Settings = Depends(get_settings),
+) -> RateLimitResponse:
+    """Return the account's remaining GitHub API quota."""
+    client = GitHubClient(_require_account_token(settings, account))
+    resources = (await client.get_rate_limit_status())["resources"]
+    return RateLimitResponse(
+        core=RateLimitBucket(**resources["core"]),
+        search=RateLimitBucket(**resources["search"]),
+    )
+
+
 class GitHubRepoInfo(BaseModel):
     full_name: str
     name: str
@@ -138,22 +729,33 @@ class GitHubRepoInfo(BaseModel):
     description: str | None = None
     private: bool
     html_url: str
+    can_push: bool = False
+
+
+class GitHubOrgInfo(BaseModel):
+    login: str
+    avatar_url: str
 
 
 @router.get("/repos")
 async def list_repos(
     q: str | None = Query(default=None),
+    search: str | None = Query(default=None),
+    account: str | None = Query(default=None),
+    affiliation: str | None = Query(default=None),
+    org: str | None = Query(default=None),
     settings: Settings = Depends(get_settings),
 ) -> list[GitHubRepoInfo]:
-    """List GitHub repos for the authenticated user, optionally filtered by name prefix."""
-    token = get_github_token(settings)
-    if token is None:
-        raise HTTPException(
-            status_code=401,
-            detail="GitHub token not configured. Set a token first via POST /api/github/token.",
-        )
-    client = GitHubClient(token)
-    raw = await client.list_repos(prefix=q)
+    """List repos the account can open as projects.
+
+    *q* filters by name prefix and *search* by free text. Includes owned,
+    collaborator, and organization repos unless narrowed by *affiliation*;
+    pass *org* to browse a single organization.
+    """
+    client = GitHubClient(_require_account_token(settings, account))
+    raw = await client.list_repos(
+        prefix=q, affiliation=affiliation, org=org, query=search
+    )
     return [
         GitHubRepoInfo(
             full_name=r["full_name"],
@@ -162,6 +764,44 @@ async def list_repos(
             description=r.get("description"),
             private=r["private"],
             html_url=r["html_url"],
+            can_push=r.get("permissions", {}).get("push", False),
         )
         for r in raw
     ]
+
+
+@router.get("/stores")
+async def list_discovered_stores(
+    account: str | None = Query(default=None),
+    settings: Settings = Depends(get_settings),
+) -> list[DiscoveredStore]:
+    """Find the account's attractor store repos without cloning them."""
+    linked: dict[str, list[str]] = {}
+    for project in list_projects(settings):
+        linked.setdefault(project.store_id, []).append(project.name)
+    client = GitHubClient(_require_account_token(settings, account))
+    return await discover_stores(client, linked)
+
+
+@router.get("/store-names")
+async def suggest_store_names(
+    project: str,
+    account: str | None = Query(default=None),
+    settings: Settings = Depends(get_settings),
+) -> list[str]:
+    """Free store repo names for a project that doesn't exist yet, e.g. a folder name."""
+    client = GitHubClient(_require_account_token(settings, account))
+    return await free_store_repo_names(client, project, count=SUGGESTED_NAMES)
+
+
+@router.get("/orgs")
+async def list_orgs(
+    account: str | None = Query(default=None),
+    settings: Settings = Depends(get_settings),
+) -> list[GitHubOrgInfo]:
+    """List organizations the account belongs to."""
+    client = GitHubClient(_require_account_token(settings, account))
+    return [
+        GitHubOrgInfo(login=o["login"], avatar_url=o["avatar_url"])
+        for o in await client.list_orgs()
+    ]
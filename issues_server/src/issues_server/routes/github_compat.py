@@ -0,0 +1,169 @@
+"""GitHub REST-compatible issue endpoints for scripts, CLI tools, and CI jobs.
+
+Opt-in with ``ATTRACTOR_GITHUB_COMPAT_API``. Routes live at the server root
+(``/repos/{owner}/{repo}/issues``) so clients only need their API base URL
+pointed at this server. ``{repo}`` is the project name, or the GitHub repo a
+project's store is backed by. Lists are returned as plain arrays, as GitHub
+does, rather than wrapped in ``ListResponse``.
+"""
+
+from __future__ import annotations
+
+import secrets
+
+from fastapi import APIRouter, Depends, Header, HTTPException, Query, status
+
+from ..core.config import Settings
+from ..core.models import (
+    Comment,
+    CreateCommentRequest,
+    CreateIssueRequest,
+    Issue,
+    IssueFilters,
+    Label,
+    StateReason,
+    UpdateIssueRequest,
+)
+from ..deps import get_settings, get_ws_manager
+from ..ws import WebSocketManager
+from . import comments, issues
+from .projects import get_project_storage, list_projects
+
+
+def _check_token(
+    authorization: str | None = Header(default=None),
+    settings: Settings = Depends(get_settings),
+) -> None:
+    """Require ``Authorization: token <t>`` or ``Bearer <t>`` when a token is set."""
+    expected = settings.github_compat_token
+    if expected is None:
+        return
+    scheme, _, token = (authorization or "").partition(" ")
+    if scheme.lower() not in ("token", "bearer") or not secrets.compare_digest(
+        token.strip(), expected
+    ):
+        raise HTTPException(status_code=401, detail="Bad credentials")
+
+
+router = APIRouter(
+    prefix="/repos/{owner}/{repo}",
+    tags=["github-compat"],
+    dependencies=[Depends(_check_token)],
+)
+
+
+def _project_name(owner: str, repo: str, settings: Settings) -> str:
+    projects = list_projects(settings)
+    for project in projects:
+        github = project.store.github
+        if github and (github.owner.lower(), github.repo.lower()) == (
+            owner.lower(),
+            repo.lower(),
+        ):
+            return project.name
+    if any(project.name == repo for project in projects):
+        return repo
+    raise HTTPException(status_code=404, detail="Not Found")
+
+
+@router.get("/issues")
+def list_repo_issues(
+    owner: str,
+    repo: str,
+    state: str = "open",
+    reason: StateReason | None = None,
+    labels: str | None = None,
+    assignee: str | None = None,
+    mentioned: str | None = None,
+    sort: str = "created",
+    direction: str = "desc",
+    page: int = 1,
+    per_page: int = Query(default=30, le=100),
+    settings: Settings = Depends(get_settings),
+) -> list[Issue]:
+    name = _project_name(owner, repo, settings)
+    filters = IssueFilters(
+        state=state,
+        reason=reason,
+        labels=labels,
+        assignee=assignee,
+        mentioned=mentioned,
+        sort=sort,
+        direction=direction,
+        page=page,
+        per_page=per_page,
+    )
+    return get_project_storage(name, settings).list_issues(filters).items
+
+
+@router.post("/issues", status_code=status.HTTP_201_CREATED)
+async def create_repo_issue(
+    owner: str,
+    repo: str,
+    body: CreateIssueRequest,
+    settings: Settings = Depends(get_settings),
+    ws: WebSocketManager = Depends(get_ws_manager),
+) -> Issue:
+    name = _project_name(owner, repo, settings)
+    storage = get_project_storage(name, settings)
+    return await issues.create_issue(name, body, settings, storage, ws)
+
+
+@router.get("/issues/{number}")
+def get_repo_issue(
+    owner: str, repo: str, number: int, settings: Settings = Depends(get_settings)
+) -> Issue:
+    name = _project_name(owner, repo, settings)
+    issue = get_project_storage(name, settings).read_issue(number)
+    if issue is None:
+        raise HTTPException(status_code=404, detail="Not Found")
+    return issue
+
+
+@router.patch("/issues/{number}")
+async def update_repo_issue(
+    owner: str,
+    repo: str,
+    number: int,
+    body: UpdateIssueRequest,
+    settings: Settings = Depends(get_settings),
+    ws: WebSocketManager = Depends(get_ws_manager),
+) -> Issue:
+    name = _project_name(owner, repo, settings)
+    storage = get_project_storage(name, settings)
+    return await issues.update_issue(name, number, body, settings, storage, ws)
+
+
+@router.get("/issues/{number}/comments")
+async def list_repo_issue_comments(
+    owner: str,
+    repo: str,
+    number: int,
+    page: int = 1,
+    per_page: int = Query(default=30, le=100),
+    settings: Settings = Depends(get_settings),
+) -> list[Comment]:
+    storage = get_project_storage(_project_name(owner, repo, settings), settings)
+    return (await comments.list_comments(number, page, per_page, storage)).items
+
+
+@router.post("/issues/{number}/comments", status_code=status.HTTP_201_CREATED)
+async def create_repo_issue_comment(
+    owner: str,
+    repo: str,
+    number: int,
+    body: CreateCommentRequest,
+    settings: Settings = Depends(get_settings),
+    ws: WebSocketManager = Depends(get_ws_manager),
+) -> Comment:
+    name = _project_name(owner, repo, settings)
+    storage = get_project_storage(name, settings)
+    return await comments.create_comment(name, number, body, storage, ws, settings)
+
+
+@router.get("/labels")
+def list_repo_labels(
+    owner: str, repo: str, settings: Settings = Depends(get_settings)
+) -> list[Label]:
+    name = _project_name(owner, repo, settings)
+    return get_project_storage(name, settings).read_labels()
@@ -0,0 +1,580 @@
+"""Routes for moving issues between a project and real GitHub Issues."""
+
+import asyncio
+import json
+import logging
+import subprocess
+from datetime import datetime, timezone
+from pathlib import Path
+
+import httpx
+from fastapi import APIRouter, Depends, HTTPException, Response
+from pydantic import BaseModel
+
+from ..core.config import Settings
+from ..core.errors import AppError
+from ..core.models import (
+    Comment,
+    Issue,
+    IssueFilters,
+    IssueMirror,
+    MirrorConfig,
+    ProjectConfig,
+)
+from ..core.storage import ProjectStorage
+from ..deps import (
+    get_mirror_scheduler,
+    get_notifications,
+    get_settings,
+    get_ws_manager,
+)
+from ..github_client import GitHubClient
+from ..github_issues import (
+    ExportResult,
+    ImportResult,
+    MirrorScheduler,
+    MirrorSyncResult,
+    export_to_github_issues,
+    import_github_issues,
+    link_issue_mirror,
+    sync_issue_mirrors,
+)
+from ..notifications import new_notification, notify
+from ..pull_requests import (
+    add_linked_pr,
+    linked_pull_request,
+    parse_pull_request_url,
+    refresh_linked_prs,
+)
+from ..ws import WebSocketManager
+from .assignees import author_association, current_user
+from .github_auth import get_github_token, store_author
+from .project_repo import _project_repo
+from .projects import get_project_storage, load_project_config, save_project_config
+from .store import _require_token
+
+logger = logging.getLogger(__name__)
+
+router = APIRouter(prefix="/projects/{name}/github", tags=["github-issues"])
+
+
+class ImportRequest(BaseModel):
+    owner: str
+    repo: str
+
+
+class LinkMirrorRequest(BaseModel):
+    remote_number: int | None = None
+
+
+class ExportRequest(BaseModel):
+    owner: str
+    repo: str
+    state: str = "all"
+    labels: str | None = None
+    assignee: str | None = None
+
+
+def _github_error(exc: httpx.HTTPStatusError, owner: str, repo: str) -> HTTPException:
+    if exc.response.status_code == 404:
+        return HTTPException(
+            status_code=404,
+            detail=f"Repository {owner}/{repo} not found or not accessible.",
+        )
+    return HTTPException(
+        status_code=502, detail=f"GitHub API error: {exc.response.status_code}"
+    )
+
+
+@router.post("/import")
+async def import_issues(
+    name: str,
+    body: ImportRequest,
+    settings: Settings = Depends(get_settings),
+    storage: ProjectStorage = Depends(get_project_storage),
+    ws: WebSocketManager = Depends(get_ws_manager),
+) -> ImportResult:
+    """Import issues, comments, labels and milestones from a GitHub repo."""
+    config = load_project_config(name, settings)
+    client = GitHubClient(_require_token(settings, config))
+    storage.sync()
+
+    try:
+        result = await import_github_issues(storage, client, body.owner, body.repo)
+    except httpx.HTTPStatusError as exc:
+        raise _github_error(exc, body.owner, body.repo) from exc
+
+    storage.commit(
+        f"Import {result.issues} issues from {body.owner}/{body.repo}",
+        event="github.import",
+    )
+    storage.push()
+
+    await ws.broadcast(
+        "issues:imported",
+        {"project": name, "result": result.model_dump(mode="json")},
+    )
+    return result
+
+
+@router.post("/export")
+async def export_issues(
+    name: str,
+    body: ExportRequest,
+    settings: Settings = Depends(get_settings),
+    storage: ProjectStorage = Depends(get_project_storage),
+) -> ExportResult:
+    """Create issues and comments on a GitHub repo for matching local issues."""
+    config = load_project_config(name, settings)
+    client = GitHubClient(_require_token(settings, config))
+    storage.sync()
+
+    filters = IssueFilters(
+        state=body.state, labels=body.labels, assignee=body.assignee, direction="asc"
+    )
+    try:
+        result = await export_to_github_issues(
+            storage, client, body.owner, body.repo, filters
+        )
+    except httpx.HTTPStatusError as exc:
+        raise _github_error(exc, body.owner, body.repo) from exc
+    finally:
+        # Issues already created on GitHub must stay recorded even on failure.
+        storage.commit(f"Export issues to {body.owner}/{body.repo}", event="github.export")
+    storage.push()
+    return result
+
+
+# ---------------------------------------------------------------------------
+# Pull requests
+# ---------------------------------------------------------------------------
+
+
+class PullRequestRequest(BaseModel):
+    issue_number: int
+    base: str | None = None
+
+
+class PullRequestResult(BaseModel):
+    number: int
+    url: str
+    created: bool
+
+
+def _pull_request_body(
+    project: str, issue: Issue, mirror: IssueMirror | None, owner: str, repo: str
+) -> str:
+    lines = [f"Implements Attractor issue `{project}#{issue.number}`: {issue.title}"]
+    if mirror is not None and (mirror.owner, mirror.repo) == (owner, repo):
+        lines.append(f"Closes #{mirror.number}")
+    elif mirror is not None:
+        lines.append(f"Tracked in {mirror.url}")
+    return "\n\n".join(lines)
+
+
+@router.post("/pulls")
+async def create_pr_for_issue(
+    name: str,
+    body: PullRequestRequest,
+    settings: Settings = Depends(get_settings),
+    storage: ProjectStorage = Depends(get_project_storage),
+    ws: WebSocketManager = Depends(get_ws_manager),
+) -> PullRequestResult:
+    """Push an issue's work branch and open a pull request for it.
+
+    The PR description links back to the issue and the PR URL is added to
+    the issue as a comment. An open PR for the branch is returned instead of
+    opening another.
+    """
+    config = load_project_config(name, settings)
+    token = _require_token(settings, config)
+    storage.sync()
+    issue = storage.read_issue(body.issue_number)
+    if issue is None:
+        raise HTTPException(
+            status_code=404, detail=f"Issue #{body.issue_number} not found"
+        )
+    if issue.branch is None:
+        raise HTTPException(
+            status_code=409,
+            detail=f"Create a branch for issue #{issue.number} first.",
+        )
+    repo, _ = _project_repo(name, settings)
+    github_repo = repo.github_repo()
+    if github_repo is None:
+        raise HTTPException(
+            status_code=409,
+            detail="The project repository's origin is not a GitHub repository.",
+        )
+    owner, repo_name = github_repo
+    await asyncio.to_thread(repo.push, token, issue.branch)
+
+    client = GitHubClient(token)
+    try:
+        pr = await client.find_pull_request(owner, repo_name, f"{owner}:{issue.branch}")
+        created = pr is None
+        if pr is None:
+            base = (
+                body.base or (await client.get_repo(owner, repo_name))["default_branch"]
+            )
+            pr = await client.create_pull_request(
+                owner,
+                repo_name,
+                {
+                    "title": issue.title,
+                    "head": issue.branch,
+                    "base": base,
+                    "body": _pull_request_body(
+                        name, issue, storage.read_mirror(issue.number), owner, repo_name
+                    ),
+                },
+            )
+    except httpx.HTTPStatusError as exc:
+        if exc.response.status_code == 422:
+            errors = exc.response.json().get("errors") or [{}]
+            message = errors[0].get("message") or exc.response.json().get("message")
+            raise HTTPException(
+                status_code=422, detail=f"GitHub rejected the pull request: {message}"
+            ) from exc
+        raise _github_error(exc, owner, repo_name) from exc
+
+    now = datetime.now(timezone.utc)
+    add_linked_pr(issue, linked_pull_request(owner, repo_name, pr))
+    issue.updated_at = now
+    comment = None
+    if created:
+        comment = Comment(
+            id=storage.next_comment_id(),
+            body=f"Opened pull request {pr['html_url']}",
+            user=await current_user(config, settings),
+            created_at=now,
+            updated_at=now,
+            author_association=await author_association(config, settings),
+        )
+        storage.write_comment(issue.number, comment)
+        issue.comments += 1
+    storage.write_issue(issue)
+    storage.commit(
+        f"Link pull request {pr['html_url']} to issue #{issue.number}",
+        event="issue.pull_request",
+        issue=issue.number,
+    )
+    storage.push()
+
+    await ws.broadcast(
+        "issue:updated", {"project": name, "issue": issue.model_dump(mode="json")}
+    )
+    if comment is not None:
+        await ws.broadcast(
+            "comment:created",
+            {
+                "project": name,
+                "issueNumber": issue.number,
+                "comment": comment.model_dump(mode="json"),
+            },
+        )
+    return PullRequestResult(number=pr["number"], url=pr["html_url"], created=created)
+
+
+class LinkPullRequestRequest(BaseModel):
+    issue_number: int
+    url: str
+
+
+class RefreshPullRequestsResult(BaseModel):
+    updated: list[int]
+
+
+@router.post("/pulls/link")
+async def link_pr(
+    name: str,
+    body: LinkPullRequestRequest,
+    settings: Settings = Depends(get_settings),
+    storage: ProjectStorage = Depends(get_project_storage),
+    ws: WebSocketManager = Depends(get_ws_manager),
+) -> Issue:
+    """Link an existing GitHub pull request to an issue by its URL."""
+    parsed = parse_pull_request_url(body.url)
+    if parsed is None:
+        raise HTTPException(
+            status_code=422,
+            detail="Expected a URL like https://github.com/{owner}/{repo}/pull/{number}",
+        )
+    owner, repo, number = parsed
+    client = GitHubClient(_require_token(settings, load_project_config(name, settings)))
+    try:
+        pr = await client.get_pull_request(owner, repo, number)
+    except httpx.HTTPStatusError as exc:
+        raise _github_error(exc, owner, repo) from exc
+
+    storage.sync()
+    issue = storage.read_issue(body.issue_number)
+    if issue is None:
+        raise HTTPException(
+            status_code=404, detail=f"Issue #{body.issue_number} not found"
+        )
+    add_linked_pr(issue, linked_pull_request(owner, repo, pr))
+    issue.updated_at = datetime.now(timezone.utc)
+    storage.write_issue(issue)
+    storage.commit(
+        f"Link pull request {pr['html_url']} to issue #{issue.number}",
+        event="issue.pull_request",
+        issue=issue.number,
+    )
+    storage.push()
+
+    await ws.broadcast(
+        "issue:updated", {"project": name, "issue": issue.model_dump(mode="json")}
+    )
+    return issue
+
+
+async def refresh_project_prs(
+    config: ProjectConfig, settings: Settings, ws: WebSocketManager
+) -> RefreshPullRequestsResult:
+    """Refresh one project's linked PR states, then commit and push the store."""
+    client = GitHubClient(_require_token(settings, config))
+    author, actor = store_author(settings, config.github_account, config.author)
+    storage = ProjectStorage(
+        Path(config.store.path),
+        author,
+        branch=config.store.branch,
+        actor=actor,
+        store_id=config.store_id,
+    )
+    storage.sync()
+    changed = await refresh_linked_prs(storage, client)
+    if changed:
+        storage.commit("Refresh linked pull requests", event="pulls.refresh")
+        storage.push()
+    for issue in changed:
+        await ws.broadcast(
+            "issue:updated",
+            {"project": config.name, "issue": issue.model_dump(mode="json")},
+        )
+    return RefreshPullRequestsResult(updated=[issue.number for issue in changed])
+
+
+async def refresh_all_linked_prs(settings: Settings, ws: WebSocketManager) -> None:
+    """Scheduled pass over every project whose GitHub account is configured."""
+    for config_path in sorted((settings.data_dir / "projects").glob("*/project.json")):
+        config = ProjectConfig(**json.loads(config_path.read_text()))
+        if (
+            not Path(config.store.path).exists()
+            or get_github_token(settings, config.github_account) is None
+        ):
+            continue
+        try:
+            await refresh_project_prs(config, settings, ws)
+        except (
+            AppError,
+            HTTPException,
+            subprocess.CalledProcessError,
+        ) as exc:
+            logger.warning(
+                "Pull request refresh for project '%s' failed: %s", config.name, exc
+            )
+
+
+@router.post("/pulls/refresh")
+async def refresh_pull_requests(
+    name: str,
+    settings: Settings = Depends(get_settings),
+    ws: WebSocketManager = Depends(get_ws_manager),
+) -> RefreshPullRequestsResult:
+    """Fetch the current state of the project's linked pull requests now."""
+    return await refresh_project_prs(load_project_config(name, settings), settings, ws)
+
+
+# ---------------------------------------------------------------------------
+# Mirroring
+# ---------------------------------------------------------------------------
+
+
+def _require_mirror(config: ProjectConfig) -> MirrorConfig:
+    if config.mirror is None:
+        raise HTTPException(status_code=400, detail="Mirroring is not configured.")
+    return config.mirror
+
+
+async def run_mirror_sync(
+    config: ProjectConfig,
+    settings: Settings,
+    ws: WebSocketManager,
+    scheduler: MirrorScheduler,
+) -> MirrorSyncResult:
+    """Sync one project's mirrored issues, then commit and push the store."""
+    mirror = _require_mirror(config)
+    client = GitHubClient(_require_token(settings, config))
+    author, actor = store_author(settings, config.github_account, config.author)
+    storage = ProjectStorage(
+        Path(config.store.path),
+        author,
+        branch=config.store.branch,
+        actor=actor,
+        store_id=config.store_id,
+    )
+
+    async with scheduler.lock(config.name):
+        storage.sync()
+        try:
+            result = await sync_issue_mirrors(storage, client, mirror)
+        finally:
+            storage.commit(
+                f"Sync mirrored issues with {mirror.owner}/{mirror.repo}",
+                event="mirror.sync",
+            )
+        storage.push()
+
+    if result.changed:
+        await ws.broadcast(
+            "mirror:synced",
+            {"project": config.name, "result": result.model_dump(mode="json")},
+        )
+    if result.conflicts:
+        count = len(result.conflicts)
+        await notify(
+            get_notifications(),
+            ws,
+            new_notification(
+                "conflict",
+                config.name,
+                f"{count} mirrored issue{'s' if count != 1 else ''} in conflict with "
+                f"{mirror.owner}/{mirror.repo}",
+                result.conflicts[0] if count == 1 else None,
+            ),
+        )
+    return result
+
+
+async def sync_all_mirrors(
+    settings: Settings, ws: WebSocketManager, scheduler: MirrorScheduler
+) -> None:
+    """Scheduled pass over every project with mirroring configured."""
+    for config_path in sorted((settings.data_dir / "projects").glob("*/project.json")):
+        config = ProjectConfig(**json.loads(config_path.read_text()))
+        if config.mirror is None or not Path(config.store.path).exists():
+            continue
+        try:
+            await run_mirror_sync(config, settings, ws, scheduler)
+        except (
+            AppError,
+            HTTPException,
+            httpx.HTTPError,
+            subprocess.CalledProcessError,
+        ) as exc:
+            logger.warning("Mirror sync for project '%s' failed: %s", config.name, exc)
+
+
+@router.get("/mirror")
+def get_mirror_config(
+    name: str, settings: Settings = Depends(get_settings)
+) -> MirrorConfig | None:
+    """Return the project's mirror configuration, if any."""
+    return load_project_config(name, settings).mirror
+
+
+@router.put("/mirror")
+async def set_mirror_config(
+    name: str,
+    body: MirrorConfig,
+    settings: Settings = Depends(get_settings),
+) -> MirrorConfig:
+    """Choose the GitHub repo to mirror with and the conflict policy."""
+    config = load_project_config(name, settings)
+    client = GitHubClient(_require_token(settings, config))
+    try:
+        await client.get_repo(body.owner, body.repo)
+    except httpx.HTTPStatusError as exc:
+        raise _github_error(exc, body.owner, body.repo) from exc
+    config.mirror = body
+    save_project_config(config, settings)
+    return body
+
+
+@router.delete("/mirror", status_code=204)
+def disable_mirror(name: str, settings: Settings = Depends(get_settings)) -> Response:
+    """Stop scheduled syncing. Issue links stay in the store."""
+    config = load_project_config(name, settings)
+    config.mirror = None
+    save_project_config(config, settings)
+    return Response(status_code=204)
+
+
+@router.post("/mirror/sync")
+async def sync_mirror(
+    name: str,
+    settings: Settings = Depends(get_settings),
+    ws: WebSocketManager = Depends(get_ws_manager),
+    scheduler: MirrorScheduler = Depends(get_mirror_scheduler),
+) -> MirrorSyncResult:
+    """Sync mirrored issues now instead of waiting for the schedule."""
+    config = load_project_config(name, settings)
+    mirror = _require_mirror(config)
+    try:
+        return await run_mirror_sync(config, settings, ws, scheduler)
+    except httpx.HTTPStatusError as exc:
+        raise _github_error(exc, mirror.owner, mirror.repo) from exc
+
+
+@router.get("/mirror/issues/{number}")
+def get_issue_mirror(
+    number: int, storage: ProjectStorage = Depends(get_project_storage)
+) -> IssueMirror:
+    """Return the GitHub link for a mirrored issue."""
+    mirror = storage.read_mirror(number)
+    if mirror is None:
+        raise HTTPException(status_code=404, detail=f"Issue #{number} is not mirrored")
+    return mirror
+
+
+@router.put("/mirror/issues/{number}")
+async def link_mirror(
+    name: str,
+    number: int,
+    body: LinkMirrorRequest,
+    settings: Settings = Depends(get_settings),
+    storage: ProjectStorage = Depends(get_project_storage),
+    scheduler: MirrorScheduler = Depends(get_mirror_scheduler),
+) -> IssueMirror:
+    """Mirror an issue, linking it to an existing GitHub issue or creating one."""
+    config = load_project_config(name, settings)
+    mirror_config = _require_mirror(config)
+    client = GitHubClient(_require_token(settings, config))
+
+    async with scheduler.lock(name):
+        storage.sync()
+        issue = storage.read_issue(number)
+        if issue is None:
+            raise HTTPException(status_code=404, detail=f"Issue #{number} not found")
+        try:
+            mirror = await link_issue_mirror(
+                storage, client, mirror_config, issue, body.remote_number
+            )
+        except httpx.HTTPStatusError as exc:
+            raise _github_error(exc, mirror_config.owner, mirror_config.repo) from exc
+        storage.commit(
+            f"Mirror issue #{number} to {mirror.owner}/{mirror.repo}#{mirror.number}",
+            event="mirror.link",
+            issue=number,
+        )
+        storage.push()
+    return mirror
+
+
+@router.delete("/mirror/issues/{number}", status_code=204)
+async def unlink_mirror(
+    name: str,
+    number: int,
+    storage: ProjectStorage = Depends(get_project_storage),
+    scheduler: MirrorScheduler = Depends(get_mirror_scheduler),
+) -> Response:
+    """Stop mirroring an issue. Neither copy is deleted."""
+    async with scheduler.lock(name):
+        storage.sync()
+        storage.delete_mirror(number)
+        storage.commit(
+            f"Stop mirroring issue #{number}", event="mirror.unlink", issue=number
+        )
+        storage.push()
+    return Response(status_code=204)
@@ -3,21 +3,40 @@
 from __future__ import annotations
 
 from datetime import datetime, timezone
+from pathlib import Path
 
 from fastapi import APIRouter, Depends, HTTPException, status
 
-from ..deps import get_ws_manager
-from ..models import (
+from ..core.config import Settings
+from ..core.errors import StaleWriteError
+from ..core.models import (
     CreateIssueRequest,
+    ExportIssueMarkdownRequest,
+    ExportIssuesFileRequest,
+    FindSimilarIssuesRequest,
+    ImportIssuesFileRequest,
     Issue,
+    IssueExportResult,
     IssueFilters,
+    IssueMarkdownExportResult,
     ListResponse,
-    SimpleUser,
+    LockIssueRequest,
     UpdateIssueRequest,
 )
-from ..storage import ProjectStorage
+from ..core.project_repo import issue_branch_name
+from ..core.similar import SimilarIssue, find_similar_issues
+from ..core.storage import ProjectStorage
+from ..deps import get_settings, get_ws_manager
+from ..github_issues import ImportResult
+from ..issue_export import copy_attachments, render_issue_markdown, render_issues
+from ..issue_import import import_issues
+from ..openers import reveal_in_file_manager
 from ..ws import WebSocketManager
-from .projects import get_project_storage
+from .assignees import author_association, current_user, resolve_assignees
+from .config import _read_config, _update_recent_issues
+from .openers import OpenResponse
+from .project_repo import _project_repo
+from .projects import get_project_storage, load_project_config
 
 router = APIRouter(prefix="/projects/{name}/issues", tags=["issues"])
 
@@ -37,17 +56,6 @@ def _resolve_labels(storage: ProjectStorage, label_names: list[str]) -> list:
     return [by_name[n] for n in label_names if n in by_name]
 
 
-def _make_user_stubs(logins: list[str]) -> list[SimpleUser]:
-    """Create minimal SimpleUser objects for a list of login names."""
-    return [
-        SimpleUser(login=login, id=0, avatar_url="", user_type="User")
-        for login in logins
-    ]
-
-
-LOCAL_USER = SimpleUser(login="local-user", id=1, avatar_url="", user_type="User")
-
-
 # ---------------------------------------------------------------------------
 # Endpoints
 # ---------------------------------------------------------------------------
@@ -66,18 +74,42 @@ def list_issues(
 async def create_issue(
     name: str,
     body: CreateIssueRequest,
+    settings: Settings = Depends(get_settings),
     storage: ProjectStorage = Depends(get_project_storage),
     ws: WebSocketManager = Depends(get_ws_manager),
 ) -> Issue:
-    """Create a new issue in the project."""
+    """Create a new issue in the project.
+
+    Fields listed in the project's ``required_fields`` must be non-empty.
+    """
+    config = load_project_config(name, settings)
+    assignees = await resolve_assignees(config, settings, body.assignees)
+    author = await current_user(config, settings)
+    association = await author_association(config, settings)
     storage.sync()
 
-    issue_id = storage.next_issue_id()
+    project_settings = storage.read_settings()
+    if body.labels is None:
+        label_names = project_settings.default_labels or (
+            _read_config(settings.data_dir).settings.default_labels
+        )
+    else:
+        label_names = body.labels
+    labels = _resolve_labels(storage, label_names)
+    provided = {"body": body.body, "labels": labels, "assignees": assignees}
+    missing = [f for f in project_settings.required_fields if not provided[f]]
+    if missing:
+        raise HTTPException(
+            status_code=422,
+            detail=f"This project requires: {', '.join(missing)}",
+        )
+
+    if project_settings.sequential_ids:
+        issue_id = storage.reserve_issue_id()
+    else:
+        issue_id = storage.next_issue_id()
     now = datetime.now(timezone.utc)
 
-    labels = _resolve_labels(storage, body.labels)
-    assignees = _make_user_stubs(body.assignees)
-
     issue = Issue(
         id=issue_id,
         number=issue_id,
@@ -85,13 +117,18 @@ async def create_issue(
         body=body.body,
         labels=labels,
         assignees=assignees,
-        user=LOCAL_USER,
+        user=author,
         created_at=now,
         updated_at=now,
+        author_association=association,
     )
 
     storage.write_issue(issue)
-    storage.commit(f"Create issue #{issue.number}: {issue.title}")
+    storage.commit(
+        f"Create issue #{issue.number}: {issue.title}",
+        event="issue.create",
+        issue=issue.number,
+    )
     storage.push()
 
     await ws.broadcast(
@@ -103,13 +140,16 @@ async def create_issue(
 
 @router.get("/{number}", response_model=Issue)
 def get_issue(
+    name: str,
     number: int,
+    settings: Settings = Depends(get_settings),
     storage: ProjectStorage = Depends(get_project_storage),
 ) -> Issue:
-    """Return a single issue by number."""
+    """Return a single issue by number and record it as recently viewed."""
     issue = storage.read_issue(number)
     if issue is None:
         raise HTTPException(status_code=404, detail=f"Issue #{number} not found")
+    _update_recent_issues(settings.data_dir, name, number)
     return issue
 
 
@@ -118,15 +158,24 @@ async def update_issue(
     name: str,
     number: int,
     body: UpdateIssueRequest,
+    settings: Settings = Depends(get_settings),
     storage: ProjectStorage = Depends(get_project_storage),
     ws: WebSocketManager = Depends(get_ws_manager),
 ) -> Issue:
     """Update an existing issue (partial update)."""
+    config = load_project_config(name, settings)
+    assignees = None
+    if body.assignees is not None:
+        assignees = await resolve_assignees(config, settings, body.assignees)
+    closer = await current_user(config, settings) if body.state == "closed" else None
     storage.sync()
 
     issue = storage.read_issue(number)
     if issue is None:
         raise HTTPException(status_code=404, detail=f"Issue #{number} not found")
+    if body.expected_version is not None and body.expected_version != issue.version:
+        raise StaleWriteError(number, body.expected_version, issue.version)
+    storage.check_unlocked(issue)
 
     now = datetime.now(timezone.utc)
 
@@ -135,32 +184,96 @@ async def update_issue(
     if body.body is not None:
         issue.body = body.body
 
-    # State transitions
-    if body.state is not None and body.state != issue.state:
-        issue.state = body.state
-        if body.state == "closed":
+    # State transitions, with GitHub's rules for state_reason
+    state = body.state or issue.state
+    reason = body.state_reason
+    if reason is not None and (reason == "reopened") != (state == "open"):
+        raise HTTPException(
+            status_code=422,
+            detail=f"state_reason '{reason}' does not apply to a {state} issue",
+        )
+    if state != issue.state:
+        issue.state = state
+        if state == "closed":
             issue.closed_at = now
-            issue.closed_by = SimpleUser(
-                login="local-user", id=1, avatar_url="", user_type="User"
-            )
-            issue.state_reason = body.state_reason or "completed"
-        elif body.state == "open":
+            issue.closed_by = closer
+            issue.state_reason = reason or "completed"
+        elif state == "open":
             issue.closed_at = None
             issue.closed_by = None
-            issue.state_reason = None
+            issue.state_reason = "reopened"
+    elif reason is not None:
+        issue.state_reason = reason
 
     # Label resolution
     if body.labels is not None:
         issue.labels = _resolve_labels(storage, body.labels)
 
     # Assignee resolution
-    if body.assignees is not None:
-        issue.assignees = _make_user_stubs(body.assignees)
+    if assignees is not None:
+        issue.assignees = assignees
 
     issue.updated_at = now
 
     storage.write_issue(issue)
-    storage.commit(f"Update issue #{number}")
+    storage.commit(f"Update issue #{number}", event="issue.update", issue=number)
+    storage.push()
+
+    await ws.broadcast(
+        "issue:updated",
+        {"project": name, "issue": issue.model_dump(mode="json")},
+    )
+    return issue
+
+
+@router.put("/{number}/lock", response_model=Issue)
+async def lock_issue(
+    name: str,
+    number: int,
+    body: LockIssueRequest,
+    storage: ProjectStorage = Depends(get_project_storage),
+    ws: WebSocketManager = Depends(get_ws_manager),
+) -> Issue:
+    """Lock an issue so only its author and the store's owner can edit or comment."""
+    return await _set_locked(name, number, True, body.lock_reason, storage, ws)
+
+
+@router.delete("/{number}/lock", response_model=Issue)
+async def unlock_issue(
+    name: str,
+    number: int,
+    storage: ProjectStorage = Depends(get_project_storage),
+    ws: WebSocketManager = Depends(get_ws_manager),
+) -> Issue:
+    """Unlock an issue. Refused for anyone who could not edit it while locked."""
+    return await _set_locked(name, number, False, None, storage, ws)
+
+
+async def _set_locked(
+    name: str,
+    number: int,
+    locked: bool,
+    reason: str | None,
+    storage: ProjectStorage,
+    ws: WebSocketManager,
+) -> Issue:
+    storage.sync()
+    issue = storage.read_issue(number)
+    if issue is None:
+        raise HTTPException(status_code=404, detail=f"Issue #{number} not found")
+    storage.check_unlocked(issue)
+    if issue.locked == locked and issue.active_lock_reason == reason:
+        return issue
+
+    issue.locked = locked
+    issue.active_lock_reason = reason
+    issue.updated_at = datetime.now(timezone.utc)
+    storage.write_issue(issue)
+    storage.commit(
+        f"{'Lock' if locked else 'Unlock'} issue #{number}",
+        event="issue.lock" if locked else "issue.unlock",
+        issue=number,
+    )
     storage.push()
 
     await ws.broadcast(
@@ -168,3 +281,172 @@ async def update_issue(
         {"project": name, "issue": issue.model_dump(mode="json")},
     )
     return issue
+
+
+@router.post("/{number}/reveal")
+def reveal_issue_files(
+    number: int, storage: ProjectStorage = Depends(get_project_storage)
+) -> OpenResponse:
+    """Show the issue's JSON file, and its comments folder, in the file manager."""
+    issue_file = storage.issues_dir / f"{number}.json"
+    if not issue_file.exists():
+        raise HTTPException(status_code=404, detail=f"Issue #{number} not found")
+    paths = [issue_file]
+    comments = storage.comments_dir / str(number)
+    if comments.is_dir():
+        paths.append(comments)
+    return OpenResponse(opened_with=reveal_in_file_manager(paths))
+
+
+@router.post("/{number}/branch", response_model=Issue)
+async def create_issue_branch(
+    name: str,
+    number: int,
+    settings: Settings = Depends(get_settings),
+    storage: ProjectStorage = Depends(get_project_storage),
+    ws: WebSocketManager = Depends(get_ws_manager),
+) -> Issue:
+    """Check out the issue's work branch in the project repo, creating it if needed.
+
+    The name comes from the project's ``branch_template`` and is recorded on
+    the issue, so later calls return to the same branch.
+    """
+    storage.sync()
+    issue = storage.read_issue(number)
+    if issue is None:
+        raise HTTPException(status_code=404, detail=f"Issue #{number} not found")
+    branch = issue.branch or issue_branch_name(
+        storage.read_settings().branch_template, number, issue.title
+    )
+    repo, _ = _project_repo(name, settings)
+    repo.checkout_branch(branch)
+    if issue.branch == branch:
+        return issue
+
+    issue.branch = branch
+    issue.updated_at = datetime.now(timezone.utc)
+    storage.write_issue(issue)
+    storage.commit(
+        f"Link branch {branch} to issue #{number}", event="issue.branch", issue=number
+    )
+    storage.push()
+
+    await ws.broadcast(
+        "issue:updated",
+        {"project": name, "issue": issue.model_dump(mode="json")},
+    )
+    return issue
+
+
+@router.post("/similar")
+def find_similar(
+    body: FindSimilarIssuesRequest,
+    storage: ProjectStorage = Depends(get_project_storage),
+) -> list[SimilarIssue]:
+    """Existing issues that look like the one being written, to warn before filing."""
+    issues = storage.all_issues(IssueFilters(state="all"))
+    return find_similar_issues(issues, body.title, body.body, body.limit)
+
+
+@router.post("/export")
+def export_issues(
+    name: str,
+    req: ExportIssuesFileRequest,
+    storage: ProjectStorage = Depends(get_project_storage),
+) -> IssueExportResult:
+    """Write the matching issues, oldest first, to a Markdown, CSV, or JSON file."""
+    filters = IssueFilters(
+        state=req.state,
+        labels=req.labels,
+        assignee=req.assignee,
+        mentioned=req.mentioned,
+        direction="asc",
+    )
+    issues = storage.all_issues(filters)
+    comments = (
+        {issue.number: storage.all_comments(issue.number) for issue in issues}
+        if req.include_comments
+        else None
+    )
+    target = Path(req.destination).expanduser()
+    try:
+        target.parent.mkdir(parents=True, exist_ok=True)
+        target.write_text(
+            render_issues(name, issues, req.format, comments),
+            encoding="utf-8",
+            newline="\n",
+        )
+    except OSError as exc:
+        raise HTTPException(
+            status_code=400, detail=f"Cannot write {target}: {exc.strerror}"
+        ) from exc
+    return IssueExportResult(path=str(target), issues=len(issues))
+
+
+@router.post("/{number}/export")
+def export_issue_markdown(
+    name: str,
+    number: int,
+    req: ExportIssueMarkdownRequest,
+    settings: Settings = Depends(get_settings),
+    storage: ProjectStorage = Depends(get_project_storage),
+) -> IssueMarkdownExportResult:
+    """Write the issue and its thread to one Markdown file for people without the app.
+
+    Local files linked from the issue or its comments are copied into a
+    ``<name>_files`` folder beside it.
+    """
+    issue = storage.read_issue(number)
+    if issue is None:
+        raise HTTPException(status_code=404, detail=f"Issue #{number} not found")
+    markdown = render_issue_markdown(name, issue, storage.all_comments(number))
+    target = Path(req.destination).expanduser()
+    bases = [storage.path]
+    project_path = load_project_config(name, settings).project_path
+    if project_path:
+        bases.insert(0, Path(project_path))
+    try:
+        markdown, attachments = copy_attachments(
+            markdown, bases, target.with_name(f"{target.stem}_files")
+        )
+        target.parent.mkdir(parents=True, exist_ok=True)
+        target.write_text(markdown, encoding="utf-8", newline="\n")
+    except OSError as exc:
+        raise HTTPException(
+            status_code=400, detail=f"Cannot write {target}: {exc.strerror}"
+        ) from exc
+    return IssueMarkdownExportResult(path=str(target), attachments=attachments)
+
+
+@router.post("/import")
+async def import_issues_from_file(
+    name: str,
+    req: ImportIssuesFileRequest,
+    storage: ProjectStorage = Depends(get_project_storage),
+    ws: WebSocketManager = Depends(get_ws_manager),
+) -> ImportResult:
+    """Create issues, labels, milestones and comments from a CSV or Jira export."""
+    source = Path(req.source).expanduser()
+    try:
+        content = source.read_text(encoding="utf-8-sig")
+    except (OSError, UnicodeDecodeError) as exc:
+        raise HTTPException(
+            status_code=400, detail=f"Cannot read {source}: {exc}"
+        ) from exc
+
+    storage.sync()
+    try:
+        result = import_issues(storage, content, req.format, req.mapping)
+    except ValueError as exc:
+        raise HTTPException(status_code=422, detail=str(exc)) from exc
+
+    storage.commit(
+        f"Import {result.issues} issues from {source.name}", event="file.import"
+    )
+    storage.push()
+
+    await ws.broadcast(
+        "issues:imported",
+        {"project": name, "result": result.model_dump(mode="json")},
+    )
+    return result
@@ -2,12 +2,12 @@
 
 from fastapi import APIRouter, Body, Depends, HTTPException, Response
 
-from ..models import (
+from ..core.models import (
     CreateLabelRequest,
     Label,
     UpdateLabelRequest,
 )
-from ..storage import ProjectStorage
+from ..core.storage import ProjectStorage
 from .projects import get_project_storage
 
 router = APIRouter(tags=["labels"])
@@ -55,7 +55,7 @@ async def create_label(
 
     labels.append(label)
     storage.write_labels(labels)
-    storage.commit(f"Create label '{label.name}'")
+    storage.commit(f"Create label '{label.name}'", event="label.create")
     storage.push()
 
     return label
@@ -119,7 +119,7 @@ async def update_label(
                     storage.write_issue(issue)
 
     storage.write_labels(labels)
-    storage.commit(f"Update label '{old_name}'")
+    storage.commit(f"Update label '{old_name}'", event="label.update")
     storage.push()
 
     return target
@@ -153,7 +153,7 @@ async def delete_label(
                 storage.write_issue(issue)
 
     storage.write_labels(labels)
-    storage.commit(f"Delete label '{label_name}'")
+    storage.commit(f"Delete label '{label_name}'", event="label.delete")
     storage.push()
 
     return Response(status_code=204)
@@ -199,7 +199,7 @@ async def add_labels_to_issue(
             existing_names.add(label_name)
 
     storage.write_issue(issue)
-    storage.commit(f"Add labels to issue #{number}")
+    storage.commit(f"Add labels to issue #{number}", event="issue.labels", issue=number)
     storage.push()
 
     return issue.labels
@@ -226,7 +226,9 @@ async def replace_issue_labels(
     ]
 
     storage.write_issue(issue)
-    storage.commit(f"Replace labels on issue #{number}")
+    storage.commit(
+        f"Replace labels on issue #{number}", event="issue.labels", issue=number
+    )
     storage.push()
 
     return issue.labels
@@ -246,7 +248,9 @@ async def remove_all_issue_labels(
 
     issue.labels = []
     storage.write_issue(issue)
-    storage.commit(f"Remove all labels from issue #{number}")
+    storage.commit(
+        f"Remove all labels from issue #{number}", event="issue.labels", issue=number
+    )
     storage.push()
 
     return Response(status_code=204)
@@ -275,7 +279,11 @@ async def remove_label_from_issue(
         )
 
     storage.write_issue(issue)
-    storage.commit(f"Remove label '{label}' from issue #{number}")
+    storage.commit(
+        f"Remove label '{label}' from issue #{number}",
+        event="issue.labels",
+        issue=number,
+    )
     storage.push()
 
     return issue.labels
@@ -0,0 +1,23 @@
+"""Recent log entries, for attaching to bug reports."""
+
+from fastapi import APIRouter, Depends, HTTPException, Query
+
+from ..core.config import Settings
+from ..core.logs import read_recent_logs
+from ..core.models import LogEntry
+from ..deps import get_settings
+
+router = APIRouter(prefix="/logs", tags=["logs"])
+
+
+@router.get("")
+def get_recent_logs(
+    level: str = "INFO",
+    limit: int = Query(default=200, ge=1, le=5000),
+    settings: Settings = Depends(get_settings),
+) -> list[LogEntry]:
+    """Entries at *level* or above, newest first."""
+    try:
+        return read_recent_logs(settings.data_dir, level, limit)
+    except ValueError as exc:
+        raise HTTPException(status_code=422, detail=str(exc)) from exc
@@ -0,0 +1,73 @@
+"""Milestone endpoints for the issues server API."""
+
+from datetime import datetime, timedelta, timezone
+
+from fastapi import APIRouter, Depends, HTTPException, Query
+
+from ..core.models import AssignMilestoneRequest, Issue, MilestoneListItem
+from ..core.storage import ProjectStorage
+from ..deps import get_ws_manager
+from ..ws import WebSocketManager
+from .projects import get_project_storage
+
+router = APIRouter(tags=["milestones"])
+
+
+@router.get("/projects/{name}/milestones")
+async def list_milestones(
+    due_within_days: int | None = Query(default=None, ge=0),
+    overdue: bool | None = None,
+    storage: ProjectStorage = Depends(get_project_storage),
+) -> list[MilestoneListItem]:
+    """List milestones, optionally only open ones due within some days, or overdue."""
+    return storage.list_milestones(
+        None if due_within_days is None else timedelta(days=due_within_days), overdue
+    )
+
+
+@router.post("/projects/{name}/milestones/{number}/issues")
+async def assign_milestone(
+    name: str,
+    number: int,
+    body: AssignMilestoneRequest,
+    storage: ProjectStorage = Depends(get_project_storage),
+    ws: WebSocketManager = Depends(get_ws_manager),
+) -> list[Issue]:
+    """Put issues in a milestone, in one commit. Returns the issues that moved."""
+    storage.sync()
+    milestone = next((m for m in storage.read_milestones() if m.number == number), None)
+    if milestone is None:
+        raise HTTPException(status_code=404, detail=f"Milestone #{number} not found")
+
+    # Read them all first so a missing or locked issue changes nothing.
+    issues = []
+    for issue_number in dict.fromkeys(body.issues):
+        issue = storage.read_issue(issue_number)
+        if issue is None:
+            raise HTTPException(
+                status_code=404, detail=f"Issue #{issue_number} not found"
+            )
+        storage.check_unlocked(issue)
+        if issue.milestone is None or issue.milestone.number != number:
+            issues.append(issue)
+    if not issues:
+        return []
+
+    now = datetime.now(timezone.utc)
+    for issue in issues:
+        issue.milestone = milestone
+        issue.updated_at = now
+        storage.write_issue(issue)
+    storage.commit(
+        f"Add {len(issues)} issue{'s' if len(issues) > 1 else ''} "
+        f"to milestone {milestone.title}",
+        event="milestone.assign",
+    )
+    storage.push()
+
+    for issue in issues:
+        await ws.broadcast(
+            "issue:updated",
+            {"project": name, "issue": issue.model_dump(mode="json")},
+        )
+    return issues
@@ -0,0 +1,54 @@
+"""Network operation control routes."""
+
+from datetime import datetime
+
+from fastapi import APIRouter, HTTPException
+from pydantic import BaseModel
+
+from ..core.network import (
+    cancel_network_operations,
+    cancel_operation,
+    list_network_operations,
+)
+
+router = APIRouter(prefix="/network", tags=["network"])
+
+
+class CancelResponse(BaseModel):
+    cancelled: int
+
+
+class OperationInfo(BaseModel):
+    id: str
+    kind: str
+    project: str | None
+    started_at: datetime
+    cancelled: bool
+
+
+@router.post("/cancel")
+def cancel_network_operation() -> CancelResponse:
+    """Abort in-flight clones, pulls, and pushes."""
+    return CancelResponse(cancelled=cancel_network_operations())
+
+
+@router.get("/operations")
+def list_operations() -> list[OperationInfo]:
+    """List in-flight cancellable operations, such as project clones."""
+    return [
+        OperationInfo(
+            id=op.id,
+            kind=op.kind,
+            project=op.project,
+            started_at=op.started_at,
+            cancelled=op.cancelled,
+        )
+        for op in list_network_operations()
+    ]
+
+
+@router.post("/operations/{operation_id}/cancel", status_code=204)
+def cancel_network_operation_by_id(operation_id: str) -> None:
+    """Cancel one operation; its partially cloned directory is removed."""
+    if not cancel_operation(operation_id):
+        raise HTTPException(status_code=404, detail="Operation not found")
@@ -0,0 +1,101 @@
+"""Notification center endpoints."""
+
+from __future__ import annotations
+
+import asyncio
+
+from fastapi import APIRouter, Depends, HTTPException
+from pydantic import BaseModel
+
+from ..core.config import Settings
+from ..core.errors import AppError
+from ..core.models import Notification
+from ..deps import get_notifications, get_settings
+from ..notifications import NotificationCenter
+from ..ws import WebSocketManager
+from .github_auth import _read_accounts as _read_github_accounts
+from .projects import get_project_storage, list_projects
+from .providers import _read_accounts as _read_provider_accounts
+
+router = APIRouter(prefix="/notifications", tags=["notifications"])
+
+
+class NotificationList(BaseModel):
+    items: list[Notification]
+    unread_count: int
+
+
+class MarkReadRequest(BaseModel):
+    read: bool = True
+
+
+def _identities(settings: Settings) -> tuple[set[str], set[str]]:
+    """Logins and commit emails that count as the local user."""
+    github = _read_github_accounts(settings).accounts
+    logins = {a.user for a in github}
+    logins |= {a.user for a in _read_provider_accounts(settings).accounts}
+    return logins, {a.commit_email for a in github}
+
+
+def scan_projects(
+    settings: Settings, notifications: NotificationCenter
+) -> list[Notification]:
+    """Check every project for store changes and due milestones; return what is new."""
+    logins, emails = _identities(settings)
+    found: list[Notification] = []
+    for project in list_projects(settings):
+        try:
+            storage = get_project_storage(project.name, settings)
+        except AppError:
+            continue
+        found.extend(notifications.scan(project.name, storage, logins, emails))
+        found.extend(notifications.check_milestones(project.name, storage))
+    return found
+
+
+async def broadcast_new_notifications(
+    settings: Settings, notifications: NotificationCenter, ws: WebSocketManager
+) -> None:
+    """Scheduled scan; new notifications are pushed as ``notification:created``."""
+    found = await asyncio.to_thread(scan_projects, settings, notifications)
+    for notification in found:
+        await ws.broadcast(
+            "notification:created", notification.model_dump(mode="json")
+        )
+
+
+@router.get("")
+def list_notifications(
+    unread: bool = False,
+    settings: Settings = Depends(get_settings),
+    notifications: NotificationCenter = Depends(get_notifications),
+) -> NotificationList:
+    """Scan stores for new changes, then return notifications newest first."""
+    scan_projects(settings, notifications)
+    items = notifications.list()
+    return NotificationList(
+        items=[n for n in items if not (unread and n.read)],
+        unread_count=sum(1 for n in items if not n.read),
+    )
+
+
+@router.post("/{notification_id}/read")
+def mark_notification_read(
+    notification_id: str,
+    req: MarkReadRequest | None = None,
+    notifications: NotificationCenter = Depends(get_notifications),
+) -> Notification:
+    """Mark a notification read, or unread with ``{"read": false}``."""
+    notification = notifications.mark_read(
+        notification_id, req.read if req is not None else True
+    )
+    if notification is None:
+        raise HTTPException(status_code=404, detail="Notification not found")
+    return notification
+
+
+@router.post("/read-all")
+def mark_all_notifications_read(
+    notifications: NotificationCenter = Depends(get_notifications),
+) -> dict[str, int]:
+    return {"marked": notifications.mark_all_read()}
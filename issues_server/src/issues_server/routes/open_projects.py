@@ -0,0 +1,55 @@
+"""Endpoints for the set of projects the app has open at once."""
+
+from datetime import datetime
+
+from fastapi import APIRouter, Depends, HTTPException, Response
+from pydantic import BaseModel
+
+from ..amplifier import AmplifierManager
+from ..deps import get_amplifier_manager, get_open_projects
+from ..open_projects import OpenProject, OpenProjectRegistry
+
+router = APIRouter(prefix="/open-projects", tags=["projects"])
+
+
+class OpenProjectInfo(BaseModel):
+    name: str
+    store_path: str
+    opened_at: datetime
+    last_accessed: datetime
+    running_sessions: int
+
+
+def _info(project: OpenProject, amplifier_manager: AmplifierManager) -> OpenProjectInfo:
+    running = sum(
+        1 for s in amplifier_manager.list_sessions(project.name) if s.status == "running"
+    )
+    return OpenProjectInfo(
+        name=project.name,
+        store_path=project.store_path,
+        opened_at=project.opened_at,
+        last_accessed=project.last_accessed,
+        running_sessions=running,
+    )
+
+
+@router.get("")
+def list_open_projects(
+    registry: OpenProjectRegistry = Depends(get_open_projects),
+    amplifier_manager: AmplifierManager = Depends(get_amplifier_manager),
+) -> list[OpenProjectInfo]:
+    """List open projects, most recently accessed first."""
+    return [_info(p, amplifier_manager) for p in registry.list()]
+
+
+@router.delete("/{name}", status_code=204)
+def close_project(
+    name: str,
+    registry: OpenProjectRegistry = Depends(get_open_projects),
+    amplifier_manager: AmplifierManager = Depends(get_amplifier_manager),
+) -> Response:
+    """Close a project, cancelling its running Amplifier sessions."""
+    if registry.close(name) is None:
+        raise HTTPException(status_code=404, detail=f"Project '{name}' is not open")
+    amplifier_manager.cancel_project(name)
+    return Response(status_code=204)
@@ -0,0 +1,61 @@
+"""Open project and store paths in local programs."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+from fastapi import APIRouter, Depends, HTTPException
+from pydantic import BaseModel
+
+from ..core.config import Settings
+from ..deps import get_settings
+from ..openers import open_in_editor, open_in_file_manager, open_in_terminal
+from .config import _read_config
+
+router = APIRouter(prefix="/open", tags=["open"])
+
+
+class OpenEditorRequest(BaseModel):
+    path: str
+    line: int | None = None
+
+
+class OpenPathRequest(BaseModel):
+    path: str
+
+
+class OpenResponse(BaseModel):
+    opened_with: str
+
+
+def _existing_path(path: str) -> Path:
+    target = Path(path).expanduser().resolve()
+    if not target.exists():
+        raise HTTPException(status_code=404, detail=f"Path not found: {target}")
+    return target
+
+
+@router.post("/editor")
+def open_path_in_editor(
+    req: OpenEditorRequest, settings: Settings = Depends(get_settings)
+) -> OpenResponse:
+    """Open a file or directory in the configured or detected editor."""
+    command = _read_config(settings.data_dir).settings.editor_command
+    return OpenResponse(
+        opened_with=open_in_editor(_existing_path(req.path), req.line, command)
+    )
+
+
+@router.post("/terminal")
+def open_path_in_terminal(
+    req: OpenPathRequest, settings: Settings = Depends(get_settings)
+) -> OpenResponse:
+    """Open a terminal in a directory, or in a file's directory."""
+    command = _read_config(settings.data_dir).settings.terminal_command
+    return OpenResponse(opened_with=open_in_terminal(_existing_path(req.path), command))
+
+
+@router.post("/folder")
+def open_path_in_file_manager(req: OpenPathRequest) -> OpenResponse:
+    """Show a directory, or a file's directory, in the platform file manager."""
+    return OpenResponse(opened_with=open_in_file_manager(_existing_path(req.path)))
@@ -0,0 +1,67 @@
+"""Commit and push hand-edited or Amplifier-edited files in the project repo."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+from fastapi import APIRouter, Depends, HTTPException, Response
+from pydantic import BaseModel
+
+from ..core.config import Settings
+from ..core.models import ProjectGitStatus
+from ..core.project_repo import ProjectRepo
+from ..deps import get_settings
+from .github_auth import commit_author, get_github_token
+from .projects import load_project_config
+
+router = APIRouter(prefix="/projects/{name}/repo", tags=["project-repo"])
+
+
+class CommitProjectRequest(BaseModel):
+    message: str
+    paths: list[str] | None = None
+
+
+class CommitProjectResponse(BaseModel):
+    sha: str
+
+
+def _project_repo(name: str, settings: Settings) -> tuple[ProjectRepo, str | None]:
+    config = load_project_config(name, settings)
+    if config.project_path is None:
+        raise HTTPException(
+            status_code=404, detail=f"Project '{name}' has no project repository"
+        )
+    repo = ProjectRepo(
+        Path(config.project_path),
+        commit_author(settings, config.github_account, config.author),
+    )
+    return repo, config.github_account
+
+
+@router.get("/status")
+def get_project_git_status(
+    name: str, settings: Settings = Depends(get_settings)
+) -> ProjectGitStatus:
+    """Branch, ahead/behind counts, and uncommitted files of the project repo."""
+    repo, _ = _project_repo(name, settings)
+    return repo.status()
+
+
+@router.post("/commit")
+def commit_project_changes(
+    name: str, req: CommitProjectRequest, settings: Settings = Depends(get_settings)
+) -> CommitProjectResponse:
+    """Commit *paths*, or every change, in the project repository."""
+    if not req.message.strip():
+        raise HTTPException(status_code=422, detail="Commit message is required")
+    repo, _ = _project_repo(name, settings)
+    return CommitProjectResponse(sha=repo.commit(req.message.strip(), req.paths))
+
+
+@router.post("/push", status_code=204)
+def push_project(name: str, settings: Settings = Depends(get_settings)) -> Response:
+    """Push the project repository's current branch to origin."""
+    repo, account = _project_repo(name, settings)
+    repo.push(get_github_token(settings, account))
+    return Response(status_code=204)
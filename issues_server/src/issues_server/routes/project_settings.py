@@ -0,0 +1,48 @@
+"""Per-project settings kept in the store, so team policies travel with it."""
+
+from fastapi import APIRouter, Depends, HTTPException
+from pydantic import ValidationError
+
+from ..core.models import ProjectSettings, UpdateProjectSettingsRequest
+from ..core.storage import ProjectStorage
+from ..deps import get_ws_manager
+from ..ws import WebSocketManager
+from .projects import get_project_storage
+
+router = APIRouter(tags=["project-settings"])
+
+
+@router.get("/projects/{name}/settings")
+async def get_project_settings(
+    storage: ProjectStorage = Depends(get_project_storage),
+) -> ProjectSettings:
+    return storage.read_settings()
+
+
+@router.patch("/projects/{name}/settings")
+async def update_project_settings(
+    name: str,
+    body: UpdateProjectSettingsRequest,
+    storage: ProjectStorage = Depends(get_project_storage),
+    ws: WebSocketManager = Depends(get_ws_manager),
+) -> ProjectSettings:
+    """Update the given settings, then commit and push them with the store."""
+    storage.sync()
+    updated = storage.read_settings().model_dump()
+    updated.update(body.model_dump(exclude_unset=True))
+    try:
+        settings = ProjectSettings.model_validate(updated)
+    except ValidationError as exc:
+        raise HTTPException(status_code=422, detail=str(exc)) from exc
+    settings.default_labels = list(dict.fromkeys(settings.default_labels))
+    settings.required_fields = list(dict.fromkeys(settings.required_fields))
+
+    storage.write_settings(settings)
+    storage.commit("Update project settings", event="settings.update")
+    storage.push()
+
+    await ws.broadcast(
+        "project:settings_updated",
+        {"project": name, "settings": settings.model_dump(mode="json")},
+    )
+    return settings
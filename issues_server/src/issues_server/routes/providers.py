@@ -0,0 +1,369 @@
+"""Stores hosted on git providers other than GitHub (GitLab, Gitea, ...).
+
+GitHub keeps its dedicated account and store routes; these endpoints work
+for any provider in :data:`issues_server.providers.PROVIDERS`.
+"""
+
+import json
+import uuid
+from datetime import datetime, timezone
+from pathlib import Path
+
+import httpx
+from fastapi import APIRouter, Depends, HTTPException
+from pydantic import BaseModel
+
+from issues_server.core.config import Settings
+from issues_server.core.errors import RepoCreateForbiddenError
+from issues_server.core.models import (
+    GitHubStoreConfig,
+    ProjectConfig,
+    ProviderAccount,
+    ProviderAccounts,
+    RemoteStoreConfig,
+    StoreConfig,
+)
+from issues_server.core.network import network_operation
+from issues_server.core.storage import ProjectStorage, write_text_atomic
+from issues_server.deps import get_settings, get_ws_manager
+from issues_server.keyring_store import delete_secret, get_secret, set_secret
+from issues_server.providers import PROVIDERS, GitProvider, get_provider
+from issues_server.routes.github_auth import get_github_account
+from issues_server.routes.projects import (
+    clone_repository,
+    progress_broadcaster,
+    load_project_config,
+    save_project_config,
+)
+from issues_server.routes.store import (
+    StoreStatusResponse,
+    adopt_cloned_store,
+    store_status,
+)
+from issues_server.ws import WebSocketManager
+
+router = APIRouter(tags=["providers"])
+
+
+class ProviderInfo(BaseModel):
+    kind: str
+    default_base_url: str
+
+
+class ProviderAccountInfo(BaseModel):
+    provider: str
+    base_url: str
+    user: str
+    validated_at: str
+
+
+class AddProviderAccountRequest(BaseModel):
+    provider: str
+    base_url: str | None = None
+    token: str
+
+
+class ConnectRemoteRequest(BaseModel):
+    provider: str
+    base_url: str | None = None
+    owner: str
+    repo: str
+    user: str | None = None
+    branch: str | None = None
+    operation_id: str | None = None
+
+
+class CreateRemoteStoreRequest(BaseModel):
+    provider: str
+    base_url: str | None = None
+    repo_name: str
+    private: bool = True
+    description: str = ""
+    user: str | None = None
+
+
+# ---------------------------------------------------------------------------
+# Provider accounts
+# ---------------------------------------------------------------------------
+
+
+def _accounts_path(settings: Settings) -> Path:
+    return settings.data_dir / "provider-accounts.json"
+
+
+def _keyring_key(account: ProviderAccount) -> str:
+    return f"{account.provider}:{account.base_url}:{account.user}"
+
+
+def _read_accounts(settings: Settings) -> ProviderAccounts:
+    path = _accounts_path(settings)
+    if not path.exists():
+        return ProviderAccounts()
+    accounts = ProviderAccounts.model_validate_json(path.read_text())
+    for account in accounts.accounts:
+        if account.token_ref is not None:
+            account.token = get_secret(account.token_ref)
+    return accounts
+
+
+def _write_accounts(settings: Settings, accounts: ProviderAccounts) -> None:
+    persisted = accounts.model_copy(deep=True)
+    for account in persisted.accounts:
+        if account.token is None or not settings.use_keyring:
+            continue
+        key = _keyring_key(account)
+        if set_secret(key, account.token):
+            account.token = None
+            account.token_ref = key
+    path = _accounts_path(settings)
+    write_text_atomic(
+        path, json.dumps(persisted.model_dump(mode="json"), indent=2) + "\n"
+    )
+
+
+def _provider_class(kind: str) -> type[GitProvider]:
+    if kind not in PROVIDERS:
+        raise HTTPException(status_code=400, detail=f"Unknown provider '{kind}'")
+    return PROVIDERS[kind]
+
+
+def _account_info(account: ProviderAccount) -> ProviderAccountInfo:
+    return ProviderAccountInfo(
+        provider=account.provider,
+        base_url=account.base_url,
+        user=account.user,
+        validated_at=account.validated_at,
+    )
+
+
+def resolve_provider(
+    settings: Settings,
+    kind: str,
+    base_url: str | None = None,
+    user: str | None = None,
+) -> tuple[GitProvider, str]:
+    """Return a provider authenticated as *user* and the login it acts as.
+
+    GitHub uses the GitHub accounts; other hosts use the first matching
+    provider account when *user* is omitted.
+    """
+    base = (base_url or _provider_class(kind).default_base_url).rstrip("/")
+    if kind == "github":
+        account = get_github_account(settings, user)
+        if account is None or account.token is None:
+            raise HTTPException(status_code=401, detail="GitHub token not configured")
+        return get_provider(kind, account.token, base), account.user
+
+    matches = [
+        a
+        for a in _read_accounts(settings).accounts
+        if a.provider == kind and a.base_url == base and user in (None, a.user)
+    ]
+    if not matches or matches[0].token is None:
+        raise HTTPException(
+            status_code=401,
+            detail=f"No {kind} account configured for {base}",
+        )
+    return get_provider(kind, matches[0].token, base), matches[0].user
+
+
+@router.get("/providers")
+def list_providers() -> list[ProviderInfo]:
+    """List the git hosts a store can live on."""
+    return [
+        ProviderInfo(kind=kind, default_base_url=cls.default_base_url)
+        for kind, cls in PROVIDERS.items()
+    ]
+
+
+@router.get("/providers/accounts")
+def list_provider_accounts(
+    settings: Settings = Depends(get_settings),
+) -> list[ProviderAccountInfo]:
+    """List stored accounts for non-GitHub providers (without tokens)."""
+    return [_account_info(a) for a in _read_accounts(settings).accounts]
+
+
+@router.post("/providers/accounts")
+async def add_provider_account(
+    req: AddProviderAccountRequest,
+    settings: Settings = Depends(get_settings),
+) -> ProviderAccountInfo:
+    """Validate a token against the provider and store it as an account."""
+    if req.provider == "github":
+        raise HTTPException(
+            status_code=400, detail="Add GitHub accounts via /github/accounts"
+        )
+    try:
+        provider = _provider_class(req.provider)(req.token, req.base_url)
+    except ValueError as exc:
+        raise HTTPException(status_code=400, detail=str(exc)) from exc
+    try:
+        user = await provider.get_authenticated_user()
+    except httpx.HTTPError as exc:
+        raise HTTPException(status_code=401, detail=f"Invalid token: {exc}") from exc
+
+    accounts = _read_accounts(settings)
+    existing = accounts.find(req.provider, provider.base_url, user)
+    if existing is not None:
+        accounts.accounts.remove(existing)
+    account = ProviderAccount(
+        provider=req.provider,
+        base_url=provider.base_url,
+        user=user,
+        token=req.token,
+        validated_at=datetime.now(timezone.utc).isoformat(),
+    )
+    accounts.accounts.append(account)
+    _write_accounts(settings, accounts)
+    return _account_info(account)
+
+
+@router.delete("/providers/accounts", status_code=204)
+def remove_provider_account(
+    provider: str,
+    base_url: str,
+    user: str,
+    settings: Settings = Depends(get_settings),
+) -> None:
+    """Forget a provider account and its stored token."""
+    accounts = _read_accounts(settings)
+    account = accounts.find(provider, base_url.rstrip("/"), user)
+    if account is None:
+        raise HTTPException(status_code=404, detail="Account not found")
+    if account.token_ref is not None:
+        delete_secret(account.token_ref)
+    accounts.accounts.remove(account)
+    _write_accounts(settings, accounts)
+
+
+# ---------------------------------------------------------------------------
+# Remote stores
+# ---------------------------------------------------------------------------
+
+
+def _set_remote_store(
+    config: ProjectConfig,
+    path: str,
+    provider: GitProvider,
+    user: str,
+    owner: str,
+    repo: str,
+    web_url: str,
+    branch: str | None = None,
+) -> None:
+    github = None
+    if provider.kind == "github":
+        github = GitHubStoreConfig(
+            owner=owner,
+            repo=repo,
+            remote_url=f"https://github.com/{owner}/{repo}.git",
+        )
+    config.store = StoreConfig(
+        path=path,
+        github=github,
+        branch=branch,
+        remote=RemoteStoreConfig(
+            provider=provider.kind,
+            base_url=provider.base_url,
+            owner=owner,
+            repo=repo,
+            web_url=web_url,
+            user=user,
+        ),
+    )
+
+
+@router.post("/projects/{name}/store/remote/connect")
+async def connect_remote_store(
+    name: str,
+    req: ConnectRemoteRequest,
+    settings: Settings = Depends(get_settings),
+    ws: WebSocketManager = Depends(get_ws_manager),
+) -> StoreStatusResponse:
+    """Connect a project's store to an existing repo on any provider."""
+    config = load_project_config(name, settings)
+    provider, user = resolve_provider(settings, req.provider, req.base_url, req.user)
+
+    if not await provider.repo_exists(req.owner, req.repo):
+        raise HTTPException(
+            status_code=404,
+            detail=f"Repository {req.owner}/{req.repo} not found or not accessible.",
+        )
+
+    new_store_dir = settings.data_dir / "stores" / f"{name}-{uuid.uuid4().hex[:8]}"
+    with network_operation("connect_store", name, req.operation_id):
+        await clone_repository(
+            provider.clone_url(req.owner, req.repo),
+            new_store_dir,
+            on_progress=progress_broadcaster(ws, name),
+        )
+
+        storage = ProjectStorage(new_store_dir, branch=req.branch)
+        storage.set_signing(config.signing)
+        storage.use_branch()
+        config.store_id = adopt_cloned_store(new_store_dir, storage)
+    _set_remote_store(
+        config,
+        str(new_store_dir.resolve()),
+        provider,
+        user,
+        req.owner,
+        req.repo,
+        provider.web_url(req.owner, req.repo),
+        req.branch,
+    )
+    save_project_config(config, settings)
+    return store_status(config)
+
+
+@router.post("/projects/{name}/store/remote/create")
+async def create_remote_store(
+    name: str,
+    req: CreateRemoteStoreRequest,
+    settings: Settings = Depends(get_settings),
+) -> StoreStatusResponse:
+    """Create a repo on any provider and push the existing local store to it."""
+    config = load_project_config(name, settings)
+    provider, user = resolve_provider(settings, req.provider, req.base_url, req.user)
+    store_path = Path(config.store.path)
+    if not store_path.exists():
+        raise HTTPException(
+            status_code=500,
+            detail=f"Store directory not found at {store_path}",
+        )
+
+    try:
+        created = await provider.create_repo(req.repo_name, req.private, req.description)
+    except PermissionError:
+        raise RepoCreateForbiddenError("Create the repo manually, then connect to it.")
+    except (httpx.HTTPError, RuntimeError) as exc:
+        raise HTTPException(
+            status_code=500,
+            detail=f"Failed to create repository: {exc}",
+        ) from exc
+
+    storage = ProjectStorage(store_path, branch=config.store.branch)
+    storage.set_remote(provider.clone_url(created.owner, created.name))
+    storage.set_ssh_command(None)
+    try:
+        storage.use_branch()
+        storage.push()
+    except Exception as exc:
+        raise HTTPException(
+            status_code=500,
+            detail=f"Failed to push to new remote: {exc}",
+        ) from exc
+
+    _set_remote_store(
+        config,
+        config.store.path,
+        provider,
+        user,
+        created.owner,
+        created.name,
+        created.web_url,
+        config.store.branch,
+    )
+    save_project_config(config, settings)
+    return store_status(config)
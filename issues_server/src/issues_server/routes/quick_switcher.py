@@ -0,0 +1,102 @@
+"""Data for the Cmd+K quick switcher, gathered in a single request."""
+
+from __future__ import annotations
+
+from collections import Counter
+
+from fastapi import APIRouter, Depends
+from pydantic import BaseModel
+
+from ..core.config import Settings
+from ..core.errors import AppError
+from ..core.models import IssueFilters
+from ..core.storage import ProjectStorage
+from ..deps import get_settings
+from .config import _read_config
+from .projects import get_project_storage
+
+router = APIRouter(prefix="/quick-switcher", tags=["quick-switcher"])
+
+
+class QuickSwitcherIssue(BaseModel):
+    project: str
+    number: int
+    title: str
+    state: str
+
+
+class QuickSwitcherLabel(BaseModel):
+    name: str
+    color: str
+    count: int
+
+
+class QuickSwitcherItems(BaseModel):
+    issues: list[QuickSwitcherIssue]
+    projects: list[str]
+    labels: list[QuickSwitcherLabel]
+    label_project: str | None = None
+
+
+@router.get("")
+def get_quick_switcher_items(
+    project: str | None = None,
+    limit: int = 10,
+    settings: Settings = Depends(get_settings),
+) -> QuickSwitcherItems:
+    """Recently viewed issues, recent projects, and the most used labels.
+
+    Labels come from *project*, or the most recent project when omitted.
+    Projects or issues that no longer exist are skipped.
+    """
+    config = _read_config(settings.data_dir)
+    storages: dict[str, ProjectStorage | None] = {}
+
+    def storage_for(name: str) -> ProjectStorage | None:
+        if name not in storages:
+            try:
+                storages[name] = get_project_storage(name, settings)
+            except AppError:
+                storages[name] = None
+        return storages[name]
+
+    projects = [p for p in config.recent_projects if storage_for(p) is not None]
+
+    issues: list[QuickSwitcherIssue] = []
+    for recent in config.recent_issues:
+        if len(issues) >= limit:
+            break
+        storage = storage_for(recent.project)
+        issue = storage.read_issue(recent.number) if storage else None
+        if issue is not None:
+            issues.append(
+                QuickSwitcherIssue(
+                    project=recent.project,
+                    number=issue.number,
+                    title=issue.title,
+                    state=issue.state,
+                )
+            )
+
+    label_project = project or (projects[0] if projects else None)
+    labels: list[QuickSwitcherLabel] = []
+    storage = storage_for(label_project) if label_project else None
+    if storage is not None:
+        counts = Counter(
+            label.name
+            for issue in storage.all_issues(IssueFilters(state="all"))
+            for label in issue.labels
+        )
+        colors = {label.name: label.color for label in storage.read_labels()}
+        labels = [
+            QuickSwitcherLabel(name=name, color=colors[name], count=count)
+            for name, count in counts.most_common()
+            if name in colors
+        ][:limit]
+
+    return QuickSwitcherItems(
+        issues=issues,
+        projects=projects[:limit],
+        labels=labels,
+        label_project=label_project if storage is not None else None,
+    )
@@ -0,0 +1,50 @@
+"""Status and quick actions for an always-visible tray or status menu.
+
+Sessions and syncs run in the server, so they keep going while no window
+is open; these endpoints let a small status surface act on all projects.
+"""
+
+from __future__ import annotations
+
+from fastapi import APIRouter, Depends
+from pydantic import BaseModel
+
+from ..amplifier import AmplifierManager
+from ..core.config import Settings
+from ..deps import get_amplifier_manager, get_settings, get_ws_manager
+from ..ws import WebSocketManager
+from .config import _read_config
+from .projects import list_projects
+from .store import SyncAllResponse, sync_all_stores
+
+router = APIRouter(prefix="/tray", tags=["tray"])
+
+
+class TrayStatus(BaseModel):
+    running_sessions: int
+    last_project: str | None = None
+
+
+@router.get("")
+def get_tray_status(
+    settings: Settings = Depends(get_settings),
+    amplifier_manager: AmplifierManager = Depends(get_amplifier_manager),
+) -> TrayStatus:
+    """Running Amplifier sessions and the project to reopen."""
+    existing = {p.name for p in list_projects(settings)}
+    recent = _read_config(settings.data_dir).recent_projects
+    return TrayStatus(
+        running_sessions=sum(
+            1 for s in amplifier_manager.list_sessions() if s.status == "running"
+        ),
+        last_project=next((p for p in recent if p in existing), None),
+    )
+
+
+@router.post("/sync")
+async def sync_all_stores(
+    settings: Settings = Depends(get_settings),
+    ws: WebSocketManager = Depends(get_ws_manager),
+) -> SyncAllResponse:
+    """Pull and push every store that has a remote."""
+    return await sync_all_stores(settings, ws)
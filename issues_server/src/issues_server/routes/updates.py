@@ -0,0 +1,31 @@
+"""Check for and install newer builds of the frontend served in production mode."""
+
+from __future__ import annotations
+
+import asyncio
+
+from fastapi import APIRouter, Depends
+
+from ..core.config import Settings
+from ..core.models import UpdateStatus
+from ..deps import get_settings, get_ws_manager
+from ..frontend import check_for_updates, install_update
+from ..ws import WebSocketManager
+
+router = APIRouter(prefix="/updates", tags=["updates"])
+
+
+@router.get("")
+def get_update_status(settings: Settings = Depends(get_settings)) -> UpdateStatus:
+    return check_for_updates(settings)
+
+
+@router.post("/install")
+async def install(
+    settings: Settings = Depends(get_settings),
+    ws: WebSocketManager = Depends(get_ws_manager),
+) -> UpdateStatus:
+    """Replace the cached build; clients pick it up when they reload."""
+    status = await asyncio.to_thread(install_update, settings)
+    await ws.broadcast("update:installed", status.model_dump())
+    return status
@@ -0,0 +1,145 @@
+"""Workspaces: named sets of projects whose issues are listed together."""
+
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+from fastapi import APIRouter, Depends, HTTPException, Response
+from pydantic import BaseModel
+
+from ..core.config import Settings
+from ..core.models import (
+    IssueFilters,
+    ListResponse,
+    Workspace,
+    Workspaces,
+    WorkspaceIssue,
+)
+from ..core.storage import sort_issues, write_text_atomic
+from ..deps import get_settings
+from .projects import get_project_storage, load_project_config
+
+router = APIRouter(prefix="/workspaces", tags=["workspaces"])
+
+
+class SetWorkspaceRequest(BaseModel):
+    projects: list[str]
+
+
+def _workspaces_path(data_dir: Path) -> Path:
+    return data_dir / "workspaces.json"
+
+
+def _read_workspaces(data_dir: Path) -> Workspaces:
+    path = _workspaces_path(data_dir)
+    if not path.exists():
+        return Workspaces()
+    return Workspaces.model_validate_json(path.read_text())
+
+
+def _write_workspaces(data_dir: Path, workspaces: Workspaces) -> None:
+    path = _workspaces_path(data_dir)
+    write_text_atomic(
+        path, json.dumps(workspaces.model_dump(mode="json"), indent=2) + "\n"
+    )
+
+
+def _require_workspace(data_dir: Path, name: str) -> Workspace:
+    workspace = _read_workspaces(data_dir).find(name)
+    if workspace is None:
+        raise HTTPException(status_code=404, detail=f"Workspace '{name}' not found")
+    return workspace
+
+
+def _rename_workspace_project(data_dir: Path, old_name: str, new_name: str) -> None:
+    """Replace *old_name* with *new_name* in every workspace."""
+    workspaces = _read_workspaces(data_dir)
+    changed = False
+    for workspace in workspaces.workspaces:
+        if old_name in workspace.projects:
+            index = workspace.projects.index(old_name)
+            workspace.projects[index] = new_name
+            changed = True
+    if changed:
+        _write_workspaces(data_dir, workspaces)
+
+
+def _remove_workspace_project(data_dir: Path, project_name: str) -> None:
+    """Drop *project_name* from every workspace."""
+    workspaces = _read_workspaces(data_dir)
+    changed = False
+    for workspace in workspaces.workspaces:
+        if project_name in workspace.projects:
+            workspace.projects.remove(project_name)
+            changed = True
+    if changed:
+        _write_workspaces(data_dir, workspaces)
+
+
+@router.get("")
+def list_workspaces(settings: Settings = Depends(get_settings)) -> list[Workspace]:
+    return _read_workspaces(settings.data_dir).workspaces
+
+
+@router.get("/{name}")
+def get_workspace(name: str, settings: Settings = Depends(get_settings)) -> Workspace:
+    return _require_workspace(settings.data_dir, name)
+
+
+@router.put("/{name}")
+def set_workspace(
+    name: str,
+    req: SetWorkspaceRequest,
+    settings: Settings = Depends(get_settings),
+) -> Workspace:
+    """Create or replace a workspace. Every project must exist."""
+    for project in req.projects:
+        load_project_config(project, settings)
+    workspaces = _read_workspaces(settings.data_dir)
+    workspace = Workspace(name=name, projects=list(dict.fromkeys(req.projects)))
+    workspaces.workspaces = [w for w in workspaces.workspaces if w.name != name]
+    workspaces.workspaces.append(workspace)
+    _write_workspaces(settings.data_dir, workspaces)
+    return workspace
+
+
+@router.delete("/{name}", status_code=204)
+def delete_workspace(name: str, settings: Settings = Depends(get_settings)) -> Response:
+    workspaces = _read_workspaces(settings.data_dir)
+    if workspaces.find(name) is None:
+        raise HTTPException(status_code=404, detail=f"Workspace '{name}' not found")
+    workspaces.workspaces = [w for w in workspaces.workspaces if w.name != name]
+    _write_workspaces(settings.data_dir, workspaces)
+    return Response(status_code=204)
+
+
+@router.get("/{name}/issues", response_model=ListResponse[WorkspaceIssue])
+def list_workspace_issues(
+    name: str,
+    filters: IssueFilters = Depends(),
+    settings: Settings = Depends(get_settings),
+) -> ListResponse[WorkspaceIssue]:
+    """Merge issues from every project in the workspace, then sort and paginate.
+
+    Filters apply per project as in the single-project issue list.
+    """
+    workspace = _require_workspace(settings.data_dir, name)
+    issues: list[WorkspaceIssue] = []
+    for project in workspace.projects:
+        storage = get_project_storage(project, settings)
+        issues.extend(
+            WorkspaceIssue(project=project, **issue.model_dump())
+            for issue in storage.all_issues(filters)
+        )
+
+    sort_issues(issues, filters)
+    per_page = min(filters.per_page, 100)
+    page = max(filters.page, 1)
+    offset = (page - 1) * per_page
+    return ListResponse(
+        items=issues[offset : offset + per_page],
+        total_count=len(issues),
+        page=page,
+        per_page=per_page,
+    )
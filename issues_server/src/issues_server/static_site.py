@@ -0,0 +1,113 @@
+"""Render a store's issues as a static, read-only HTML site.
+
+The site is committed to the store's ``gh-pages`` branch so it can be served
+by GitHub Pages, giving people without app access a browse link.
+"""
+
+from __future__ import annotations
+
+from html import escape
+
+from pydantic import BaseModel
+
+from .core.models import Comment, Issue, IssueFilters
+from .core.storage import ProjectStorage
+
+SITE_BRANCH = "gh-pages"
+
+_STYLE = """
+body { font: 14px/1.5 system-ui, sans-serif; max-width: 860px; margin: 2rem auto;
+       padding: 0 1rem; color: #1f2328; }
+a { color: #0969da; text-decoration: none; }
+table { width: 100%; border-collapse: collapse; }
+td { padding: .5rem; border-bottom: 1px solid #d0d7de; vertical-align: top; }
+.state { font-size: 12px; padding: 2px 8px; border-radius: 999px; color: #fff; }
+.open { background: #1a7f37; } .closed { background: #8250df; }
+.label { font-size: 12px; padding: 0 6px; border-radius: 999px; margin-left: 4px; }
+.meta { color: #59636e; font-size: 12px; }
+.body { white-space: pre-wrap; border: 1px solid #d0d7de; border-radius: 6px;
+        padding: .75rem; margin: .5rem 0 1rem; }
+"""
+
+
+class StaticSiteResult(BaseModel):
+    branch: str
+    issues: int
+    changed: bool
+    pushed: bool
+    url: str | None = None
+
+
+def _page(title: str, content: str) -> str:
+    return (
+        "<!DOCTYPE html>\n<html lang=\"en\"><head><meta charset=\"utf-8\">"
+        f"<title>{escape(title)}</title><style>{_STYLE}</style></head>"
+        f"<body>{content}</body></html>\n"
+    )
+
+
+def _labels(issue: Issue) -> str:
+    return "".join(
+        f'<span class="label" style="background:#{escape(label.color)}">'
+        f"{escape(label.name)}</span>"
+        for label in issue.labels
+    )
+
+
+def _state(issue: Issue) -> str:
+    return f'<span class="state {issue.state}">{issue.state}</span>'
+
+
+def _index(title: str, issues: list[Issue]) -> str:
+    rows = "".join(
+        f"<tr><td>{_state(issue)}</td><td>"
+        f'<a href="issues/{issue.number}.html">{escape(issue.title)}</a>{_labels(issue)}'
+        f'<div class="meta">#{issue.number} opened {issue.created_at:%Y-%m-%d} by '
+        f"{escape(issue.user.login)}</div></td></tr>"
+        for issue in issues
+    )
+    return _page(title, f"<h1>{escape(title)}</h1><table>{rows}</table>")
+
+
+def _issue_page(title: str, issue: Issue, comments: list[Comment]) -> str:
+    thread = "".join(
+        f'<div class="meta">{escape(c.user.login)} commented {c.created_at:%Y-%m-%d}</div>'
+        f'<div class="body">{escape(c.body)}</div>'
+        for c in comments
+    )
+    content = (
+        f'<p><a href="../index.html">&larr; {escape(title)}</a></p>'
+        f"<h1>{escape(issue.title)} <span class=\"meta\">#{issue.number}</span></h1>"
+        f"<p>{_state(issue)}{_labels(issue)}</p>"
+        f'<div class="meta">{escape(issue.user.login)} opened '
+        f"{issue.created_at:%Y-%m-%d}</div>"
+        f'<div class="body">{escape(issue.body or "")}</div>{thread}'
+    )
+    return _page(f"{issue.title} - {title}", content)
+
+
+def render_static_site(storage: ProjectStorage, title: str) -> dict[str, str]:
+    """Return the site as ``{path: html}``: an index plus one page per issue."""
+    issues = storage.all_issues(IssueFilters(state="all", direction="desc"))
+    # .nojekyll stops Pages from running the files through Jekyll.
+    files = {"index.html": _index(title, issues), ".nojekyll": ""}
+    for issue in issues:
+        files[f"issues/{issue.number}.html"] = _issue_page(
+            title, issue, storage.all_comments(issue.number)
+        )
+    return files
+
+
+def export_static_site(
+    storage: ProjectStorage, owner: str, repo: str
+) -> StaticSiteResult:
+    """Commit the rendered site to the ``gh-pages`` branch and push it."""
+    files = render_static_site(storage, f"{owner}/{repo}")
+    changed = storage.write_branch(SITE_BRANCH, files, "Publish static site")
+    pushed = storage.push_branch(SITE_BRANCH)
+    return StaticSiteResult(
+        branch=SITE_BRANCH,
+        issues=sum(1 for path in files if path.startswith("issues/")),
+        changed=changed,
+        pushed=pushed,
+    )
@@ -0,0 +1,120 @@
+"""Find attractor stores on a GitHub account without cloning them.
+
+Used to recover projects on a new machine: each ``attractor-store-*`` repo's
+manifest is read through the contents API to learn its store_id.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import json
+import logging
+
+import httpx
+from pydantic import BaseModel, Field
+
+from .core.errors import AppError
+from .github_client import GitHubClient
+
+logger = logging.getLogger(__name__)
+
+STORE_REPO_PREFIX = "attractor-store-"
+MANIFEST_PATH = ".attractor-store.json"
+# Keeps a burst of contents requests under GitHub's secondary rate limits.
+MAX_CONCURRENT_FETCHES = 8
+# Suffixes tried after the bare name before giving up.
+MAX_NAME_SUFFIX = 100
+SUGGESTED_NAMES = 3
+
+
+class StoreNameExhaustedError(AppError):
+    status_code = 409
+    code = "STORE_NAME_EXHAUSTED"
+
+
+class DiscoveredStore(BaseModel):
+    """A store repo found on GitHub.
+
+    ``store_id`` is None when the repo has no readable manifest yet.
+    ``project_hint`` is the repo name without the store prefix, and
+    ``linked_projects`` lists local projects already using this store.
+    """
+
+    owner: str
+    repo: str
+    html_url: str
+    private: bool
+    store_id: str | None = None
+    project_hint: str
+    linked_projects: list[str] = Field(default_factory=list)
+    last_activity: str | None = None
+
+
+async def _read_store_id(client: GitHubClient, owner: str, repo: str) -> str | None:
+    try:
+        raw = await client.get_file_content(owner, repo, MANIFEST_PATH)
+    except httpx.HTTPError as exc:
+        logger.info("Could not read manifest of %s/%s: %s", owner, repo, exc)
+        return None
+    if raw is None:
+        return None
+    try:
+        return json.loads(raw)["store_id"]
+    except (ValueError, KeyError, TypeError):
+        return None
+
+
+async def discover_stores(
+    client: GitHubClient, linked: dict[str, list[str]] | None = None
+) -> list[DiscoveredStore]:
+    """Return every ``attractor-store-*`` repo the account can see, newest first.
+
+    *linked* maps store_id to the local projects that use it.
+    """
+    repos = await client.list_repos(prefix=STORE_REPO_PREFIX)
+    semaphore = asyncio.Semaphore(MAX_CONCURRENT_FETCHES)
+
+    async def inspect(repo: dict) -> DiscoveredStore:
+        owner = repo["owner"]["login"]
+        async with semaphore:
+            store_id = await _read_store_id(client, owner, repo["name"])
+        return DiscoveredStore(
+            owner=owner,
+            repo=repo["name"],
+            html_url=repo["html_url"],
+            private=repo["private"],
+            store_id=store_id,
+            project_hint=repo["name"].removeprefix(STORE_REPO_PREFIX),
+            linked_projects=(linked or {}).get(store_id, []) if store_id else [],
+            last_activity=repo.get("pushed_at") or repo.get("updated_at"),
+        )
+
+    stores = await asyncio.gather(*(inspect(r) for r in repos))
+    return sorted(stores, key=lambda s: s.last_activity or "", reverse=True)
+
+
+async def free_store_repo_names(
+    client: GitHubClient, project: str, count: int = 1
+) -> list[str]:
+    """Up to *count* of ``attractor-store-<project>``, ``-2``, ``-3``... not yet owned.
+
+    Lists the user's repos once rather than probing each candidate.
+    """
+    base = f"{STORE_REPO_PREFIX}{project}"
+    login = (await client.get_authenticated_user())["login"]
+    # GitHub repo names are case-insensitive.
+    taken = {
+        r["name"].lower()
+        for r in await client.list_repos(prefix=STORE_REPO_PREFIX, affiliation="owner")
+        if r["owner"]["login"] == login
+    }
+    candidates = (
+        base if n == 1 else f"{base}-{n}" for n in range(1, MAX_NAME_SUFFIX + 1)
+    )
+    free = [c for c in candidates if c.lower() not in taken][:count]
+    if not free:
+        raise StoreNameExhaustedError(
+            f"Every name from {base} to {base}-{MAX_NAME_SUFFIX} is taken",
+            base=base,
+        )
+    return free
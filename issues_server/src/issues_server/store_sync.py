@@ -0,0 +1,36 @@
+"""Periodic pull and push of every store that has a remote."""
+
+from __future__ import annotations
+
+import asyncio
+import logging
+from collections.abc import Awaitable, Callable
+
+logger = logging.getLogger(__name__)
+
+
+class StoreSyncScheduler:
+    """Run store syncs on the interval from the app settings; 0 disables them."""
+
+    def __init__(self) -> None:
+        self._task: asyncio.Task | None = None  # type: ignore[type-arg]
+        self.interval = 0.0
+
+    def start(self, interval: float, run: Callable[[], Awaitable[None]]) -> None:
+        self.stop()
+        self.interval = interval
+        if interval > 0:
+            self._task = asyncio.create_task(self._loop(interval, run))
+
+    def stop(self) -> None:
+        if self._task is not None:
+            self._task.cancel()
+            self._task = None
+
+    async def _loop(self, interval: float, run: Callable[[], Awaitable[None]]) -> None:
+        while True:
+            await asyncio.sleep(interval)
+            try:
+                await run()
+            except Exception:
+                logger.exception("Scheduled store sync failed")